@@ -23,7 +23,8 @@ use ratatui::backend::CrosstermBackend;
 use rc_core::keymap::{KeyChord, KeyCode, KeyContext, KeyModifiers, Keymap, KeymapParseReport};
 use rc_core::settings_io;
 use rc_core::{
-    AppCommand, AppState, ApplyResult, ExternalEditRequest, JobRequest, MouseClickTarget, Settings,
+    AppCommand, AppState, ApplyResult, ExternalEditRequest, JobQueue, JobRequest, MouseClickTarget,
+    Settings, UndoJournal,
 };
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::fmt::writer::BoxMakeWriter;
@@ -142,6 +143,22 @@ fn main() -> Result<()> {
         .unwrap_or(std::env::current_dir().context("failed to resolve current directory")?);
     let mut state = AppState::new(start_path).context("failed to initialize app state")?;
     state.replace_settings(settings.clone());
+    if let Some(path) = settings_paths.undo_journal_path() {
+        match UndoJournal::load(&path) {
+            Ok(journal) => state.replace_undo_journal(journal),
+            Err(error) => {
+                tracing::warn!("failed to read undo journal '{}': {error}", path.display());
+            }
+        }
+    }
+    if let Some(path) = settings_paths.job_queue_path() {
+        match JobQueue::load(&path) {
+            Ok(queue) => state.offer_interrupted_jobs(queue),
+            Err(error) => {
+                tracing::warn!("failed to read job queue '{}': {error}", path.display());
+            }
+        }
+    }
     state.refresh_panels();
 
     let skin_dirs = settings.appearance.skin_dirs.clone();
@@ -407,6 +424,7 @@ fn run_app(
         &mut runtime,
         skin_runtime,
     );
+    persist_job_queue(state, skin_runtime);
     queue_deferred_save_before_shutdown(state, &mut runtime);
     let shutdown_result = runtime.shutdown();
     let restore_result = restore_terminal(&mut terminal);
@@ -447,6 +465,8 @@ fn run_event_loop(
 
     loop {
         runtime.drain_events(state);
+        persist_undo_journal(state, skin_runtime);
+        persist_job_queue(state, skin_runtime);
         state.poll_deferred_work();
         runtime.dispatch_pending_commands(state);
         state.expire_status_line();
@@ -646,14 +666,42 @@ fn persist_dirty_settings(state: &mut AppState, skin_runtime: &SkinRuntimeConfig
     });
 }
 
+fn persist_undo_journal(state: &mut AppState, skin_runtime: &SkinRuntimeConfig) {
+    if !state.take_pending_undo_journal_save() {
+        return;
+    }
+    let Some(path) = skin_runtime.settings_paths.undo_journal_path() else {
+        return;
+    };
+    if let Err(error) = state.undo_journal().save(&path) {
+        tracing::warn!("failed to write undo journal '{}': {error}", path.display());
+        state.set_status(format!("Undo journal save failed: {error}"));
+    }
+}
+
+/// Rewrites the queue file after unfinished mutation jobs changed, so it is
+/// current even if rc does not get to shut down cleanly.
+fn persist_job_queue(state: &mut AppState, skin_runtime: &SkinRuntimeConfig) {
+    if !state.take_pending_job_queue_save() {
+        return;
+    }
+    let Some(path) = skin_runtime.settings_paths.job_queue_path() else {
+        return;
+    };
+    if let Err(error) = state.job_queue().save(&path) {
+        tracing::warn!("failed to write job queue '{}': {error}", path.display());
+        state.set_status(format!("Job queue save failed: {error}"));
+    }
+}
+
 fn dispatch_pending_external_edit_requests(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     state: &mut AppState,
 ) {
     for request in state.take_pending_external_edit_requests() {
-        if let Err(error) = run_external_editor_request(terminal, &request) {
-            state.set_status(format!("Editor launch failed: {error}"));
-        }
+        let result =
+            run_external_editor_request(terminal, &request).map_err(|error| error.to_string());
+        state.finish_external_edit(&request, result);
     }
 }
 
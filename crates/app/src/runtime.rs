@@ -1,18 +1,18 @@
 use std::collections::HashMap;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
 use anyhow::{Result, anyhow};
 use rc_core::{
     AppState, BackgroundEvent, FOUNDATION_SLO, JobError, JobEvent, JobId, JobRequest,
-    PanelListingSource, PanelRefreshResult, PanelRefreshStreamRequest, WorkerCommand,
+    PanelListingSource, PanelRefreshResult, PanelRefreshStreamRequest, RuntimeSlot, WorkerCommand,
     build_tree_ready_event, execute_worker_job, read_disk_usage, run_find_entries,
     stream_refresh_panel_entries,
 };
-use tokio::sync::{Semaphore, mpsc as tokio_mpsc, oneshot};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc as tokio_mpsc, oneshot};
 use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 
@@ -22,6 +22,7 @@ const FS_MUTATION_CONCURRENCY_LIMIT: usize = 2;
 const SETTINGS_CONCURRENCY_LIMIT: usize = 1;
 const SCAN_CONCURRENCY_LIMIT: usize = 4;
 const PROCESS_CONCURRENCY_LIMIT: usize = 2;
+const PERMIT_CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
 pub(crate) struct RuntimeBridge {
     command_tx: tokio_mpsc::Sender<RuntimeCommand>,
@@ -449,14 +450,23 @@ async fn run_runtime_loop(
                             JobRequest::Copy { .. }
                             | JobRequest::Move { .. }
                             | JobRequest::Delete { .. }
+                            | JobRequest::Trash { .. }
+                            | JobRequest::RestoreTrash { .. }
+                            | JobRequest::PurgeTrash { .. }
                             | JobRequest::Mkdir { .. }
-                            | JobRequest::Rename { .. } => {
+                            | JobRequest::Rename { .. }
+                            | JobRequest::Chmod { .. }
+                            | JobRequest::Chown { .. }
+                            | JobRequest::Link { .. }
+                            | JobRequest::Symlink { .. }
+                            | JobRequest::EditSymlink { .. } => {
                                 (Arc::clone(&fs_mutation_limit), "fs_mutation", true)
                             }
                             JobRequest::Find { .. }
                             | JobRequest::QuickCdSearch { .. }
                             | JobRequest::MeasureSelection { .. }
-                            | JobRequest::BuildTree { .. } => {
+                            | JobRequest::BuildTree { .. }
+                            | JobRequest::PlanTransfer { .. } => {
                                 (Arc::clone(&background_scan_limit), "scan", true)
                             }
                             JobRequest::LoadViewer { .. } | JobRequest::LoadQuickView { .. } => {
@@ -583,7 +593,7 @@ fn spawn_worker_task(tasks: &mut JoinSet<TaskCompletion>, spec: WorkerTaskSpec)
         run_after,
         notify_next,
         worker_class,
-        worker_job,
+        mut worker_job,
         worker_event_tx,
         background_event_tx,
         queued_at,
@@ -632,7 +642,7 @@ fn spawn_worker_task(tasks: &mut JoinSet<TaskCompletion>, spec: WorkerTaskSpec)
                 );
                 return TaskCompletion::Worker { job_id };
             }
-            permit = limit.acquire_owned() => {
+            permit = Arc::clone(&limit).acquire_owned() => {
                 let Ok(permit) = permit else {
                     return TaskCompletion::Worker { job_id };
                 };
@@ -662,8 +672,14 @@ fn spawn_worker_task(tasks: &mut JoinSet<TaskCompletion>, spec: WorkerTaskSpec)
             );
             return TaskCompletion::Worker { job_id };
         }
+        let runtime_slot = Arc::new(PermitSlot {
+            limit,
+            permit: Mutex::new(Some(permit)),
+            runtime: tokio::runtime::Handle::current(),
+        });
+        worker_job.set_runtime_slot(Arc::clone(&runtime_slot) as Arc<dyn RuntimeSlot>);
         let blocking = tokio::task::spawn_blocking(move || {
-            let _permit = permit;
+            let _runtime_slot = runtime_slot;
             let run_started = Instant::now();
             tracing::debug!(
                 runtime_event = "started",
@@ -736,6 +752,46 @@ fn finish_canceled_worker_before_start(
     });
 }
 
+/// The scheduler permit of a running worker, handed back while the job waits
+/// on an overwrite or item-error prompt.
+#[derive(Debug)]
+struct PermitSlot {
+    limit: Arc<Semaphore>,
+    permit: Mutex<Option<OwnedSemaphorePermit>>,
+    runtime: tokio::runtime::Handle,
+}
+
+impl PermitSlot {
+    fn permit(&self) -> std::sync::MutexGuard<'_, Option<OwnedSemaphorePermit>> {
+        self.permit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl RuntimeSlot for PermitSlot {
+    fn release(&self) {
+        self.permit().take();
+    }
+
+    fn reacquire(&self, cancel_flag: &AtomicBool) {
+        let mut permit = self.permit();
+        if permit.is_some() {
+            return;
+        }
+        *permit = self.runtime.block_on(async {
+            tokio::select! {
+                permit = Arc::clone(&self.limit).acquire_owned() => permit.ok(),
+                _ = async {
+                    while !is_canceled(cancel_flag) {
+                        tokio::time::sleep(PERMIT_CANCEL_POLL_INTERVAL).await;
+                    }
+                } => None,
+            }
+        });
+    }
+}
+
 struct SequenceCompletion(Option<oneshot::Sender<()>>);
 
 impl SequenceCompletion {
@@ -829,6 +885,7 @@ fn execute_runtime_worker_job(
             paths,
             request_id,
             cancel_flag,
+            worker_job.pause_flag(),
             worker_event_tx,
             background_event_tx,
         ),
@@ -845,6 +902,17 @@ fn execute_runtime_worker_job(
             worker_event_tx,
             background_event_tx,
         ),
+        JobRequest::PlanTransfer {
+            request,
+            request_id,
+        } => execute_plan_transfer_worker_job(
+            worker_job.id,
+            *request,
+            request_id,
+            cancel_flag,
+            worker_event_tx,
+            background_event_tx,
+        ),
         _ => execute_worker_job(worker_job, worker_event_tx),
     }
 }
@@ -1056,11 +1124,16 @@ fn execute_selection_size_worker_job(
     paths: Vec<std::path::PathBuf>,
     request_id: u64,
     cancel_flag: Arc<AtomicBool>,
+    pause_flag: Arc<AtomicBool>,
     worker_event_tx: &Sender<JobEvent>,
     background_event_tx: &Sender<BackgroundEvent>,
 ) {
     let _ = worker_event_tx.send(JobEvent::Started { id: job_id });
-    let report = match rc_core::measure_selection_size(&paths, cancel_flag.as_ref()) {
+    let report = match rc_core::measure_selection_size_pausable(
+        &paths,
+        cancel_flag.as_ref(),
+        pause_flag.as_ref(),
+    ) {
         Ok(report) if !is_canceled(cancel_flag.as_ref()) => report,
         Ok(_) => {
             let _ = worker_event_tx.send(JobEvent::Finished {
@@ -1144,6 +1217,32 @@ fn execute_tree_worker_job(
     let _ = worker_event_tx.send(JobEvent::Finished { id: job_id, result });
 }
 
+fn execute_plan_transfer_worker_job(
+    job_id: JobId,
+    request: JobRequest,
+    request_id: u64,
+    cancel_flag: Arc<AtomicBool>,
+    worker_event_tx: &Sender<JobEvent>,
+    background_event_tx: &Sender<BackgroundEvent>,
+) {
+    let _ = worker_event_tx.send(JobEvent::Started { id: job_id });
+    let plan = rc_core::plan_request(&request, cancel_flag.as_ref());
+    if is_canceled(cancel_flag.as_ref()) {
+        let _ = worker_event_tx.send(JobEvent::Finished {
+            id: job_id,
+            result: Err(JobError::canceled()),
+        });
+        return;
+    }
+    let result = background_event_tx
+        .send(BackgroundEvent::TransferPlanned {
+            request_id,
+            result: plan.map_err(|error| error.to_string()),
+        })
+        .map_err(|_| JobError::from_message("background event channel disconnected"));
+    let _ = worker_event_tx.send(JobEvent::Finished { id: job_id, result });
+}
+
 fn runtime_queue_depth(command_tx: &tokio_mpsc::Sender<RuntimeCommand>) -> usize {
     command_tx
         .max_capacity()
@@ -1251,8 +1350,9 @@ fn worker_command_name(command: &WorkerCommand) -> &'static str {
 mod tests {
     use super::*;
     use rc_core::{
-        ActivePanel, AppState, JobErrorCode, JobId, JobManager, JobRequest, JobRetryHint,
-        JobStatus, PanelFilter, PanelListingSource, SortMode, settings_io,
+        ActivePanel, AppState, FollowLinks, JobErrorCode, JobId, JobManager, JobRequest,
+        JobRetryHint, JobStatus, OverwriteAnswer, OverwriteDecision, OverwritePolicy, PanelFilter,
+        PanelListingSource, PreserveLevel, SortMode, settings_io,
     };
     use std::env;
     use std::fs;
@@ -1842,7 +1942,15 @@ mod tests {
                     let error = result.expect_err("shutdown should cancel queued and running jobs");
                     finished.insert(id, error.code);
                 }
-                JobEvent::Started { .. } | JobEvent::Progress { .. } => {}
+                JobEvent::Started { .. }
+                | JobEvent::Progress { .. }
+                | JobEvent::Undoable { .. }
+                | JobEvent::OverwriteConflict { .. }
+                | JobEvent::ItemFailed { .. }
+                | JobEvent::ItemSkipped { .. }
+                | JobEvent::Warning { .. }
+                | JobEvent::SourceCompleted { .. }
+                | JobEvent::DestinationClaimed { .. } => {}
             }
         }
         for job_id in &job_ids {
@@ -1914,7 +2022,14 @@ mod tests {
                         );
                     }
                 }
-                JobEvent::Progress { .. } => {}
+                JobEvent::Progress { .. }
+                | JobEvent::Undoable { .. }
+                | JobEvent::OverwriteConflict { .. }
+                | JobEvent::ItemFailed { .. }
+                | JobEvent::ItemSkipped { .. }
+                | JobEvent::Warning { .. }
+                | JobEvent::SourceCompleted { .. }
+                | JobEvent::DestinationClaimed { .. } => {}
             }
         }
         assert!(
@@ -2072,6 +2187,179 @@ mod tests {
         fs::remove_dir_all(&root).expect("temp root should be removable");
     }
 
+    #[test]
+    fn jobs_waiting_on_prompts_leave_their_permits_to_other_jobs() {
+        let root = make_temp_dir("prompt-permit");
+        let (command_tx, worker_event_rx, _background_event_rx, runtime_handle) =
+            spawn_runtime_loop_thread();
+        let mut manager = JobManager::new();
+        let mut prompting = Vec::new();
+        for index in 0..FS_MUTATION_CONCURRENCY_LIMIT {
+            let source = root.join(format!("source-{index}.txt"));
+            let destination_dir = root.join(format!("destination-{index}"));
+            fs::write(&source, "new").expect("source should be writable");
+            fs::create_dir(&destination_dir).expect("destination should be creatable");
+            fs::write(destination_dir.join(format!("source-{index}.txt")), "old")
+                .expect("conflicting file should be writable");
+            let job = manager.enqueue(JobRequest::Copy {
+                sources: vec![source],
+                destination_dir,
+                destination_names: None,
+                overwrite: OverwritePolicy::Ask,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+                follow_links: FollowLinks::Never,
+                hard_links: false,
+            });
+            prompting.push(job.id);
+            send_run(&command_tx, job);
+        }
+        let mut asked = 0;
+        while asked < prompting.len() {
+            if let JobEvent::OverwriteConflict { .. } =
+                recv_event(&worker_event_rx, Duration::from_secs(2))
+            {
+                asked += 1;
+            }
+        }
+
+        let mkdir = manager.enqueue(JobRequest::Mkdir {
+            path: root.join("made"),
+        });
+        let mkdir_id = mkdir.id;
+        send_run(&command_tx, mkdir);
+        loop {
+            if let JobEvent::Finished { id, result } =
+                recv_event(&worker_event_rx, Duration::from_secs(2))
+                && id == mkdir_id
+            {
+                result.expect("mkdir should run while the copies wait on prompts");
+                break;
+            }
+        }
+        assert!(root.join("made").is_dir());
+
+        for id in &prompting {
+            assert!(manager.answer_overwrite(
+                *id,
+                OverwriteAnswer {
+                    decision: OverwriteDecision::Overwrite,
+                    apply_to_all: false,
+                },
+            ));
+        }
+        let mut finished = 0;
+        while finished < prompting.len() {
+            if let JobEvent::Finished { id, result } =
+                recv_event(&worker_event_rx, Duration::from_secs(2))
+            {
+                assert!(prompting.contains(&id));
+                result.expect("answered copy should finish");
+                finished += 1;
+            }
+        }
+        for index in 0..FS_MUTATION_CONCURRENCY_LIMIT {
+            let copied = root
+                .join(format!("destination-{index}"))
+                .join(format!("source-{index}.txt"));
+            assert_eq!(
+                fs::read_to_string(copied).expect("copy should exist"),
+                "new"
+            );
+        }
+
+        command_tx
+            .blocking_send(RuntimeCommand::Shutdown)
+            .expect("runtime shutdown should send");
+        runtime_handle
+            .join()
+            .expect("runtime loop thread should terminate cleanly");
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[test]
+    fn paused_jobs_leave_their_permits_to_other_jobs() {
+        let root = make_temp_dir("pause-permit");
+        let (command_tx, worker_event_rx, _background_event_rx, runtime_handle) =
+            spawn_runtime_loop_thread();
+        let mut manager = JobManager::new();
+        let mut paused = Vec::new();
+        for index in 0..FS_MUTATION_CONCURRENCY_LIMIT {
+            let source = root.join(format!("source-{index}.txt"));
+            let destination_dir = root.join(format!("destination-{index}"));
+            fs::write(&source, "copied").expect("source should be writable");
+            fs::create_dir(&destination_dir).expect("destination should be creatable");
+            let job = manager.enqueue(JobRequest::Copy {
+                sources: vec![source],
+                destination_dir,
+                destination_names: None,
+                overwrite: OverwritePolicy::Ask,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+                follow_links: FollowLinks::Never,
+                hard_links: false,
+            });
+            assert!(manager.set_paused(job.id, true));
+            paused.push(job.id);
+            send_run(&command_tx, job);
+        }
+        let mut started = 0;
+        while started < paused.len() {
+            if let JobEvent::Started { id } = recv_event(&worker_event_rx, Duration::from_secs(2))
+                && paused.contains(&id)
+            {
+                started += 1;
+            }
+        }
+
+        let mkdir = manager.enqueue(JobRequest::Mkdir {
+            path: root.join("made"),
+        });
+        let mkdir_id = mkdir.id;
+        send_run(&command_tx, mkdir);
+        loop {
+            if let JobEvent::Finished { id, result } =
+                recv_event(&worker_event_rx, Duration::from_secs(2))
+            {
+                assert_eq!(id, mkdir_id, "paused copies should not finish");
+                result.expect("mkdir should run while the copies are paused");
+                break;
+            }
+        }
+        assert!(root.join("made").is_dir());
+
+        for id in &paused {
+            assert!(manager.set_paused(*id, false));
+        }
+        let mut finished = 0;
+        while finished < paused.len() {
+            if let JobEvent::Finished { id, result } =
+                recv_event(&worker_event_rx, Duration::from_secs(2))
+            {
+                assert!(paused.contains(&id));
+                result.expect("resumed copy should finish");
+                finished += 1;
+            }
+        }
+        for index in 0..FS_MUTATION_CONCURRENCY_LIMIT {
+            let copied = root
+                .join(format!("destination-{index}"))
+                .join(format!("source-{index}.txt"));
+            assert_eq!(
+                fs::read_to_string(copied).expect("copy should exist"),
+                "copied"
+            );
+        }
+
+        command_tx
+            .blocking_send(RuntimeCommand::Shutdown)
+            .expect("runtime shutdown should send");
+        runtime_handle
+            .join()
+            .expect("runtime loop thread should terminate cleanly");
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
     #[test]
     fn cancel_during_run_finishes_job_as_canceled() {
         let root = make_temp_dir("cancel-during-run");
@@ -2220,6 +2508,7 @@ mod tests {
             vec![selected],
             73,
             cancel_flag,
+            Arc::new(AtomicBool::new(false)),
             &worker_event_tx,
             &background_event_tx,
         );
@@ -0,0 +1,108 @@
+use std::fs;
+use std::path::Path;
+
+pub const PASSWD_PATH: &str = "/etc/passwd";
+pub const GROUP_PATH: &str = "/etc/group";
+
+/// A user or group name with its numeric id, as listed by the chown pickers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountEntry {
+    pub name: String,
+    pub id: u32,
+}
+
+impl AccountEntry {
+    pub fn new(name: impl Into<String>, id: u32) -> Self {
+        Self {
+            name: name.into(),
+            id,
+        }
+    }
+}
+
+pub fn load_users() -> Vec<AccountEntry> {
+    load_account_file(Path::new(PASSWD_PATH))
+}
+
+pub fn load_groups() -> Vec<AccountEntry> {
+    load_account_file(Path::new(GROUP_PATH))
+}
+
+fn load_account_file(path: &Path) -> Vec<AccountEntry> {
+    fs::read_to_string(path)
+        .map(|content| parse_account_file(&content))
+        .unwrap_or_default()
+}
+
+/// Parses `/etc/passwd` or `/etc/group` content.
+///
+/// Both formats keep the name in the first field and the numeric id in the
+/// third. Comments, NIS `+`/`-` lines and malformed rows are ignored, and
+/// duplicated names keep their first entry.
+pub fn parse_account_file(content: &str) -> Vec<AccountEntry> {
+    let mut entries: Vec<AccountEntry> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(['+', '-']) {
+            continue;
+        }
+        let mut fields = line.split(':');
+        let (Some(name), Some(_password), Some(id)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let Ok(id) = id.parse::<u32>() else {
+            continue;
+        };
+        if name.is_empty() || entries.iter().any(|entry| entry.name == name) {
+            continue;
+        }
+        entries.push(AccountEntry::new(name, id));
+    }
+    entries.sort_by(|left, right| left.name.cmp(&right.name));
+    entries
+}
+
+/// Returns the index of `id` in `entries`, appending a numeric placeholder when
+/// the id has no name (for example files owned by a deleted user).
+pub fn ensure_account_id(entries: &mut Vec<AccountEntry>, id: u32) -> usize {
+    if let Some(index) = entries.iter().position(|entry| entry.id == id) {
+        return index;
+    }
+    entries.push(AccountEntry::new(id.to_string(), id));
+    entries.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_account_file_reads_names_and_ids_sorted_by_name() {
+        let content = "\
+# local users
+root:x:0:0:root:/root:/bin/sh
++nisuser::::::
+daemon:x:1:1::/usr/sbin:/usr/sbin/nologin
+broken:x:notanumber:0::/:/bin/false
+root:x:99:0:duplicate:/:/bin/false
+alice:x:1000:1000:Alice:/home/alice:/bin/zsh
+";
+        assert_eq!(
+            parse_account_file(content),
+            vec![
+                AccountEntry::new("alice", 1000),
+                AccountEntry::new("daemon", 1),
+                AccountEntry::new("root", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn ensure_account_id_adds_numeric_placeholder_for_unknown_ids() {
+        let mut entries = parse_account_file("wheel:x:10:alice\n");
+        assert_eq!(ensure_account_id(&mut entries, 10), 0);
+        assert_eq!(ensure_account_id(&mut entries, 4242), 1);
+        assert_eq!(entries[1], AccountEntry::new("4242", 4242));
+    }
+}
@@ -105,6 +105,10 @@ pub enum BackgroundEvent {
         root: PathBuf,
         result: TreeBuildResult,
     },
+    TransferPlanned {
+        request_id: u64,
+        result: Result<crate::TransferPlan, String>,
+    },
 }
 
 pub fn refresh_panel_event(
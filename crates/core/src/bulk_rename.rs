@@ -0,0 +1,105 @@
+//! The name list of a bulk rename, vidir style: the selected names are
+//! written one per line to a temporary file, the external editor changes
+//! them in place, and the edited list is compared with the original line by
+//! line. Lines stay in the order they were written, so deleting or adding a
+//! line is refused rather than guessed at.
+
+use std::path::PathBuf;
+
+use crate::ActivePanel;
+
+/// A name list handed to the external editor and the renames it stands for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct BulkRenameSession {
+    pub(crate) list_path: PathBuf,
+    pub(crate) directory: PathBuf,
+    pub(crate) sources: Vec<PathBuf>,
+    pub(crate) names: Vec<String>,
+    pub(crate) panel: ActivePanel,
+}
+
+/// The contents of the name list, or an error for a name that cannot be
+/// written on a line of its own.
+pub(crate) fn render_name_list(names: &[String]) -> Result<String, String> {
+    let mut list = String::new();
+    for name in names {
+        if name.contains(['\n', '\r']) {
+            return Err(format!("{name:?} contains a line break"));
+        }
+        list.push_str(name);
+        list.push('\n');
+    }
+    Ok(list)
+}
+
+/// The lines of `edited` that differ from `names`, as the index of the name
+/// and its new value.
+pub(crate) fn changed_names(
+    names: &[String],
+    edited: &str,
+) -> Result<Vec<(usize, String)>, String> {
+    let mut lines: Vec<&str> = edited.lines().collect();
+    // Editors may leave blank lines at the end of the file.
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    if lines.len() < names.len() {
+        return Err(format!(
+            "{} line(s) deleted; a bulk rename cannot remove files",
+            names.len() - lines.len()
+        ));
+    }
+    if lines.len() > names.len() {
+        return Err(format!(
+            "{} line(s) added; keep one line per name",
+            lines.len() - names.len()
+        ));
+    }
+    if let Some(line) = lines.iter().position(|line| line.is_empty()) {
+        return Err(format!("line {} is empty", line + 1));
+    }
+    Ok(names
+        .iter()
+        .zip(lines)
+        .enumerate()
+        .filter(|(_, (name, line))| name.as_str() != *line)
+        .map(|(index, (_, line))| (index, line.to_string()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn edited_lists_yield_changed_names_and_refuse_deleted_lines() {
+        let original = names(&["a.txt", "b.txt", "c.txt"]);
+        assert_eq!(
+            render_name_list(&original).as_deref(),
+            Ok("a.txt\nb.txt\nc.txt\n")
+        );
+        assert!(render_name_list(&names(&["two\nlines"])).is_err());
+
+        assert_eq!(
+            changed_names(&original, "b.txt\r\na.txt\r\nc.txt\r\n\n"),
+            Ok(vec![(0, String::from("b.txt")), (1, String::from("a.txt"))])
+        );
+        assert_eq!(
+            changed_names(&original, "a.txt\nb.txt\nc.txt"),
+            Ok(Vec::new())
+        );
+        assert!(
+            changed_names(&original, "a.txt\nc.txt\n")
+                .is_err_and(|error| error.contains("1 line(s) deleted"))
+        );
+        assert!(changed_names(&original, "a.txt\nb.txt\nc.txt\nd.txt\n").is_err());
+        assert!(
+            changed_names(&original, "a.txt\n\nc.txt\n")
+                .is_err_and(|error| error.contains("line 2"))
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::bulk_rename::{BulkRenameSession, changed_names, render_name_list};
+use crate::jobs::validate_destination_names;
+use crate::rename_mask::source_name;
+use crate::*;
+
+impl AppState {
+    pub(crate) fn start_bulk_rename(&mut self) {
+        let configured_editor = self.settings.configuration.editor_command.clone();
+        self.start_bulk_rename_with_resolver(|| {
+            resolve_external_editor_command(configured_editor.as_deref())
+        });
+    }
+
+    /// Writes the names of the tagged entries to a temporary list and opens
+    /// it in the external editor; [`Self::finish_external_edit`] reads it
+    /// back once the editor exits.
+    pub(crate) fn start_bulk_rename_with_resolver(
+        &mut self,
+        resolve_external_editor: impl FnOnce() -> Option<String>,
+    ) {
+        let sources = self.selected_operation_paths();
+        if sources.is_empty() {
+            self.set_status("Bulk rename requires a selected or tagged entry");
+            return;
+        }
+        let directory = sources[0].parent().map(PathBuf::from).unwrap_or_default();
+        if sources
+            .iter()
+            .any(|source| source.parent() != Some(directory.as_path()))
+        {
+            self.set_status("Bulk rename requires entries of a single directory");
+            return;
+        }
+        let Some(editor_command) = resolve_external_editor() else {
+            self.set_status("Bulk rename: no external editor configured");
+            return;
+        };
+        let names: Vec<String> = sources.iter().map(|source| source_name(source)).collect();
+        let list = match render_name_list(&names) {
+            Ok(list) => list,
+            Err(error) => {
+                self.set_status(format!("Bulk rename unavailable: {error}"));
+                return;
+            }
+        };
+        let list_path = match write_bulk_rename_list(&list) {
+            Ok(list_path) => list_path,
+            Err(error) => {
+                self.set_status(format!("Bulk rename failed: {error}"));
+                return;
+            }
+        };
+        if let Some(previous) = self.pending_bulk_rename.take() {
+            let _ = fs::remove_file(previous.list_path);
+        }
+        self.pending_external_edit_requests
+            .push(ExternalEditRequest {
+                editor_command,
+                path: list_path.clone(),
+                cwd: directory.clone(),
+            });
+        self.set_status(format!("Bulk rename: editing {} name(s)", names.len()));
+        self.pending_bulk_rename = Some(BulkRenameSession {
+            list_path,
+            directory,
+            sources,
+            names,
+            panel: self.active_panel,
+        });
+    }
+
+    /// Reports how an external edit ended. An edited bulk rename list is
+    /// compared with the names it was written with, and the changed lines
+    /// are queued as one move job.
+    pub fn finish_external_edit(
+        &mut self,
+        request: &ExternalEditRequest,
+        result: Result<(), String>,
+    ) {
+        let session = match self.pending_bulk_rename.take() {
+            Some(session) if session.list_path == request.path => session,
+            other => {
+                self.pending_bulk_rename = other;
+                if let Err(error) = result {
+                    self.set_status(format!("Editor launch failed: {error}"));
+                }
+                return;
+            }
+        };
+        let edited = fs::read_to_string(&session.list_path);
+        let _ = fs::remove_file(&session.list_path);
+        if let Err(error) = result {
+            self.set_status(format!("Bulk rename canceled: {error}"));
+            return;
+        }
+        let changes = match edited
+            .map_err(|error| error.to_string())
+            .and_then(|edited| changed_names(&session.names, &edited))
+        {
+            Ok(changes) => changes,
+            Err(error) => {
+                self.set_status(format!("Bulk rename refused: {error}"));
+                return;
+            }
+        };
+        if changes.is_empty() {
+            self.set_status("Bulk rename: no names changed");
+            return;
+        }
+
+        let (sources, names): (Vec<PathBuf>, Vec<String>) = changes
+            .into_iter()
+            .map(|(index, name)| (session.sources[index].clone(), name))
+            .unzip();
+        if let Err(error) = validate_destination_names(&sources, &session.directory, &names, true) {
+            self.set_status(format!("Bulk rename refused: {error}"));
+            return;
+        }
+        let renamed = sources.len();
+        self.queue_copy_or_move_job_with_names(
+            TransferKind::Move,
+            sources,
+            session.directory,
+            names,
+            self.overwrite_policy(),
+            self.settings.configuration.preserve_level,
+            OperationOrigin::Panel(session.panel),
+        );
+        self.set_status(format!("Bulk rename: renaming {renamed} item(s)"));
+    }
+}
+
+/// Writes `list` to a new file in the shared temp directory. The file is
+/// created under a name nothing else holds and is readable only by the
+/// user, so a file or symlink planted there beforehand is never written
+/// through.
+fn write_bulk_rename_list(list: &str) -> io::Result<PathBuf> {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    let process = std::process::id();
+    for attempt in 1..=u32::MAX {
+        let list_path =
+            env::temp_dir().join(format!("rc-bulk-rename-{process}-{stamp}-{attempt}.txt"));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        match options.open(&list_path) {
+            Ok(mut file) => {
+                if let Err(error) = file.write_all(list.as_bytes()) {
+                    let _ = fs::remove_file(&list_path);
+                    return Err(error);
+                }
+                return Ok(list_path);
+            }
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "no free name for the bulk rename list",
+    ))
+}
@@ -21,6 +21,7 @@ impl AppCommand {
             (KeyContext::FindResults, KeyCommand::Quit) => Some(Self::CloseFindResults),
             (KeyContext::Tree, KeyCommand::Quit) => Some(Self::CloseTree),
             (KeyContext::Hotlist, KeyCommand::Quit) => Some(Self::CloseHotlist),
+            (KeyContext::Trash, KeyCommand::Quit) => Some(Self::CloseTrash),
             (KeyContext::FileManager, KeyCommand::PanelOther) => Some(Self::SwitchPanel),
             (KeyContext::FileManagerXMap, KeyCommand::PanelInfo) => {
                 Some(Self::SetOtherPanelView(crate::PanelViewMode::Info))
@@ -28,6 +29,21 @@ impl AppCommand {
             (KeyContext::FileManagerXMap, KeyCommand::PanelQuickView) => {
                 Some(Self::SetOtherPanelView(crate::PanelViewMode::QuickView))
             }
+            (KeyContext::FileManagerXMap, KeyCommand::ChangeMode) => Some(Self::OpenChmodDialog),
+            (KeyContext::FileManagerXMap, KeyCommand::ChangeOwn) => Some(Self::OpenChownDialog),
+            (KeyContext::FileManagerXMap, KeyCommand::Link) => Some(Self::OpenLinkDialog),
+            (KeyContext::FileManagerXMap, KeyCommand::LinkSymbolic) => {
+                Some(Self::OpenSymlinkDialog)
+            }
+            (KeyContext::FileManagerXMap, KeyCommand::LinkSymbolicRelative) => {
+                Some(Self::OpenRelativeSymlinkDialog)
+            }
+            (KeyContext::FileManagerXMap, KeyCommand::LinkSymbolicEdit) => {
+                Some(Self::OpenEditSymlinkDialog)
+            }
+            (KeyContext::FileManager, KeyCommand::ChangeOwnAdvanced) => {
+                Some(Self::OpenAdvancedChownDialog)
+            }
             (KeyContext::FileManager, KeyCommand::ToggleTag) => Some(Self::ToggleTag),
             (KeyContext::FileManager, KeyCommand::InvertTags) => Some(Self::InvertTags),
             (KeyContext::FileManager, KeyCommand::CycleListingFormat) => {
@@ -43,10 +59,25 @@ impl AppCommand {
             (KeyContext::FileManager, KeyCommand::Copy) => Some(Self::Copy),
             (KeyContext::FileManager, KeyCommand::Move) => Some(Self::Move),
             (KeyContext::FileManager, KeyCommand::Delete) => Some(Self::Delete),
+            (KeyContext::FileManager, KeyCommand::DeletePermanently) => {
+                Some(Self::DeletePermanently)
+            }
             (KeyContext::FileManager, KeyCommand::CancelJob) => Some(Self::CancelJob),
             (KeyContext::FileManager, KeyCommand::OpenJobs) => Some(Self::OpenJobsScreen),
             (KeyContext::Jobs, KeyCommand::CloseJobs) => Some(Self::CloseJobsScreen),
             (KeyContext::Jobs, KeyCommand::CancelJob) => Some(Self::CancelJob),
+            (KeyContext::Jobs, KeyCommand::LimitJobBandwidth) => Some(Self::OpenJobBandwidthLimit),
+            (KeyContext::Jobs, KeyCommand::PauseJob) => Some(Self::ToggleJobPause),
+            (KeyContext::Jobs, KeyCommand::PauseAllJobs) => Some(Self::TogglePauseAllJobs),
+            (KeyContext::Jobs, KeyCommand::RetryJob) => Some(Self::RetryJob),
+            (KeyContext::Jobs, KeyCommand::RetryFailedItems) => Some(Self::RetryFailedItems),
+            (KeyContext::Jobs, KeyCommand::ShowSkippedItems) => Some(Self::ShowSkippedItems),
+            (KeyContext::Jobs, KeyCommand::MoveJobUp) => Some(Self::MoveJobUp),
+            (KeyContext::Jobs, KeyCommand::MoveJobDown) => Some(Self::MoveJobDown),
+            (KeyContext::Jobs, KeyCommand::RunJobNext) => Some(Self::RunJobNext),
+            (KeyContext::Jobs, KeyCommand::LimitAllBandwidth) => {
+                Some(Self::OpenGlobalBandwidthLimit)
+            }
             (KeyContext::Listbox, KeyCommand::CursorUp) => Some(Self::DialogListboxUp),
             (KeyContext::Listbox, KeyCommand::CursorDown) => Some(Self::DialogListboxDown),
             (KeyContext::Input, KeyCommand::CursorUp) => Some(Self::DialogListboxUp),
@@ -105,6 +136,17 @@ impl AppCommand {
                 Some(Self::HotlistEditSelected)
             }
             (KeyContext::Hotlist, KeyCommand::RemoveHotlist) => Some(Self::HotlistRemoveSelected),
+            (KeyContext::FileManager | KeyContext::FileManagerXMap, KeyCommand::OpenTrash) => {
+                Some(Self::OpenTrash)
+            }
+            (KeyContext::FileManager | KeyContext::FileManagerXMap, KeyCommand::Undo) => {
+                Some(Self::UndoLastOperation)
+            }
+            (KeyContext::FileManager | KeyContext::FileManagerXMap, KeyCommand::BulkRename) => {
+                Some(Self::BulkRename)
+            }
+            (KeyContext::Trash, KeyCommand::RestoreTrash) => Some(Self::TrashRestoreSelected),
+            (KeyContext::Trash, KeyCommand::PurgeTrash) => Some(Self::TrashPurgeSelected),
             (KeyContext::ViewerHex, KeyCommand::Quit) => Some(Self::CloseViewer),
             (KeyContext::Viewer, KeyCommand::Search) => Some(Self::ViewerSearchForward),
             (KeyContext::Viewer, KeyCommand::SearchBackward) => Some(Self::ViewerSearchBackward),
@@ -176,6 +218,7 @@ fn navigation_command(context: KeyContext, key_command: &KeyCommand) -> Option<A
         KeyContext::FindResults => NavigationTarget::FindResults,
         KeyContext::Tree => NavigationTarget::Tree,
         KeyContext::Hotlist => NavigationTarget::Hotlist,
+        KeyContext::Trash => NavigationTarget::Trash,
         KeyContext::Viewer | KeyContext::ViewerHex => NavigationTarget::Viewer,
         _ => return None,
     };
@@ -197,6 +240,7 @@ fn navigation_command(context: KeyContext, key_command: &KeyCommand) -> Option<A
             | NavigationTarget::FindResults
             | NavigationTarget::Tree
             | NavigationTarget::Hotlist
+            | NavigationTarget::Trash
             | NavigationTarget::Viewer,
             KeyCommand::PageUp,
         ) => NavigationMotion::PageUp,
@@ -206,6 +250,7 @@ fn navigation_command(context: KeyContext, key_command: &KeyCommand) -> Option<A
             | NavigationTarget::FindResults
             | NavigationTarget::Tree
             | NavigationTarget::Hotlist
+            | NavigationTarget::Trash
             | NavigationTarget::Viewer,
             KeyCommand::PageDown,
         ) => NavigationMotion::PageDown,
@@ -218,6 +263,7 @@ fn navigation_command(context: KeyContext, key_command: &KeyCommand) -> Option<A
             | NavigationTarget::FindResults
             | NavigationTarget::Tree
             | NavigationTarget::Hotlist
+            | NavigationTarget::Trash
             | NavigationTarget::Viewer,
             KeyCommand::Home,
         ) => NavigationMotion::Home,
@@ -228,6 +274,7 @@ fn navigation_command(context: KeyContext, key_command: &KeyCommand) -> Option<A
             | NavigationTarget::FindResults
             | NavigationTarget::Tree
             | NavigationTarget::Hotlist
+            | NavigationTarget::Trash
             | NavigationTarget::Viewer,
             KeyCommand::End,
         ) => NavigationMotion::End,
@@ -0,0 +1,283 @@
+//! Kernel-assisted copying of file contents.
+//!
+//! On Linux the copy first tries a FICLONE reflink, keeps sparse files sparse
+//! by copying only the ranges `SEEK_DATA`/`SEEK_HOLE` report, and moves bytes
+//! with `copy_file_range`. Every step falls back to plain positional reads
+//! and writes when the filesystem or kernel refuses it.
+
+use std::fmt;
+use std::fs::{File, Metadata};
+use std::io;
+
+/// Bytes moved per `copy_file_range` call, so cancellation and progress are
+/// checked regularly even on multi-gigabyte files.
+#[cfg(target_os = "linux")]
+const COPY_RANGE_CHUNK: usize = 16 * 1024 * 1024;
+const FALLBACK_BUFFER_SIZE: usize = 64 * 1024;
+
+/// How a file's contents were copied.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CopyStrategy {
+    Reflink,
+    CopyFileRange,
+    Sparse,
+    Buffered,
+}
+
+impl CopyStrategy {
+    pub const ALL: [Self; 4] = [
+        Self::Reflink,
+        Self::CopyFileRange,
+        Self::Sparse,
+        Self::Buffered,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Reflink => "reflink",
+            Self::CopyFileRange => "copy_file_range",
+            Self::Sparse => "sparse",
+            Self::Buffered => "buffered",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// The distinct strategies used by one job.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CopyStrategies {
+    bits: u8,
+}
+
+impl CopyStrategies {
+    pub fn insert(&mut self, strategy: CopyStrategy) {
+        self.bits |= strategy.bit();
+    }
+
+    pub fn contains(self, strategy: CopyStrategy) -> bool {
+        self.bits & strategy.bit() != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+}
+
+impl fmt::Display for CopyStrategies {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for strategy in CopyStrategy::ALL {
+            if self.contains(strategy) {
+                if !first {
+                    formatter.write_str("+")?;
+                }
+                formatter.write_str(strategy.label())?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shares the source's extents with the empty `destination`; `false` when
+/// the filesystem cannot reflink.
+#[cfg(target_os = "linux")]
+pub(crate) fn reflink(source: &File, destination: &File) -> bool {
+    rustix::fs::ioctl_ficlone(destination, source).is_ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn reflink(_source: &File, _destination: &File) -> bool {
+    false
+}
+
+/// Whether fewer blocks are allocated than the apparent size needs.
+#[cfg(unix)]
+pub(crate) fn is_sparse(metadata: &Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    metadata.blocks().saturating_mul(512) < metadata.len()
+}
+
+#[cfg(not(unix))]
+pub(crate) fn is_sparse(_metadata: &Metadata) -> bool {
+    false
+}
+
+/// Data ranges of `source` between `start` and `end`, or `None` when the
+/// filesystem cannot report holes.
+#[cfg(target_os = "linux")]
+pub(crate) fn data_segments(
+    source: &File,
+    start: u64,
+    end: u64,
+) -> io::Result<Option<Vec<(u64, u64)>>> {
+    use rustix::fs::{SeekFrom, seek};
+    use rustix::io::Errno;
+
+    let mut segments = Vec::new();
+    let mut offset = start;
+    while offset < end {
+        let data = match seek(source, SeekFrom::Data(offset as i64)) {
+            Ok(data) => data,
+            // No data past `offset`: the rest of the file is a hole.
+            Err(Errno::NXIO) => break,
+            Err(Errno::INVAL | Errno::OPNOTSUPP) if segments.is_empty() => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        if data >= end {
+            break;
+        }
+        let hole = seek(source, SeekFrom::Hole(data as i64))?.min(end);
+        segments.push((data, hole));
+        offset = hole;
+    }
+    Ok(Some(segments))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn data_segments(
+    _source: &File,
+    _start: u64,
+    _end: u64,
+) -> io::Result<Option<Vec<(u64, u64)>>> {
+    Ok(None)
+}
+
+/// Copies `source[start..end]` to the same offsets of `destination`, or up to
+/// the end of the source when `end` is `None`. `step` runs after every chunk
+/// with its length and aborts the copy when it fails.
+///
+/// Returns `true` when `copy_file_range` moved at least part of the range and
+/// `false` when the buffered fallback copied all of it.
+pub(crate) fn copy_range(
+    source: &File,
+    destination: &File,
+    start: u64,
+    end: Option<u64>,
+    step: &mut dyn FnMut(u64) -> io::Result<()>,
+) -> io::Result<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        use rustix::io::Errno;
+
+        let mut offset = start;
+        loop {
+            let remaining = end.map_or(COPY_RANGE_CHUNK as u64, |end| end.saturating_sub(offset));
+            if remaining == 0 {
+                return Ok(true);
+            }
+            let length = remaining.min(COPY_RANGE_CHUNK as u64) as usize;
+            let (mut offset_in, mut offset_out) = (offset, offset);
+            match rustix::fs::copy_file_range(
+                source,
+                Some(&mut offset_in),
+                destination,
+                Some(&mut offset_out),
+                length,
+            ) {
+                Ok(0) => return Ok(true),
+                Ok(copied) => {
+                    offset += copied as u64;
+                    step(copied as u64)?;
+                }
+                Err(Errno::INTR) => {}
+                // Older kernels, cross-filesystem copies and special files
+                // refuse the call; the fallback continues where it stopped.
+                Err(Errno::XDEV | Errno::NOSYS | Errno::INVAL | Errno::OPNOTSUPP | Errno::PERM) => {
+                    copy_range_buffered(source, destination, offset, end, step)?;
+                    return Ok(offset != start);
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        copy_range_buffered(source, destination, start, end, step)?;
+        Ok(false)
+    }
+}
+
+#[cfg(unix)]
+fn copy_range_buffered(
+    source: &File,
+    destination: &File,
+    start: u64,
+    end: Option<u64>,
+    step: &mut dyn FnMut(u64) -> io::Result<()>,
+) -> io::Result<()> {
+    use std::os::unix::fs::FileExt;
+
+    let mut buffer = vec![0_u8; FALLBACK_BUFFER_SIZE];
+    let mut offset = start;
+    loop {
+        let wanted = end.map_or(buffer.len() as u64, |end| {
+            end.saturating_sub(offset).min(buffer.len() as u64)
+        }) as usize;
+        if wanted == 0 {
+            return Ok(());
+        }
+        let bytes_read = match source.read_at(&mut buffer[..wanted], offset) {
+            Ok(0) => return Ok(()),
+            Ok(bytes_read) => bytes_read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        destination.write_all_at(&buffer[..bytes_read], offset)?;
+        offset += bytes_read as u64;
+        step(bytes_read as u64)?;
+    }
+}
+
+#[cfg(not(unix))]
+fn copy_range_buffered(
+    source: &File,
+    destination: &File,
+    start: u64,
+    end: Option<u64>,
+    step: &mut dyn FnMut(u64) -> io::Result<()>,
+) -> io::Result<()> {
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    let (mut source, mut destination) = (source, destination);
+    source.seek(SeekFrom::Start(start))?;
+    destination.seek(SeekFrom::Start(start))?;
+    let mut buffer = vec![0_u8; FALLBACK_BUFFER_SIZE];
+    let mut offset = start;
+    loop {
+        let wanted = end.map_or(buffer.len() as u64, |end| {
+            end.saturating_sub(offset).min(buffer.len() as u64)
+        }) as usize;
+        if wanted == 0 {
+            return Ok(());
+        }
+        let bytes_read = source.read(&mut buffer[..wanted])?;
+        if bytes_read == 0 {
+            return Ok(());
+        }
+        destination.write_all(&buffer[..bytes_read])?;
+        offset += bytes_read as u64;
+        step(bytes_read as u64)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_strategies_list_each_strategy_once_in_order() {
+        let mut strategies = CopyStrategies::default();
+        assert!(strategies.is_empty());
+        strategies.insert(CopyStrategy::Buffered);
+        strategies.insert(CopyStrategy::Reflink);
+        strategies.insert(CopyStrategy::Buffered);
+        assert_eq!(strategies.to_string(), "reflink+buffered");
+        assert!(!strategies.contains(CopyStrategy::Sparse));
+    }
+}
@@ -0,0 +1,189 @@
+//! Staging files for resumable copies.
+//!
+//! A resumable copy writes to `.name.rc-partial` next to the destination and
+//! records the source it came from in `.name.rc-partial.info`. Neither file is
+//! removed when the copy is canceled or fails, so the next copy of the same
+//! source to the same destination can continue after the bytes already there.
+
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::jobs::path_identity_token;
+use crate::trash::{decode_path, encode_path};
+
+const PARTIAL_SUFFIX: &str = "rc-partial";
+const SIDECAR_SUFFIX: &str = "info";
+/// Bytes at the end of the staged prefix compared against the source before
+/// resuming; a torn final write or a rewritten source shows up here.
+const RESUME_VERIFY_BYTES: u64 = 1024 * 1024;
+
+/// Where a resumable copy of `destination` keeps its data and sidecar.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ResumeStaging {
+    pub(crate) partial: PathBuf,
+    pub(crate) sidecar: PathBuf,
+}
+
+impl ResumeStaging {
+    pub(crate) fn for_destination(destination: &Path) -> Self {
+        let parent = destination.parent().unwrap_or(Path::new("."));
+        let file_name = destination
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("item"));
+        let partial = parent.join(format!(".{file_name}.{PARTIAL_SUFFIX}"));
+        let sidecar = parent.join(format!(".{file_name}.{PARTIAL_SUFFIX}.{SIDECAR_SUFFIX}"));
+        Self { partial, sidecar }
+    }
+
+    /// Returns how many staged bytes can be kept for `stamp`'s source.
+    ///
+    /// Zero means there is nothing reusable: the sidecar is missing or names
+    /// a different or modified source, the staged file is longer than the
+    /// source, or its tail does not match the source at the same offset.
+    pub(crate) fn resumable_prefix(&self, source: &Path, stamp: &ResumeStamp) -> io::Result<u64> {
+        let Ok(recorded) = fs::read_to_string(&self.sidecar) else {
+            return Ok(0);
+        };
+        if ResumeStamp::parse(&recorded).as_ref() != Some(stamp) {
+            return Ok(0);
+        }
+        let Ok(metadata) = fs::symlink_metadata(&self.partial) else {
+            return Ok(0);
+        };
+        if !metadata.is_file() || metadata.len() > stamp.size {
+            return Ok(0);
+        }
+        let length = metadata.len();
+        let verify = length.min(RESUME_VERIFY_BYTES);
+        let offset = length - verify;
+        let expected = read_range(source, offset, verify)?;
+        let staged = read_range(&self.partial, offset, verify)?;
+        Ok(if expected == staged { length } else { 0 })
+    }
+
+    /// Drops any stale staging data and records `stamp` for a fresh start.
+    pub(crate) fn restart(&self, stamp: &ResumeStamp) -> io::Result<()> {
+        self.discard();
+        fs::write(&self.sidecar, stamp.render())
+    }
+
+    pub(crate) fn discard(&self) {
+        let _ = fs::remove_file(&self.partial);
+        let _ = fs::remove_file(&self.sidecar);
+    }
+}
+
+/// Identity of a copy source at the time its staging file was started.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ResumeStamp {
+    source: PathBuf,
+    identity: String,
+    size: u64,
+    modified_nanos: u128,
+}
+
+impl ResumeStamp {
+    pub(crate) fn read(source: &Path) -> io::Result<Self> {
+        let metadata = fs::metadata(source)?;
+        let modified_nanos = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |duration| duration.as_nanos());
+        Ok(Self {
+            source: source.to_path_buf(),
+            identity: path_identity_token(source)?.unwrap_or_default(),
+            size: metadata.len(),
+            modified_nanos,
+        })
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "source={}\nidentity={}\nsize={}\nmodified={}\n",
+            encode_path(&self.source),
+            self.identity,
+            self.size,
+            self.modified_nanos
+        )
+    }
+
+    fn parse(content: &str) -> Option<Self> {
+        let mut source = None;
+        let mut identity = None;
+        let mut size = None;
+        let mut modified_nanos = None;
+        for line in content.lines() {
+            let (key, value) = line.split_once('=')?;
+            match key {
+                "source" => source = Some(decode_path(value)),
+                "identity" => identity = Some(value.to_string()),
+                "size" => size = value.parse().ok(),
+                "modified" => modified_nanos = value.parse().ok(),
+                _ => {}
+            }
+        }
+        Some(Self {
+            source: source?,
+            identity: identity?,
+            size: size?,
+            modified_nanos: modified_nanos?,
+        })
+    }
+}
+
+fn read_range(path: &Path, offset: u64, length: u64) -> io::Result<Vec<u8>> {
+    let mut file = fs::File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buffer = Vec::with_capacity(length as usize);
+    file.take(length).read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_temp_dir;
+
+    #[test]
+    fn resumable_prefix_requires_matching_stamp_and_tail() {
+        let root = make_temp_dir("copy-resume-prefix");
+        let source = root.join("large.bin");
+        fs::write(&source, b"0123456789").expect("source should be writable");
+        let staging = ResumeStaging::for_destination(&root.join("out").join("large.bin"));
+        assert_eq!(
+            staging.partial,
+            root.join("out").join(".large.bin.rc-partial")
+        );
+        fs::create_dir_all(root.join("out")).expect("destination dir should exist");
+
+        let stamp = ResumeStamp::read(&source).expect("stamp should be readable");
+        assert_eq!(ResumeStamp::parse(&stamp.render()), Some(stamp.clone()));
+        assert_eq!(staging.resumable_prefix(&source, &stamp).unwrap(), 0);
+
+        staging.restart(&stamp).expect("sidecar should be writable");
+        fs::write(&staging.partial, b"012345").expect("partial should be writable");
+        assert_eq!(staging.resumable_prefix(&source, &stamp).unwrap(), 6);
+
+        fs::write(&staging.partial, b"012x45").expect("partial should be writable");
+        assert_eq!(
+            staging.resumable_prefix(&source, &stamp).unwrap(),
+            0,
+            "a diverging tail must restart the copy"
+        );
+
+        fs::write(&staging.partial, b"012345").expect("partial should be writable");
+        let other = ResumeStamp {
+            size: 11,
+            ..stamp.clone()
+        };
+        assert_eq!(staging.resumable_prefix(&source, &other).unwrap(), 0);
+
+        staging.discard();
+        assert!(!staging.partial.exists() && !staging.sidecar.exists());
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+}
@@ -0,0 +1,202 @@
+//! Extended attributes carried over by copies.
+//!
+//! Each [`PreserveLevel`] adds namespaces to the previous one: `user.*`
+//! attributes, then POSIX ACLs (`system.posix_acl_access` and
+//! `system.posix_acl_default`), then everything else, including SELinux
+//! labels and file capabilities in `security.*` and `trusted.*`. Attributes
+//! that cannot be read or written are returned to the caller instead of
+//! failing the copy.
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+const ACL_ATTRIBUTES: [&str; 2] = ["system.posix_acl_access", "system.posix_acl_default"];
+
+/// How much metadata a copy carries over beyond times, permissions and owner.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PreserveLevel {
+    /// Timestamps, permissions and a best-effort owner only.
+    #[default]
+    Basic,
+    /// Also `user.*` extended attributes.
+    Xattrs,
+    /// Also POSIX ACLs.
+    Acls,
+    /// Every attribute the source has, including SELinux labels and
+    /// `security.capability`.
+    Everything,
+}
+
+impl PreserveLevel {
+    pub const ALL: [Self; 4] = [Self::Basic, Self::Xattrs, Self::Acls, Self::Everything];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Basic => "basic",
+            Self::Xattrs => "+xattrs",
+            Self::Acls => "+ACLs",
+            Self::Everything => "everything",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Basic => Self::Xattrs,
+            Self::Xattrs => Self::Acls,
+            Self::Acls => Self::Everything,
+            Self::Everything => Self::Basic,
+        }
+    }
+
+    fn includes(self, name: &str) -> bool {
+        match self {
+            Self::Basic => false,
+            Self::Xattrs => name.starts_with("user."),
+            Self::Acls => name.starts_with("user.") || ACL_ATTRIBUTES.contains(&name),
+            Self::Everything => true,
+        }
+    }
+}
+
+/// An attribute that was left behind by [`copy_xattrs`].
+#[derive(Debug)]
+pub(crate) struct XattrFailure {
+    pub(crate) name: String,
+    pub(crate) error: io::Error,
+}
+
+impl fmt::Display for XattrFailure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} ({})", self.name, self.error)
+    }
+}
+
+/// Copies the attributes of `source` that `level` covers onto `destination`
+/// without following symlinks, and returns the ones that were not copied.
+#[cfg(target_os = "linux")]
+pub(crate) fn copy_xattrs(
+    source: &Path,
+    destination: &Path,
+    level: PreserveLevel,
+) -> Vec<XattrFailure> {
+    use rustix::fs::{XattrFlags, lsetxattr};
+    use rustix::io::Errno;
+
+    if level == PreserveLevel::Basic {
+        return Vec::new();
+    }
+    let names = match list_xattr_names(source) {
+        Ok(names) => names,
+        // A filesystem without attributes has nothing to carry over.
+        Err(Errno::OPNOTSUPP) => return Vec::new(),
+        Err(error) => {
+            return vec![XattrFailure {
+                name: String::from("attribute list"),
+                error: error.into(),
+            }];
+        }
+    };
+
+    let mut failures = Vec::new();
+    for name in names {
+        let label = name.to_string_lossy().into_owned();
+        if !level.includes(&label) {
+            continue;
+        }
+        let copied = read_xattr(source, &name).and_then(|value| {
+            lsetxattr(destination, name.as_os_str(), &value, XattrFlags::empty())
+        });
+        match copied {
+            Ok(()) => {}
+            // Removed from the source since it was listed.
+            Err(Errno::NODATA) => {}
+            Err(error) => failures.push(XattrFailure {
+                name: label,
+                error: error.into(),
+            }),
+        }
+    }
+    failures
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn copy_xattrs(
+    _source: &Path,
+    _destination: &Path,
+    level: PreserveLevel,
+) -> Vec<XattrFailure> {
+    if level == PreserveLevel::Basic {
+        return Vec::new();
+    }
+    vec![XattrFailure {
+        name: String::from("extended attributes"),
+        error: io::Error::new(io::ErrorKind::Unsupported, "not supported on this platform"),
+    }]
+}
+
+#[cfg(target_os = "linux")]
+fn list_xattr_names(path: &Path) -> Result<Vec<std::ffi::OsString>, rustix::io::Errno> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    use rustix::fs::llistxattr;
+    use rustix::io::Errno;
+
+    loop {
+        let size = llistxattr(path, &mut [])?;
+        let mut buffer = vec![0_u8; size];
+        let length = match llistxattr(path, &mut buffer) {
+            Ok(length) => length,
+            // The list grew between the two calls.
+            Err(Errno::RANGE) => continue,
+            Err(error) => return Err(error),
+        };
+        return Ok(buffer[..length]
+            .split(|&byte| byte == 0)
+            .filter(|name| !name.is_empty())
+            .map(|name| OsStr::from_bytes(name).to_os_string())
+            .collect());
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_xattr(path: &Path, name: &std::ffi::OsStr) -> Result<Vec<u8>, rustix::io::Errno> {
+    use rustix::fs::lgetxattr;
+    use rustix::io::Errno;
+
+    loop {
+        let size = lgetxattr(path, name, &mut [])?;
+        let mut value = vec![0_u8; size];
+        match lgetxattr(path, name, &mut value) {
+            Ok(length) => {
+                value.truncate(length);
+                return Ok(value);
+            }
+            Err(Errno::RANGE) => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preserve_levels_add_namespaces_in_order() {
+        let names = [
+            "user.origin",
+            "system.posix_acl_access",
+            "security.capability",
+        ];
+        let included =
+            |level: PreserveLevel| names.iter().filter(|name| level.includes(name)).count();
+        assert_eq!(
+            PreserveLevel::ALL.map(included),
+            [0, 1, 2, 3],
+            "each level must keep what the previous one copied"
+        );
+        assert_eq!(PreserveLevel::Everything.next(), PreserveLevel::Basic);
+    }
+}
@@ -0,0 +1,280 @@
+//! What a copy's destination filesystem cannot hold.
+//!
+//! vfat and exFAT refuse some characters in names and fold case, so two
+//! names that differ only in case land on the same file; vfat (FAT32) also
+//! caps files at 4 GiB. Copies check their sources against these limits
+//! before they start, so the user hears of it before the job fails halfway.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use crate::jobs::ensure_not_canceled;
+
+/// Largest file FAT32 can hold: 4 GiB minus one byte.
+const FAT32_MAX_FILE_SIZE: u64 = u32::MAX as u64;
+/// Characters vfat and exFAT refuse in names, besides control characters.
+const FAT_RESERVED_CHARACTERS: [char; 9] = ['"', '*', '/', ':', '<', '>', '?', '\\', '|'];
+
+/// A destination filesystem with limits a copy can run into.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum RestrictedFilesystem {
+    Vfat,
+    Exfat,
+}
+
+impl RestrictedFilesystem {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Vfat => "vfat",
+            Self::Exfat => "exFAT",
+        }
+    }
+
+    fn max_file_size(self) -> Option<u64> {
+        match self {
+            Self::Vfat => Some(FAT32_MAX_FILE_SIZE),
+            Self::Exfat => None,
+        }
+    }
+
+    /// Both refuse reserved characters and silently drop trailing dots and
+    /// spaces, which would rename the file.
+    fn allows_name(self, name: &str) -> bool {
+        !name
+            .chars()
+            .any(|character| character.is_control() || FAT_RESERVED_CHARACTERS.contains(&character))
+            && !name.ends_with(['.', ' '])
+    }
+}
+
+/// The restricted filesystem `path` is on, if any.
+#[cfg(target_os = "linux")]
+pub(crate) fn restricted_filesystem(path: &Path) -> Option<RestrictedFilesystem> {
+    /// `statfs` magic numbers of the kernel's FAT drivers.
+    const MSDOS_SUPER_MAGIC: u64 = 0x4d44;
+    const EXFAT_SUPER_MAGIC: u64 = 0x2011_bab0;
+
+    let stats = nix::sys::statfs::statfs(path).ok()?;
+    match stats.filesystem_type().0 as u64 {
+        MSDOS_SUPER_MAGIC => Some(RestrictedFilesystem::Vfat),
+        EXFAT_SUPER_MAGIC => Some(RestrictedFilesystem::Exfat),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn restricted_filesystem(path: &Path) -> Option<RestrictedFilesystem> {
+    let stats = nix::sys::statfs::statfs(path).ok()?;
+    match stats.filesystem_type_name() {
+        "msdos" => Some(RestrictedFilesystem::Vfat),
+        "exfat" => Some(RestrictedFilesystem::Exfat),
+        _ => None,
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub(crate) fn restricted_filesystem(_path: &Path) -> Option<RestrictedFilesystem> {
+    None
+}
+
+/// Entries of the copied trees that `filesystem` cannot hold as they are.
+#[derive(Debug, Default)]
+struct Findings {
+    illegal_names: Vec<PathBuf>,
+    case_collisions: Vec<PathBuf>,
+    too_large: Vec<PathBuf>,
+}
+
+impl Findings {
+    fn warnings(self, filesystem: RestrictedFilesystem) -> Vec<String> {
+        let label = filesystem.label();
+        [
+            (
+                self.illegal_names,
+                format!("name(s) not allowed on {label}"),
+            ),
+            (
+                self.case_collisions,
+                format!("name(s) that differ only in case from another on {label}"),
+            ),
+            (
+                self.too_large,
+                format!("file(s) over 4 GiB, too large for {label}"),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(paths, what)| {
+            let first = paths.first()?;
+            Some(format!(
+                "{} {what}, e.g. {}",
+                paths.len(),
+                first.to_string_lossy()
+            ))
+        })
+        .collect()
+    }
+}
+
+/// Checks every name and file size of the copies of `sources` (pairs of
+/// source and destination path) against `filesystem`; returns one warning
+/// per kind of problem, naming the first entry it affects.
+pub(crate) fn check_destination_names(
+    filesystem: RestrictedFilesystem,
+    sources: &[(PathBuf, PathBuf)],
+    cancel_flag: &AtomicBool,
+) -> io::Result<Vec<String>> {
+    let mut findings = Findings::default();
+    // Top-level copies land next to what the destination already holds.
+    let mut folded: HashMap<(PathBuf, String), String> = HashMap::new();
+    let mut listed = HashSet::new();
+    for (_, destination) in sources {
+        let Some(parent) = destination.parent() else {
+            continue;
+        };
+        if !listed.insert(parent) {
+            continue;
+        }
+        for entry in fs::read_dir(parent)?.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            folded.insert((parent.to_path_buf(), name.to_lowercase()), name);
+        }
+    }
+    for (source, destination) in sources {
+        ensure_not_canceled(cancel_flag)?;
+        let name = destination
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let parent = destination.parent().unwrap_or(Path::new("")).to_path_buf();
+        match folded.get(&(parent.clone(), name.to_lowercase())) {
+            Some(other) if *other != name => findings.case_collisions.push(source.clone()),
+            Some(_) => {}
+            None => {
+                folded.insert((parent, name.to_lowercase()), name.clone());
+            }
+        }
+        check_entry(filesystem, source, &name, &mut findings, cancel_flag)?;
+    }
+    Ok(findings.warnings(filesystem))
+}
+
+fn check_entry(
+    filesystem: RestrictedFilesystem,
+    source: &Path,
+    name: &str,
+    findings: &mut Findings,
+    cancel_flag: &AtomicBool,
+) -> io::Result<()> {
+    ensure_not_canceled(cancel_flag)?;
+    if !filesystem.allows_name(name) {
+        findings.illegal_names.push(source.to_path_buf());
+    }
+    let metadata = fs::symlink_metadata(source)?;
+    if metadata.is_file()
+        && filesystem
+            .max_file_size()
+            .is_some_and(|max| metadata.len() > max)
+    {
+        findings.too_large.push(source.to_path_buf());
+    }
+    if !metadata.is_dir() {
+        return Ok(());
+    }
+    let mut siblings = HashMap::new();
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let child_name = entry.file_name().to_string_lossy().into_owned();
+        if siblings
+            .insert(child_name.to_lowercase(), child_name.clone())
+            .is_some()
+        {
+            findings.case_collisions.push(entry.path());
+        }
+        check_entry(
+            filesystem,
+            &entry.path(),
+            &child_name,
+            findings,
+            cancel_flag,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_temp_dir;
+
+    #[test]
+    fn fat_names_reject_reserved_characters_and_trailing_dots() {
+        for filesystem in [RestrictedFilesystem::Vfat, RestrictedFilesystem::Exfat] {
+            assert!(filesystem.allows_name("report 2024.txt"));
+            assert!(!filesystem.allows_name("a:b.txt"));
+            assert!(!filesystem.allows_name("what?"));
+            assert!(!filesystem.allows_name("tab\there"));
+            assert!(!filesystem.allows_name("trailing."));
+            assert!(!filesystem.allows_name("trailing "));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn destination_check_reports_illegal_names_case_collisions_and_large_files() {
+        let root = make_temp_dir("destination-fs");
+        let source = root.join("tree");
+        let destination = root.join("stick");
+        fs::create_dir_all(source.join("docs")).expect("source dirs should exist");
+        fs::create_dir_all(&destination).expect("destination should exist");
+        fs::write(source.join("docs/README"), "a").expect("file should be writable");
+        fs::write(source.join("docs/readme"), "b").expect("file should be writable");
+        fs::write(source.join("docs/a:b.txt"), "c").expect("file should be writable");
+        fs::write(root.join("Notes"), "d").expect("file should be writable");
+        fs::write(destination.join("notes"), "e").expect("file should be writable");
+        // Sparse, so it takes no space.
+        fs::File::create(source.join("disk.img"))
+            .and_then(|file| file.set_len(FAT32_MAX_FILE_SIZE + 1))
+            .expect("large file should be creatable");
+
+        let sources = vec![
+            (source.clone(), destination.join("tree")),
+            (root.join("Notes"), destination.join("Notes")),
+        ];
+        let warnings = check_destination_names(
+            RestrictedFilesystem::Vfat,
+            &sources,
+            &AtomicBool::new(false),
+        )
+        .expect("check should succeed");
+        assert_eq!(warnings.len(), 3, "{warnings:?}");
+        assert!(
+            warnings[0].starts_with("1 name(s) not allowed on vfat, e.g. ")
+                && warnings[0].ends_with("a:b.txt"),
+            "{warnings:?}"
+        );
+        assert!(
+            warnings[1].starts_with("2 name(s) that differ only in case"),
+            "{warnings:?}"
+        );
+        assert!(
+            warnings[2].starts_with("1 file(s) over 4 GiB, too large for vfat")
+                && warnings[2].ends_with("disk.img"),
+            "{warnings:?}"
+        );
+        let warnings = check_destination_names(
+            RestrictedFilesystem::Exfat,
+            &sources,
+            &AtomicBool::new(false),
+        )
+        .expect("check should succeed");
+        assert!(
+            !warnings.iter().any(|warning| warning.contains("4 GiB")),
+            "exFAT takes large files: {warnings:?}"
+        );
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+}
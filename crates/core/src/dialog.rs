@@ -1,5 +1,9 @@
+use crate::accounts::AccountEntry;
 use crate::keymap::KeyContext;
-use crate::{FindNameMode, FindSpec, PanelFilter, QuickCdSearchSnapshot, QuickCdSuggestion};
+use crate::{
+    FindNameMode, FindSpec, OverwriteAnswer, OverwriteConflict, OverwriteDecision, PanelFilter,
+    PermissionMask, QuickCdSearchSnapshot, QuickCdSuggestion,
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DialogButtonFocus {
@@ -406,6 +410,482 @@ impl FilterDialogState {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MaskDialogField {
+    #[default]
+    SourceMask,
+    DestinationMask,
+    NameMode,
+}
+
+impl MaskDialogField {
+    const ALL: [Self; 3] = [Self::SourceMask, Self::DestinationMask, Self::NameMode];
+
+    const fn index(self) -> usize {
+        match self {
+            Self::SourceMask => 0,
+            Self::DestinationMask => 1,
+            Self::NameMode => 2,
+        }
+    }
+}
+
+/// Source and destination masks of a batch rename, with the names they
+/// currently produce.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaskDialogState {
+    pub source_mask: String,
+    pub destination_mask: String,
+    pub name_mode: FindNameMode,
+    pub focus: MaskDialogField,
+    /// `old -> new` rows, or the reason the masks cannot be applied.
+    pub preview: Vec<String>,
+}
+
+impl MaskDialogState {
+    fn move_focus(&mut self, delta: isize) {
+        let len = MaskDialogField::ALL.len();
+        let index = self.focus.index();
+        let next = if delta.is_negative() {
+            index.checked_sub(delta.unsigned_abs()).unwrap_or(len - 1)
+        } else {
+            index.saturating_add(delta as usize) % len
+        };
+        self.focus = MaskDialogField::ALL[next];
+    }
+
+    fn insert(&mut self, character: char) {
+        match self.focus {
+            MaskDialogField::SourceMask => self.source_mask.push(character),
+            MaskDialogField::DestinationMask => self.destination_mask.push(character),
+            MaskDialogField::NameMode if character == ' ' => {
+                self.name_mode = match self.name_mode {
+                    FindNameMode::Glob => FindNameMode::Regex,
+                    FindNameMode::Regex => FindNameMode::Glob,
+                };
+            }
+            MaskDialogField::NameMode => {}
+        }
+    }
+
+    fn backspace(&mut self) {
+        match self.focus {
+            MaskDialogField::SourceMask => {
+                self.source_mask.pop();
+            }
+            MaskDialogField::DestinationMask => {
+                self.destination_mask.pop();
+            }
+            MaskDialogField::NameMode => {}
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChmodDialogField {
+    #[default]
+    SetUid,
+    SetGid,
+    Sticky,
+    OwnerRead,
+    OwnerWrite,
+    OwnerExecute,
+    GroupRead,
+    GroupWrite,
+    GroupExecute,
+    OtherRead,
+    OtherWrite,
+    OtherExecute,
+    Recursive,
+    DirectoryExecuteOnly,
+}
+
+impl ChmodDialogField {
+    pub const ALL: [Self; 14] = [
+        Self::SetUid,
+        Self::SetGid,
+        Self::Sticky,
+        Self::OwnerRead,
+        Self::OwnerWrite,
+        Self::OwnerExecute,
+        Self::GroupRead,
+        Self::GroupWrite,
+        Self::GroupExecute,
+        Self::OtherRead,
+        Self::OtherWrite,
+        Self::OtherExecute,
+        Self::Recursive,
+        Self::DirectoryExecuteOnly,
+    ];
+
+    const fn index(self) -> usize {
+        match self {
+            Self::SetUid => 0,
+            Self::SetGid => 1,
+            Self::Sticky => 2,
+            Self::OwnerRead => 3,
+            Self::OwnerWrite => 4,
+            Self::OwnerExecute => 5,
+            Self::GroupRead => 6,
+            Self::GroupWrite => 7,
+            Self::GroupExecute => 8,
+            Self::OtherRead => 9,
+            Self::OtherWrite => 10,
+            Self::OtherExecute => 11,
+            Self::Recursive => 12,
+            Self::DirectoryExecuteOnly => 13,
+        }
+    }
+
+    pub const fn mode_bit(self) -> Option<u32> {
+        match self {
+            Self::SetUid => Some(0o4000),
+            Self::SetGid => Some(0o2000),
+            Self::Sticky => Some(0o1000),
+            Self::OwnerRead => Some(0o400),
+            Self::OwnerWrite => Some(0o200),
+            Self::OwnerExecute => Some(0o100),
+            Self::GroupRead => Some(0o040),
+            Self::GroupWrite => Some(0o020),
+            Self::GroupExecute => Some(0o010),
+            Self::OtherRead => Some(0o004),
+            Self::OtherWrite => Some(0o002),
+            Self::OtherExecute => Some(0o001),
+            Self::Recursive | Self::DirectoryExecuteOnly => None,
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::SetUid => "Set user ID on execution",
+            Self::SetGid => "Set group ID on execution",
+            Self::Sticky => "Sticky bit",
+            Self::OwnerRead => "Read by owner",
+            Self::OwnerWrite => "Write by owner",
+            Self::OwnerExecute => "Execute/search by owner",
+            Self::GroupRead => "Read by group",
+            Self::GroupWrite => "Write by group",
+            Self::GroupExecute => "Execute/search by group",
+            Self::OtherRead => "Read by others",
+            Self::OtherWrite => "Write by others",
+            Self::OtherExecute => "Execute/search by others",
+            Self::Recursive => "Apply recursively",
+            Self::DirectoryExecuteOnly => "Add execute to dirs only (X)",
+        }
+    }
+}
+
+/// Permission editor for the selected or tagged entries.
+///
+/// The checkboxes start from the mode of the first target. Only the bits the
+/// user toggles are applied, so tagged entries with different modes keep the
+/// bits that were left alone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChmodDialogState {
+    pub target_label: String,
+    pub original_mode: u32,
+    pub mode: u32,
+    pub recursive: bool,
+    pub directory_execute_only: bool,
+    pub focus: ChmodDialogField,
+}
+
+impl ChmodDialogState {
+    pub fn new(target_label: impl Into<String>, mode: u32) -> Self {
+        let mode = mode & PermissionMask::BITS;
+        Self {
+            target_label: target_label.into(),
+            original_mode: mode,
+            mode,
+            recursive: false,
+            directory_execute_only: false,
+            focus: ChmodDialogField::OwnerRead,
+        }
+    }
+
+    pub fn is_checked(&self, field: ChmodDialogField) -> bool {
+        match field.mode_bit() {
+            Some(bit) => self.mode & bit != 0,
+            None => match field {
+                ChmodDialogField::Recursive => self.recursive,
+                ChmodDialogField::DirectoryExecuteOnly => self.directory_execute_only,
+                _ => false,
+            },
+        }
+    }
+
+    pub fn dir_mask(&self) -> PermissionMask {
+        PermissionMask::from_modes(self.original_mode, self.mode)
+    }
+
+    /// Mirrors `chmod u+X`: newly granted execute bits only reach directories,
+    /// while removals still apply to files.
+    pub fn file_mask(&self) -> PermissionMask {
+        let mask = self.dir_mask();
+        if self.directory_execute_only {
+            PermissionMask::new(mask.set & !0o111, mask.clear)
+        } else {
+            mask
+        }
+    }
+
+    fn move_focus(&mut self, delta: isize) {
+        let len = ChmodDialogField::ALL.len();
+        let index = self.focus.index();
+        let next = if delta.is_negative() {
+            index.checked_sub(delta.unsigned_abs()).unwrap_or(len - 1)
+        } else {
+            index.saturating_add(delta as usize) % len
+        };
+        self.focus = ChmodDialogField::ALL[next];
+    }
+
+    fn insert(&mut self, character: char) {
+        if character == ' ' {
+            self.toggle(self.focus);
+        }
+    }
+
+    fn toggle(&mut self, field: ChmodDialogField) {
+        match field.mode_bit() {
+            Some(bit) => self.mode ^= bit,
+            None => match field {
+                ChmodDialogField::Recursive => self.recursive = !self.recursive,
+                ChmodDialogField::DirectoryExecuteOnly => {
+                    self.directory_execute_only = !self.directory_execute_only;
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChownDialogField {
+    Owner,
+    Group,
+    Recursive,
+    Permission(ChmodDialogField),
+}
+
+/// Owner/group picker for the selected or tagged entries.
+///
+/// Advanced chown adds the permission checkboxes of the chmod dialog, with the
+/// dialog's own recursive flag covering both edits. As with chmod, the owner
+/// and group are only changed when a different account is picked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChownDialogState {
+    pub target_label: String,
+    pub users: Vec<AccountEntry>,
+    pub groups: Vec<AccountEntry>,
+    pub user_index: usize,
+    pub group_index: usize,
+    pub original_user_index: usize,
+    pub original_group_index: usize,
+    pub recursive: bool,
+    pub permissions: Option<ChmodDialogState>,
+    pub focus: ChownDialogField,
+}
+
+impl ChownDialogState {
+    /// `user_index` and `group_index` locate the current owner and group in
+    /// `users` and `groups`; `mode` enables the advanced permission editor.
+    pub fn new(
+        target_label: impl Into<String>,
+        users: Vec<AccountEntry>,
+        groups: Vec<AccountEntry>,
+        user_index: usize,
+        group_index: usize,
+        mode: Option<u32>,
+    ) -> Self {
+        let target_label = target_label.into();
+        let permissions = mode.map(|mode| ChmodDialogState::new(target_label.clone(), mode));
+        Self {
+            target_label,
+            users,
+            groups,
+            user_index,
+            group_index,
+            original_user_index: user_index,
+            original_group_index: group_index,
+            recursive: false,
+            permissions,
+            focus: ChownDialogField::Owner,
+        }
+    }
+
+    pub fn fields(&self) -> Vec<ChownDialogField> {
+        let mut fields = vec![
+            ChownDialogField::Owner,
+            ChownDialogField::Group,
+            ChownDialogField::Recursive,
+        ];
+        if self.permissions.is_some() {
+            fields.extend(
+                ChmodDialogField::ALL
+                    .into_iter()
+                    .filter(|field| *field != ChmodDialogField::Recursive)
+                    .map(ChownDialogField::Permission),
+            );
+        }
+        fields
+    }
+
+    pub fn selected_user(&self) -> Option<&AccountEntry> {
+        self.users.get(self.user_index)
+    }
+
+    pub fn selected_group(&self) -> Option<&AccountEntry> {
+        self.groups.get(self.group_index)
+    }
+
+    pub fn owner_change(&self) -> Option<u32> {
+        (self.user_index != self.original_user_index)
+            .then(|| self.selected_user().map(|user| user.id))
+            .flatten()
+    }
+
+    pub fn group_change(&self) -> Option<u32> {
+        (self.group_index != self.original_group_index)
+            .then(|| self.selected_group().map(|group| group.id))
+            .flatten()
+    }
+
+    pub fn is_checked(&self, field: ChownDialogField) -> bool {
+        match field {
+            ChownDialogField::Recursive => self.recursive,
+            ChownDialogField::Permission(field) => self
+                .permissions
+                .as_ref()
+                .is_some_and(|permissions| permissions.is_checked(field)),
+            ChownDialogField::Owner | ChownDialogField::Group => false,
+        }
+    }
+
+    pub fn file_mask(&self) -> PermissionMask {
+        self.permissions
+            .as_ref()
+            .map(ChmodDialogState::file_mask)
+            .unwrap_or_default()
+    }
+
+    pub fn dir_mask(&self) -> PermissionMask {
+        self.permissions
+            .as_ref()
+            .map(ChmodDialogState::dir_mask)
+            .unwrap_or_default()
+    }
+
+    fn move_focus(&mut self, delta: isize) {
+        let fields = self.fields();
+        let len = fields.len();
+        let index = fields
+            .iter()
+            .position(|field| *field == self.focus)
+            .unwrap_or(0);
+        let next = if delta.is_negative() {
+            index.checked_sub(delta.unsigned_abs()).unwrap_or(len - 1)
+        } else {
+            index.saturating_add(delta as usize) % len
+        };
+        self.focus = fields[next];
+    }
+
+    /// Space steps to the next account and a letter jumps to the next account
+    /// whose name starts with it.
+    fn insert(&mut self, character: char) {
+        match self.focus {
+            ChownDialogField::Owner => {
+                self.user_index = next_account_index(&self.users, self.user_index, character);
+            }
+            ChownDialogField::Group => {
+                self.group_index = next_account_index(&self.groups, self.group_index, character);
+            }
+            ChownDialogField::Recursive if character == ' ' => {
+                self.recursive = !self.recursive;
+            }
+            ChownDialogField::Permission(field) if character == ' ' => {
+                if let Some(permissions) = self.permissions.as_mut() {
+                    permissions.toggle(field);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn select_previous(&mut self) {
+        let (entries, index) = match self.focus {
+            ChownDialogField::Owner => (&self.users, &mut self.user_index),
+            ChownDialogField::Group => (&self.groups, &mut self.group_index),
+            _ => return,
+        };
+        if !entries.is_empty() {
+            *index = index.checked_sub(1).unwrap_or(entries.len() - 1);
+        }
+    }
+}
+
+fn next_account_index(entries: &[AccountEntry], current: usize, character: char) -> usize {
+    if entries.is_empty() {
+        return current;
+    }
+    let len = entries.len();
+    if character == ' ' {
+        return current.saturating_add(1) % len;
+    }
+    (1..=len)
+        .map(|offset| (current + offset) % len)
+        .find(|index| {
+            entries[*index]
+                .name
+                .chars()
+                .next()
+                .is_some_and(|first| first.eq_ignore_ascii_case(&character))
+        })
+        .unwrap_or(current)
+}
+
+/// Per-conflict prompt shown while a job waits under the "ask" overwrite
+/// policy. Up/Down pick the decision; Space or Tab toggles "apply to all".
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverwriteDialogState {
+    pub conflict: OverwriteConflict,
+    pub selected: usize,
+    pub apply_to_all: bool,
+}
+
+impl OverwriteDialogState {
+    pub fn new(conflict: OverwriteConflict) -> Self {
+        Self {
+            conflict,
+            selected: 1,
+            apply_to_all: false,
+        }
+    }
+
+    pub fn decision(&self) -> OverwriteDecision {
+        OverwriteDecision::ALL[self.selected.min(OverwriteDecision::ALL.len() - 1)]
+    }
+
+    pub fn answer(&self) -> OverwriteAnswer {
+        OverwriteAnswer {
+            decision: self.decision(),
+            apply_to_all: self.apply_to_all,
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = OverwriteDecision::ALL.len();
+        self.selected = if delta.is_negative() {
+            self.selected
+                .checked_sub(delta.unsigned_abs())
+                .unwrap_or(len - 1)
+        } else {
+            self.selected.saturating_add(delta as usize) % len
+        };
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum DialogKind {
     Confirm(ConfirmDialogState),
@@ -415,6 +895,10 @@ pub enum DialogKind {
     Listbox(ListboxDialogState),
     Find(FindDialogState),
     Filter(FilterDialogState),
+    Mask(MaskDialogState),
+    Chmod(ChmodDialogState),
+    Chown(ChownDialogState),
+    Overwrite(OverwriteDialogState),
 }
 
 #[derive(Clone, Debug)]
@@ -553,6 +1037,50 @@ impl DialogState {
         }
     }
 
+    pub fn mask(
+        title: impl Into<String>,
+        source_mask: &str,
+        destination_mask: &str,
+        name_mode: FindNameMode,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            kind: DialogKind::Mask(MaskDialogState {
+                source_mask: source_mask.to_string(),
+                destination_mask: destination_mask.to_string(),
+                name_mode,
+                focus: MaskDialogField::DestinationMask,
+                preview: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn chmod(target_label: impl Into<String>, mode: u32) -> Self {
+        Self {
+            title: String::from("Chmod"),
+            kind: DialogKind::Chmod(ChmodDialogState::new(target_label, mode)),
+        }
+    }
+
+    pub fn chown(state: ChownDialogState) -> Self {
+        let title = if state.permissions.is_some() {
+            "Advanced chown"
+        } else {
+            "Chown"
+        };
+        Self {
+            title: String::from(title),
+            kind: DialogKind::Chown(state),
+        }
+    }
+
+    pub fn overwrite(conflict: OverwriteConflict) -> Self {
+        Self {
+            title: String::from("File exists"),
+            kind: DialogKind::Overwrite(OverwriteDialogState::new(conflict)),
+        }
+    }
+
     pub fn demo_confirm() -> Self {
         Self::confirm("Confirm", "Proceed with this action?")
     }
@@ -580,7 +1108,12 @@ impl DialogState {
                 KeyContext::Input
             }
             DialogKind::Listbox(_) => KeyContext::Listbox,
-            DialogKind::Find(_) | DialogKind::Filter(_) => KeyContext::FindDialog,
+            DialogKind::Find(_)
+            | DialogKind::Filter(_)
+            | DialogKind::Mask(_)
+            | DialogKind::Chmod(_)
+            | DialogKind::Chown(_)
+            | DialogKind::Overwrite(_) => KeyContext::FindDialog,
         }
     }
 
@@ -742,6 +1275,96 @@ impl DialogState {
                 }
                 DialogEvent::Cancel => DialogTransition::Close(DialogResult::Canceled),
             },
+            DialogKind::Mask(mask) => match event {
+                DialogEvent::FocusNext | DialogEvent::MoveDown => {
+                    mask.move_focus(1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::MoveUp => {
+                    mask.move_focus(-1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::InsertChar(character) => {
+                    mask.insert(character);
+                    DialogTransition::Stay
+                }
+                DialogEvent::Backspace => {
+                    mask.backspace();
+                    DialogTransition::Stay
+                }
+                DialogEvent::Accept => DialogTransition::Close(DialogResult::MaskSubmitted {
+                    source_mask: mask.source_mask.clone(),
+                    destination_mask: mask.destination_mask.clone(),
+                    name_mode: mask.name_mode,
+                }),
+                DialogEvent::Cancel => DialogTransition::Close(DialogResult::Canceled),
+            },
+            DialogKind::Chmod(chmod) => match event {
+                DialogEvent::FocusNext | DialogEvent::MoveDown => {
+                    chmod.move_focus(1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::MoveUp => {
+                    chmod.move_focus(-1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::InsertChar(character) => {
+                    chmod.insert(character);
+                    DialogTransition::Stay
+                }
+                DialogEvent::Backspace => DialogTransition::Stay,
+                DialogEvent::Accept => DialogTransition::Close(DialogResult::ChmodSubmitted {
+                    file_mask: chmod.file_mask(),
+                    dir_mask: chmod.dir_mask(),
+                    recursive: chmod.recursive,
+                }),
+                DialogEvent::Cancel => DialogTransition::Close(DialogResult::Canceled),
+            },
+            DialogKind::Chown(chown) => match event {
+                DialogEvent::FocusNext | DialogEvent::MoveDown => {
+                    chown.move_focus(1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::MoveUp => {
+                    chown.move_focus(-1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::InsertChar(character) => {
+                    chown.insert(character);
+                    DialogTransition::Stay
+                }
+                DialogEvent::Backspace => {
+                    chown.select_previous();
+                    DialogTransition::Stay
+                }
+                DialogEvent::Accept => DialogTransition::Close(DialogResult::ChownSubmitted {
+                    owner: chown.owner_change(),
+                    group: chown.group_change(),
+                    file_mask: chown.file_mask(),
+                    dir_mask: chown.dir_mask(),
+                    recursive: chown.recursive,
+                }),
+                DialogEvent::Cancel => DialogTransition::Close(DialogResult::Canceled),
+            },
+            DialogKind::Overwrite(overwrite) => match event {
+                DialogEvent::MoveDown => {
+                    overwrite.move_selection(1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::MoveUp => {
+                    overwrite.move_selection(-1);
+                    DialogTransition::Stay
+                }
+                DialogEvent::FocusNext | DialogEvent::InsertChar(' ') => {
+                    overwrite.apply_to_all = !overwrite.apply_to_all;
+                    DialogTransition::Stay
+                }
+                DialogEvent::InsertChar(_) | DialogEvent::Backspace => DialogTransition::Stay,
+                DialogEvent::Accept => {
+                    DialogTransition::Close(DialogResult::OverwriteSubmitted(overwrite.answer()))
+                }
+                DialogEvent::Cancel => DialogTransition::Close(DialogResult::Canceled),
+            },
         }
     }
 }
@@ -776,6 +1399,24 @@ pub enum DialogResult {
     },
     FindSubmitted(Box<FindSpec>),
     FilterSubmitted(PanelFilter),
+    MaskSubmitted {
+        source_mask: String,
+        destination_mask: String,
+        name_mode: FindNameMode,
+    },
+    ChmodSubmitted {
+        file_mask: PermissionMask,
+        dir_mask: PermissionMask,
+        recursive: bool,
+    },
+    ChownSubmitted {
+        owner: Option<u32>,
+        group: Option<u32>,
+        file_mask: PermissionMask,
+        dir_mask: PermissionMask,
+        recursive: bool,
+    },
+    OverwriteSubmitted(OverwriteAnswer),
     Canceled,
 }
 
@@ -805,6 +1446,27 @@ impl DialogResult {
             Self::FilterSubmitted(filter) => {
                 format!("Filter accepted: {}", filter.display_pattern())
             }
+            Self::MaskSubmitted {
+                source_mask,
+                destination_mask,
+                ..
+            } => format!("Masks accepted: {source_mask} -> {destination_mask}"),
+            Self::ChmodSubmitted { dir_mask, .. } => {
+                format!("Chmod accepted: {}", dir_mask.label())
+            }
+            Self::ChownSubmitted { owner, group, .. } => {
+                let id_label = |id: &Option<u32>| {
+                    id.map_or_else(|| String::from("unchanged"), |id| id.to_string())
+                };
+                format!("Chown accepted: {}:{}", id_label(owner), id_label(group))
+            }
+            Self::OverwriteSubmitted(answer) => {
+                if answer.apply_to_all {
+                    format!("{} all", answer.decision.label())
+                } else {
+                    answer.decision.label().to_string()
+                }
+            }
             Self::Canceled => String::from("Dialog canceled"),
         }
     }
@@ -1004,4 +1666,128 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn chmod_dialog_applies_only_toggled_bits_and_limits_x_to_directories() {
+        let mut dialog = DialogState::chmod("demo", 0o100644);
+        assert_eq!(dialog.key_context(), KeyContext::FindDialog);
+        let DialogKind::Chmod(chmod) = &dialog.kind else {
+            panic!("expected chmod dialog");
+        };
+        assert_eq!(chmod.original_mode, 0o644);
+        assert_eq!(chmod.focus, ChmodDialogField::OwnerRead);
+
+        for _ in 0..2 {
+            dialog.handle_event(DialogEvent::MoveDown);
+        }
+        dialog.handle_event(DialogEvent::InsertChar(' '));
+        dialog.handle_event(DialogEvent::MoveDown);
+        dialog.handle_event(DialogEvent::InsertChar(' '));
+        for _ in 0..7 {
+            dialog.handle_event(DialogEvent::MoveUp);
+        }
+        assert_eq!(
+            dialog.handle_event(DialogEvent::InsertChar(' ')),
+            DialogTransition::Stay
+        );
+
+        let DialogKind::Chmod(chmod) = &dialog.kind else {
+            panic!("expected chmod dialog");
+        };
+        assert_eq!(chmod.focus, ChmodDialogField::DirectoryExecuteOnly);
+        assert_eq!(chmod.mode, 0o704);
+        assert_eq!(
+            dialog.handle_event(DialogEvent::Accept),
+            DialogTransition::Close(DialogResult::ChmodSubmitted {
+                file_mask: PermissionMask::new(0, 0o040),
+                dir_mask: PermissionMask::new(0o100, 0o040),
+                recursive: false,
+            })
+        );
+    }
+
+    #[test]
+    fn advanced_chown_dialog_picks_accounts_and_permission_bits() {
+        let users = vec![
+            AccountEntry::new("alice", 1000),
+            AccountEntry::new("bob", 1001),
+            AccountEntry::new("root", 0),
+        ];
+        let groups = vec![
+            AccountEntry::new("staff", 20),
+            AccountEntry::new("wheel", 10),
+        ];
+        let mut dialog = DialogState::chown(ChownDialogState::new(
+            "demo",
+            users,
+            groups,
+            2,
+            1,
+            Some(0o644),
+        ));
+        assert_eq!(dialog.title, "Advanced chown");
+
+        for event in [
+            DialogEvent::InsertChar('b'),
+            DialogEvent::InsertChar(' '),
+            DialogEvent::Backspace,
+            DialogEvent::FocusNext,
+            DialogEvent::InsertChar(' '),
+            DialogEvent::FocusNext,
+            DialogEvent::InsertChar(' '),
+        ] {
+            assert_eq!(dialog.handle_event(event), DialogTransition::Stay);
+        }
+        for _ in 0..6 {
+            dialog.handle_event(DialogEvent::FocusNext);
+        }
+        dialog.handle_event(DialogEvent::InsertChar(' '));
+
+        let DialogKind::Chown(chown) = &dialog.kind else {
+            panic!("expected chown dialog");
+        };
+        assert_eq!(
+            chown.focus,
+            ChownDialogField::Permission(ChmodDialogField::OwnerExecute)
+        );
+        assert_eq!(chown.selected_user(), Some(&AccountEntry::new("bob", 1001)));
+        assert_eq!(
+            chown.selected_group(),
+            Some(&AccountEntry::new("staff", 20))
+        );
+        assert_eq!(
+            dialog.handle_event(DialogEvent::Accept),
+            DialogTransition::Close(DialogResult::ChownSubmitted {
+                owner: Some(1001),
+                group: Some(20),
+                file_mask: PermissionMask::new(0o100, 0),
+                dir_mask: PermissionMask::new(0o100, 0),
+                recursive: true,
+            })
+        );
+    }
+
+    #[test]
+    fn chown_dialog_leaves_unpicked_owner_and_group_unchanged() {
+        let users = vec![AccountEntry::new("alice", 1000)];
+        let groups = vec![AccountEntry::new("staff", 20)];
+        let mut dialog =
+            DialogState::chown(ChownDialogState::new("demo", users, groups, 0, 0, None));
+        assert_eq!(dialog.title, "Chown");
+        let DialogKind::Chown(chown) = &dialog.kind else {
+            panic!("expected chown dialog");
+        };
+        assert_eq!(chown.fields().len(), 3);
+
+        assert_eq!(
+            dialog.handle_event(DialogEvent::Accept),
+            DialogTransition::Close(DialogResult::ChownSubmitted {
+                owner: None,
+                group: None,
+                file_mask: PermissionMask::default(),
+                dir_mask: PermissionMask::default(),
+                recursive: false,
+            })
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use std::path::{Path, PathBuf};
 
 use crate::dialog::DialogEvent;
+use crate::rename_mask::source_name;
+use crate::throttle::{format_rate, parse_rate};
 use crate::*;
 
 impl AppState {
@@ -16,6 +18,17 @@ impl AppState {
             AppCommand::OpenQuickCd => self.start_quick_cd_dialog(),
             AppCommand::OpenListboxDialog => self.start_overwrite_policy_dialog(),
             AppCommand::OpenSkinDialog => self.start_skin_dialog(),
+            AppCommand::OpenJobBandwidthLimit => self.start_job_bandwidth_dialog(),
+            AppCommand::OpenGlobalBandwidthLimit => self.start_global_bandwidth_dialog(),
+            AppCommand::OpenChmodDialog => self.start_chmod_dialog(),
+            AppCommand::OpenChownDialog => self.start_chown_dialog(false),
+            AppCommand::OpenAdvancedChownDialog => self.start_chown_dialog(true),
+            AppCommand::OpenLinkDialog => self.start_transfer_dialog(TransferKind::Link),
+            AppCommand::OpenSymlinkDialog => self.start_transfer_dialog(TransferKind::Symlink),
+            AppCommand::OpenRelativeSymlinkDialog => {
+                self.start_transfer_dialog(TransferKind::RelativeSymlink)
+            }
+            AppCommand::OpenEditSymlinkDialog => self.start_edit_symlink_dialog(),
             AppCommand::OpenListingFormat => {
                 self.open_panel_listing_format_dialog(self.active_panel)
             }
@@ -46,7 +59,9 @@ impl AppState {
                 }
             }
             AppCommand::DialogFocusNext => {
-                if !self.toggle_panel_sort_dialog_reverse() && !self.toggle_panelize_dialog_focus()
+                if !self.toggle_panel_sort_dialog_reverse()
+                    && !self.cycle_transfer_preserve_level()
+                    && !self.toggle_panelize_dialog_focus()
                 {
                     self.handle_dialog_event(DialogEvent::FocusNext);
                 }
@@ -57,14 +72,27 @@ impl AppState {
                 if quick_cd_changed {
                     self.sync_quick_cd_search();
                 }
+                self.sync_transfer_mask_preview();
             }
             AppCommand::DialogInputChar(ch) => {
-                if ch != ' ' || !self.toggle_panel_sort_dialog_reverse() {
+                let handled = match ch {
+                    ' ' => {
+                        self.toggle_panel_sort_dialog_reverse()
+                            || self.cycle_transfer_preserve_level()
+                    }
+                    'l' => self.cycle_transfer_follow_links(),
+                    'h' => self.toggle_transfer_hard_links(),
+                    'm' => self.start_transfer_mask(),
+                    'p' => self.start_transfer_preview(),
+                    _ => false,
+                };
+                if !handled {
                     let quick_cd_changed = self.quick_cd_dialog_is_active();
                     self.handle_dialog_event(DialogEvent::InsertChar(ch));
                     if quick_cd_changed {
                         self.sync_quick_cd_search();
                     }
+                    self.sync_transfer_mask_preview();
                 }
             }
             AppCommand::DialogListboxUp => {
@@ -159,6 +187,111 @@ impl AppState {
         true
     }
 
+    /// Steps the preservation level of an open copy/move overwrite dialog.
+    fn cycle_transfer_preserve_level(&mut self) -> bool {
+        let Some(Route::Dialog(dialog)) = self.routes.last_mut() else {
+            return false;
+        };
+        let (kind, preserve, follow_links, hard_links) = match dialog.action_mut() {
+            Some(
+                PendingDialogAction::TransferOverwrite {
+                    kind,
+                    preserve,
+                    follow_links,
+                    hard_links,
+                    ..
+                }
+                | PendingDialogAction::TransferRenameOverwrite {
+                    kind,
+                    preserve,
+                    follow_links,
+                    hard_links,
+                    ..
+                },
+            ) if matches!(kind, TransferKind::Copy | TransferKind::Move) => {
+                *preserve = preserve.next();
+                (*kind, *preserve, *follow_links, *hard_links)
+            }
+            _ => return false,
+        };
+        if let DialogKind::Listbox(listbox) = &mut dialog.kind {
+            listbox.footer_hint =
+                transfer_overwrite_footer(kind, preserve, follow_links, hard_links);
+        }
+        self.set_status(format!("{}: preserve {}", kind.title(), preserve.label()));
+        true
+    }
+
+    /// Steps which symlinks an open copy overwrite dialog follows.
+    fn cycle_transfer_follow_links(&mut self) -> bool {
+        let Some(Route::Dialog(dialog)) = self.routes.last_mut() else {
+            return false;
+        };
+        let (preserve, follow_links, hard_links) = match dialog.action_mut() {
+            Some(
+                PendingDialogAction::TransferOverwrite {
+                    kind: TransferKind::Copy,
+                    preserve,
+                    follow_links,
+                    hard_links,
+                    ..
+                }
+                | PendingDialogAction::TransferRenameOverwrite {
+                    kind: TransferKind::Copy,
+                    preserve,
+                    follow_links,
+                    hard_links,
+                    ..
+                },
+            ) => {
+                *follow_links = follow_links.next();
+                (*preserve, *follow_links, *hard_links)
+            }
+            _ => return false,
+        };
+        if let DialogKind::Listbox(listbox) = &mut dialog.kind {
+            listbox.footer_hint =
+                transfer_overwrite_footer(TransferKind::Copy, preserve, follow_links, hard_links);
+        }
+        self.set_status(format!("Copy: follow links {}", follow_links.label()));
+        true
+    }
+
+    /// Toggles whether an open copy overwrite dialog keeps hard links.
+    fn toggle_transfer_hard_links(&mut self) -> bool {
+        let Some(Route::Dialog(dialog)) = self.routes.last_mut() else {
+            return false;
+        };
+        let (preserve, follow_links, hard_links) = match dialog.action_mut() {
+            Some(
+                PendingDialogAction::TransferOverwrite {
+                    kind: TransferKind::Copy,
+                    preserve,
+                    follow_links,
+                    hard_links,
+                    ..
+                }
+                | PendingDialogAction::TransferRenameOverwrite {
+                    kind: TransferKind::Copy,
+                    preserve,
+                    follow_links,
+                    hard_links,
+                    ..
+                },
+            ) => {
+                *hard_links = !*hard_links;
+                (*preserve, *follow_links, *hard_links)
+            }
+            _ => return false,
+        };
+        if let DialogKind::Listbox(listbox) = &mut dialog.kind {
+            listbox.footer_hint =
+                transfer_overwrite_footer(TransferKind::Copy, preserve, follow_links, hard_links);
+        }
+        self.set_status(format!("Copy: hard links {}", hard_links_label(hard_links)));
+        true
+    }
+
     pub(crate) fn start_move_dialog(&mut self) {
         let sources = self.selected_operation_paths();
         if sources.is_empty() {
@@ -218,7 +351,15 @@ impl AppState {
     fn start_transfer_dialog(&mut self, kind: TransferKind) {
         let sources = self.selected_operation_paths();
         if sources.is_empty() {
-            self.set_status("Copy/Move requires a selected or tagged entry");
+            match kind {
+                TransferKind::Copy | TransferKind::Move => {
+                    self.set_status("Copy/Move requires a selected or tagged entry");
+                }
+                _ => self.set_status(format!(
+                    "{} requires a selected or tagged entry",
+                    kind.title()
+                )),
+            }
             return;
         }
 
@@ -241,10 +382,7 @@ impl AppState {
         destination_dir: PathBuf,
         origin: OperationOrigin,
     ) {
-        let title = match kind {
-            TransferKind::Copy => "Copy",
-            TransferKind::Move => "Move",
-        };
+        let title = kind.title();
         self.push_dialog(
             DialogState::input(
                 title,
@@ -281,22 +419,49 @@ impl AppState {
         targets: Vec<PathBuf>,
         origin: OperationOrigin,
     ) {
-        let message = if targets.len() == 1 {
-            let name = targets[0]
-                .file_name()
-                .map(|name| name.to_string_lossy().into_owned())
-                .unwrap_or_else(|| targets[0].to_string_lossy().into_owned());
-            format!("Delete '{name}'?")
-        } else {
-            format!("Delete {} selected items?", targets.len())
-        };
+        let items = DeleteMode::ALL
+            .iter()
+            .map(|mode| mode.label().to_string())
+            .collect();
         self.push_dialog(
-            DialogState::confirm("Delete", message),
+            DialogState::listbox_with_hint(
+                "Delete",
+                items,
+                self.default_delete_mode().index(),
+                format!(
+                    "{} (p preview)",
+                    delete_confirmation_message("Delete", &targets)
+                ),
+            ),
             PendingDialogAction::ConfirmDelete { targets, origin },
         );
         self.set_status("Confirm delete");
     }
 
+    pub(crate) fn start_permanent_delete(&mut self) {
+        let targets = self.selected_operation_paths();
+        if targets.is_empty() {
+            self.set_status("Delete requires a selected or tagged entry");
+            return;
+        }
+        let origin = OperationOrigin::Panel(self.active_panel);
+        if !self.settings.confirmation.confirm_delete {
+            self.queue_delete_job_from(targets, origin);
+            return;
+        }
+        self.push_dialog(
+            DialogState::confirm(
+                "Delete permanently",
+                format!(
+                    "{} (p preview)",
+                    delete_confirmation_message("Permanently delete", &targets)
+                ),
+            ),
+            PendingDialogAction::ConfirmPermanentDelete { targets, origin },
+        );
+        self.set_status("Confirm permanent delete");
+    }
+
     pub(crate) fn start_quit_confirmation(&mut self) {
         self.push_dialog(
             DialogState::confirm("Quit", "Exit rc?"),
@@ -328,6 +493,123 @@ impl AppState {
         self.set_status("Rename/Move: enter new name");
     }
 
+    pub(crate) fn start_edit_symlink_dialog(&mut self) {
+        let Some(entry) = self.selected_non_parent_entry() else {
+            self.set_status("Edit symlink requires a selected entry");
+            return;
+        };
+        let link = entry.path.clone();
+        let name = entry.name.clone();
+        let Ok(current_target) = fs::read_link(&link) else {
+            self.set_status(format!("Edit symlink: '{name}' is not a symlink"));
+            return;
+        };
+
+        self.push_dialog(
+            DialogState::input(
+                "Edit symlink",
+                format!("Symlink '{name}' points to:"),
+                current_target.to_string_lossy(),
+            ),
+            PendingDialogAction::EditSymlink {
+                link,
+                current_target,
+            },
+        );
+        self.set_status("Edit symlink: enter new target");
+    }
+
+    fn queue_edit_symlink_job(&mut self, link: PathBuf, target: PathBuf) {
+        self.queue_filesystem_job(
+            JobRequest::EditSymlink { link, target },
+            OperationOrigin::Panel(self.active_panel),
+        );
+    }
+
+    /// Targets, a display label and the metadata of the first target for the
+    /// chmod and chown dialogs.
+    fn attribute_dialog_targets(
+        &mut self,
+        operation: &str,
+    ) -> Option<(Vec<PathBuf>, String, FileEntryMetadata)> {
+        let targets = self.selected_operation_paths();
+        let Some(first) = targets.first() else {
+            self.set_status(format!("{operation} requires a selected or tagged entry"));
+            return None;
+        };
+        let metadata = self
+            .active_panel()
+            .entries
+            .iter()
+            .find(|entry| &entry.path == first)
+            .map(|entry| entry.metadata)
+            .unwrap_or_default();
+        let target_label = if targets.len() == 1 {
+            first
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| first.to_string_lossy().into_owned())
+        } else {
+            format!("{} selected items", targets.len())
+        };
+        Some((targets, target_label, metadata))
+    }
+
+    pub(crate) fn start_chmod_dialog(&mut self) {
+        let Some((targets, target_label, metadata)) = self.attribute_dialog_targets("Chmod") else {
+            return;
+        };
+        let Some(mode) = metadata.mode else {
+            self.set_status("Chmod unavailable: permission bits are unknown");
+            return;
+        };
+        self.push_dialog(
+            DialogState::chmod(target_label, mode),
+            PendingDialogAction::Chmod { targets },
+        );
+        self.set_status("Chmod: toggle permission bits");
+    }
+
+    pub(crate) fn start_chown_dialog(&mut self, advanced: bool) {
+        let operation = if advanced { "Advanced chown" } else { "Chown" };
+        let Some((targets, target_label, metadata)) = self.attribute_dialog_targets(operation)
+        else {
+            return;
+        };
+        let (Some(user_id), Some(group_id)) = (metadata.user_id, metadata.group_id) else {
+            self.set_status(format!("{operation} unavailable: owner is unknown"));
+            return;
+        };
+        let mode = if advanced {
+            let Some(mode) = metadata.mode else {
+                self.set_status(format!(
+                    "{operation} unavailable: permission bits are unknown"
+                ));
+                return;
+            };
+            Some(mode)
+        } else {
+            None
+        };
+
+        let mut users = accounts::load_users();
+        let mut groups = accounts::load_groups();
+        let user_index = accounts::ensure_account_id(&mut users, user_id);
+        let group_index = accounts::ensure_account_id(&mut groups, group_id);
+        self.push_dialog(
+            DialogState::chown(ChownDialogState::new(
+                target_label,
+                users,
+                groups,
+                user_index,
+                group_index,
+                mode,
+            )),
+            PendingDialogAction::Chown { targets },
+        );
+        self.set_status(format!("{operation}: pick owner and group"));
+    }
+
     pub(crate) fn start_mkdir_dialog(&mut self) {
         let base_dir = self.active_panel().cwd.clone();
         self.start_mkdir_dialog_at(base_dir, OperationOrigin::Panel(self.active_panel));
@@ -350,6 +632,70 @@ impl AppState {
         self.set_status("Choose default overwrite policy");
     }
 
+    /// Shows the conflict a worker is blocked on; the job resumes once the
+    /// dialog is answered and aborts when it is dismissed.
+    pub(crate) fn prompt_overwrite_conflict(&mut self, job_id: JobId, conflict: OverwriteConflict) {
+        let name = conflict
+            .destination
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| conflict.destination.to_string_lossy().into_owned());
+        self.push_dialog(
+            DialogState::overwrite(conflict),
+            PendingDialogAction::ResolveOverwrite { job_id },
+        );
+        self.set_status(format!("Job #{job_id}: '{name}' already exists"));
+    }
+
+    /// Shows the item a worker failed on; the job resumes once the dialog
+    /// is answered and aborts when it is dismissed.
+    pub(crate) fn prompt_item_failure(&mut self, job_id: JobId, failure: ItemFailure) {
+        let name = failure
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| failure.path.to_string_lossy().into_owned());
+        let answers = ItemErrorAnswer::ALL
+            .iter()
+            .map(|answer| String::from(answer.label()))
+            .collect();
+        let skip = ItemErrorAnswer::ALL
+            .iter()
+            .position(|answer| *answer == ItemErrorAnswer::Skip)
+            .unwrap_or(0);
+        self.push_dialog(
+            DialogState::listbox_with_hint(
+                format!("Job #{job_id} failed on '{name}'"),
+                answers,
+                skip,
+                failure.message.clone(),
+            ),
+            PendingDialogAction::ResolveItemError { job_id },
+        );
+        self.set_status(format!(
+            "Job #{job_id}: {}: {}",
+            failure.path.to_string_lossy(),
+            failure.message
+        ));
+    }
+
+    /// Drops prompts left open by a job that finished or was canceled.
+    pub(crate) fn dismiss_job_prompts(&mut self, job_id: JobId) {
+        self.routes.retain(|route| {
+            !matches!(
+                route,
+                Route::Dialog(dialog)
+                    if matches!(
+                        dialog.action(),
+                        Some(
+                            PendingDialogAction::ResolveOverwrite { job_id: id }
+                            | PendingDialogAction::ResolveItemError { job_id: id }
+                        ) if *id == job_id
+                    )
+            )
+        });
+    }
+
     pub(crate) fn start_skin_dialog(&mut self) {
         if self.available_skins.is_empty() {
             self.set_status("No skins available");
@@ -370,6 +716,42 @@ impl AppState {
         self.set_status("Choose skin");
     }
 
+    fn start_job_bandwidth_dialog(&mut self) {
+        let Some(job) = self.selected_job_record().filter(|job| {
+            matches!(
+                job.status,
+                JobStatus::Queued | JobStatus::Running | JobStatus::Paused
+            )
+        }) else {
+            self.set_status("No queued or running job selected");
+            return;
+        };
+        let job_id = job.id;
+        let current = bandwidth_limit_input(job.bandwidth_limit);
+        self.push_dialog(
+            DialogState::input(
+                "Bandwidth limit",
+                format!("Job #{job_id} limit (e.g. 10M, 0 = none):"),
+                current,
+            ),
+            PendingDialogAction::SetJobBandwidthLimit { job_id },
+        );
+        self.set_status(format!("Job #{job_id}: set bandwidth limit"));
+    }
+
+    pub(crate) fn start_global_bandwidth_dialog(&mut self) {
+        let current = bandwidth_limit_input(self.settings.configuration.bandwidth_limit);
+        self.push_dialog(
+            DialogState::input(
+                "Bandwidth limit",
+                "Limit for all jobs (e.g. 10M, 0 = none):",
+                current,
+            ),
+            PendingDialogAction::SetGlobalBandwidthLimit,
+        );
+        self.set_status("Set bandwidth limit for all jobs");
+    }
+
     pub(crate) fn finish_dialog(&mut self, result: DialogResult) {
         let pending = match self.routes.last() {
             Some(Route::Dialog(_)) => match self.routes.pop() {
@@ -385,15 +767,77 @@ impl AppState {
             (None, result) => self.set_status(result.status_line()),
             (
                 Some(PendingDialogAction::ConfirmDelete { targets, origin }),
+                DialogResult::ListboxSubmitted {
+                    index: Some(index), ..
+                },
+            ) => match DeleteMode::ALL.get(index) {
+                Some(mode) => self.queue_delete_job_with_mode(targets, origin, *mode),
+                None => self.set_status("Delete canceled"),
+            },
+            (
+                Some(PendingDialogAction::ConfirmPermanentDelete { targets, origin }),
                 DialogResult::ConfirmAccepted,
             ) => {
                 self.queue_delete_job_from(targets, origin);
             }
-            (Some(PendingDialogAction::ConfirmDelete { .. }), DialogResult::ConfirmDeclined)
-            | (Some(PendingDialogAction::ConfirmDelete { .. }), DialogResult::Canceled) => {
+            (Some(PendingDialogAction::ConfirmDelete { .. }), _)
+            | (
+                Some(PendingDialogAction::ConfirmPermanentDelete { .. }),
+                DialogResult::ConfirmDeclined,
+            )
+            | (Some(PendingDialogAction::ConfirmPermanentDelete { .. }), DialogResult::Canceled) => {
                 self.set_status("Delete canceled");
             }
+            (
+                Some(PendingDialogAction::ResolveOverwrite { job_id }),
+                DialogResult::OverwriteSubmitted(answer),
+            ) => {
+                if self.jobs.answer_overwrite(job_id, answer) {
+                    self.set_status(format!(
+                        "Job #{job_id}: {}",
+                        DialogResult::OverwriteSubmitted(answer).status_line()
+                    ));
+                } else {
+                    self.set_status(format!("Job #{job_id} is no longer waiting"));
+                }
+            }
+            (Some(PendingDialogAction::ResolveOverwrite { job_id }), _) => {
+                if self.request_cancel_for_job(job_id) {
+                    self.set_status(format!("Job #{job_id}: aborting on overwrite conflict"));
+                }
+            }
+            (Some(PendingDialogAction::ResolveItemError { job_id }), result) => {
+                // Dismissing the prompt aborts, so the job fails with the error.
+                let answer = match result {
+                    DialogResult::ListboxSubmitted {
+                        index: Some(index), ..
+                    } => ItemErrorAnswer::ALL
+                        .get(index)
+                        .copied()
+                        .unwrap_or(ItemErrorAnswer::Abort),
+                    _ => ItemErrorAnswer::Abort,
+                };
+                if self.jobs.answer_item_error(job_id, answer) {
+                    self.set_status(format!("Job #{job_id}: {}", answer.label()));
+                } else {
+                    self.set_status(format!("Job #{job_id} is no longer waiting"));
+                }
+            }
+            (
+                Some(PendingDialogAction::ConfirmTrashPurge { items }),
+                DialogResult::ConfirmAccepted,
+            ) => {
+                self.queue_trash_purge_job(items);
+            }
+            (
+                Some(PendingDialogAction::ConfirmTrashPurge { .. }),
+                DialogResult::ConfirmDeclined,
+            )
+            | (Some(PendingDialogAction::ConfirmTrashPurge { .. }), DialogResult::Canceled) => {
+                self.set_status("Purge canceled");
+            }
             (Some(PendingDialogAction::ConfirmQuit), DialogResult::ConfirmAccepted) => {
+                self.freeze_job_queue();
                 self.request_cancel_for_all_jobs();
                 self.pending_quit = true;
                 self.set_status("Quitting...");
@@ -448,6 +892,113 @@ impl AppState {
             (Some(PendingDialogAction::RenameEntry { .. }), DialogResult::Canceled) => {
                 self.set_status("Rename canceled");
             }
+            (
+                Some(PendingDialogAction::Chmod { targets }),
+                DialogResult::ChmodSubmitted {
+                    file_mask,
+                    dir_mask,
+                    recursive,
+                },
+            ) => {
+                if file_mask.is_empty() && dir_mask.is_empty() {
+                    self.set_status("Chmod skipped: permissions unchanged");
+                    return;
+                }
+                self.queue_filesystem_job(
+                    JobRequest::Chmod {
+                        targets,
+                        file_mask,
+                        dir_mask,
+                        recursive,
+                    },
+                    OperationOrigin::Panel(self.active_panel),
+                );
+            }
+            (Some(PendingDialogAction::Chmod { .. }), DialogResult::Canceled) => {
+                self.set_status("Chmod canceled");
+            }
+            (
+                Some(PendingDialogAction::Chown { targets }),
+                DialogResult::ChownSubmitted {
+                    owner,
+                    group,
+                    file_mask,
+                    dir_mask,
+                    recursive,
+                },
+            ) => {
+                if owner.is_none() && group.is_none() && file_mask.is_empty() && dir_mask.is_empty()
+                {
+                    self.set_status("Chown skipped: owner, group and permissions unchanged");
+                    return;
+                }
+                self.queue_filesystem_job(
+                    JobRequest::Chown {
+                        targets,
+                        owner,
+                        group,
+                        file_mask,
+                        dir_mask,
+                        recursive,
+                    },
+                    OperationOrigin::Panel(self.active_panel),
+                );
+            }
+            (Some(PendingDialogAction::Chown { .. }), DialogResult::Canceled) => {
+                self.set_status("Chown canceled");
+            }
+            (
+                Some(PendingDialogAction::EditSymlink {
+                    link,
+                    current_target,
+                }),
+                DialogResult::InputSubmitted(value),
+            ) => {
+                if value.is_empty() {
+                    self.set_status("Edit symlink canceled: empty target");
+                    return;
+                }
+                let target = PathBuf::from(value);
+                if target == current_target {
+                    self.set_status("Edit symlink skipped: target unchanged");
+                    return;
+                }
+                // Relative targets resolve from the directory holding the link.
+                let resolved = link
+                    .parent()
+                    .map_or_else(|| target.clone(), |parent| parent.join(&target));
+                if fs::metadata(&resolved).is_err() {
+                    self.push_dialog(
+                        DialogState::confirm(
+                            "Edit symlink",
+                            format!(
+                                "Target '{}' does not exist. Retarget anyway?",
+                                target.to_string_lossy()
+                            ),
+                        ),
+                        PendingDialogAction::ConfirmDanglingSymlink { link, target },
+                    );
+                    self.set_status("Edit symlink: new target does not resolve");
+                    return;
+                }
+                self.queue_edit_symlink_job(link, target);
+            }
+            (Some(PendingDialogAction::EditSymlink { .. }), DialogResult::Canceled) => {
+                self.set_status("Edit symlink canceled");
+            }
+            (
+                Some(PendingDialogAction::ConfirmDanglingSymlink { link, target }),
+                DialogResult::ConfirmAccepted,
+            ) => {
+                self.queue_edit_symlink_job(link, target);
+            }
+            (
+                Some(PendingDialogAction::ConfirmDanglingSymlink { .. }),
+                DialogResult::ConfirmDeclined,
+            )
+            | (Some(PendingDialogAction::ConfirmDanglingSymlink { .. }), DialogResult::Canceled) => {
+                self.set_status("Edit symlink canceled");
+            }
             (
                 Some(PendingDialogAction::TransferDestination {
                     kind,
@@ -472,16 +1023,16 @@ impl AppState {
                 };
                 if self.settings.confirmation.confirm_overwrite {
                     let selected = overwrite_policy_index(self.overwrite_policy());
+                    let preserve = self.settings.configuration.preserve_level;
                     self.push_dialog(
-                        DialogState::listbox(
-                            "Overwrite Policy",
-                            overwrite_policy_items(),
-                            selected,
-                        ),
+                        transfer_overwrite_dialog(kind, selected, preserve),
                         PendingDialogAction::TransferOverwrite {
                             kind,
                             sources,
                             destination_dir,
+                            preserve,
+                            follow_links: FollowLinks::Never,
+                            hard_links: false,
                             origin,
                         },
                     );
@@ -492,6 +1043,7 @@ impl AppState {
                         sources,
                         destination_dir,
                         self.overwrite_policy(),
+                        self.settings.configuration.preserve_level,
                         origin,
                     );
                 }
@@ -530,16 +1082,16 @@ impl AppState {
                 if default_new_name == new_name {
                     if self.settings.confirmation.confirm_overwrite {
                         let selected = overwrite_policy_index(self.overwrite_policy());
+                        let preserve = self.settings.configuration.preserve_level;
                         self.push_dialog(
-                            DialogState::listbox(
-                                "Overwrite Policy",
-                                overwrite_policy_items(),
-                                selected,
-                            ),
+                            transfer_overwrite_dialog(kind, selected, preserve),
                             PendingDialogAction::TransferOverwrite {
                                 kind,
                                 sources,
                                 destination_dir: destination,
+                                preserve,
+                                follow_links: FollowLinks::Never,
+                                hard_links: false,
                                 origin,
                             },
                         );
@@ -550,6 +1102,7 @@ impl AppState {
                             sources,
                             destination,
                             self.overwrite_policy(),
+                            self.settings.configuration.preserve_level,
                             origin,
                         );
                     }
@@ -564,17 +1117,17 @@ impl AppState {
                 );
                 if self.settings.confirmation.confirm_overwrite {
                     let selected = overwrite_policy_index(self.overwrite_policy());
+                    let preserve = self.settings.configuration.preserve_level;
                     self.push_dialog(
-                        DialogState::listbox(
-                            "Overwrite Policy",
-                            overwrite_policy_items(),
-                            selected,
-                        ),
+                        transfer_overwrite_dialog(kind, selected, preserve),
                         PendingDialogAction::TransferRenameOverwrite {
                             kind,
                             sources,
                             destination_dir: destination,
                             destination_names: rename_destinations,
+                            preserve,
+                            follow_links: FollowLinks::Never,
+                            hard_links: false,
                             origin,
                         },
                     );
@@ -586,6 +1139,7 @@ impl AppState {
                         destination,
                         rename_destinations,
                         self.overwrite_policy(),
+                        self.settings.configuration.preserve_level,
                         origin,
                     );
                 }
@@ -596,6 +1150,9 @@ impl AppState {
                     sources,
                     destination_dir,
                     destination_names,
+                    preserve,
+                    follow_links,
+                    hard_links,
                     origin,
                 }),
                 DialogResult::ListboxSubmitted { index, .. },
@@ -603,14 +1160,18 @@ impl AppState {
                 let overwrite = index
                     .map(overwrite_policy_from_index)
                     .unwrap_or(self.overwrite_policy());
-                self.queue_copy_or_move_job_with_names(
-                    kind,
-                    sources,
-                    destination_dir,
-                    destination_names,
-                    overwrite,
-                    origin,
-                );
+                let request = self
+                    .transfer_request(
+                        kind,
+                        sources,
+                        destination_dir,
+                        Some(destination_names),
+                        overwrite,
+                        preserve,
+                    )
+                    .with_follow_links(follow_links)
+                    .with_hard_links(hard_links);
+                self.queue_filesystem_job(request, origin);
             }
             (Some(PendingDialogAction::TransferRenameOverwrite { .. }), DialogResult::Canceled) => {
                 self.set_status("Copy/Move canceled");
@@ -623,6 +1184,9 @@ impl AppState {
                     kind,
                     sources,
                     destination_dir,
+                    preserve,
+                    follow_links,
+                    hard_links,
                     origin,
                 }),
                 DialogResult::ListboxSubmitted { index, .. },
@@ -630,11 +1194,97 @@ impl AppState {
                 let overwrite = index
                     .map(overwrite_policy_from_index)
                     .unwrap_or(self.overwrite_policy());
-                self.queue_copy_or_move_job(kind, sources, destination_dir, overwrite, origin);
+                let request = self
+                    .transfer_request(kind, sources, destination_dir, None, overwrite, preserve)
+                    .with_follow_links(follow_links)
+                    .with_hard_links(hard_links);
+                self.queue_filesystem_job(request, origin);
             }
             (Some(PendingDialogAction::TransferOverwrite { .. }), DialogResult::Canceled) => {
                 self.set_status("Copy/Move canceled");
             }
+            (
+                Some(PendingDialogAction::ResumeInterruptedJobs),
+                DialogResult::ListboxSubmitted { index, .. },
+            ) => match index {
+                Some(0) => self.resume_interrupted_jobs(),
+                Some(1) => self.discard_interrupted_jobs(),
+                // The job rows only show what was already done.
+                _ => {
+                    self.push_interrupted_jobs_dialog();
+                    self.set_status("Choose Resume all or Discard all");
+                }
+            },
+            (Some(PendingDialogAction::ResumeInterruptedJobs), DialogResult::Canceled) => {
+                self.set_status("Unfinished jobs kept for the next start");
+            }
+            (
+                Some(PendingDialogAction::TransferMask {
+                    sources,
+                    destination_dir,
+                }),
+                DialogResult::MaskSubmitted {
+                    source_mask,
+                    destination_mask,
+                    name_mode,
+                },
+            ) => self.apply_transfer_mask(
+                sources,
+                destination_dir,
+                &source_mask,
+                &destination_mask,
+                name_mode,
+            ),
+            (Some(PendingDialogAction::TransferMask { .. }), DialogResult::Canceled) => {
+                self.set_status("Rename masks canceled");
+            }
+            (Some(PendingDialogAction::ShowTransferPreview), _) => {
+                self.set_status("Back to the confirmation");
+            }
+            (Some(PendingDialogAction::ShowSkippedItems), _) => {}
+            (
+                Some(PendingDialogAction::SetJobBandwidthLimit { job_id }),
+                DialogResult::InputSubmitted(value),
+            ) => {
+                let Some(limit) = parse_rate(&value) else {
+                    self.set_status(format!("Invalid bandwidth limit '{}'", value.trim()));
+                    return;
+                };
+                if self.jobs.set_bandwidth_limit(job_id, limit) {
+                    self.set_status(format!(
+                        "Job #{job_id} bandwidth limit: {}",
+                        bandwidth_limit_label(limit)
+                    ));
+                } else {
+                    self.set_status(format!("Job #{job_id} already finished"));
+                }
+            }
+            (
+                Some(PendingDialogAction::SetGlobalBandwidthLimit),
+                DialogResult::InputSubmitted(value),
+            ) => {
+                let Some(limit) = parse_rate(&value) else {
+                    self.set_status(format!("Invalid bandwidth limit '{}'", value.trim()));
+                    return;
+                };
+                self.settings.configuration.bandwidth_limit = limit;
+                self.settings.mark_dirty();
+                self.jobs.set_global_bandwidth_limit(limit);
+                self.refresh_settings_entries();
+                self.set_status(format!(
+                    "Bandwidth limit for all jobs: {}",
+                    bandwidth_limit_label(limit)
+                ));
+            }
+            (
+                Some(
+                    PendingDialogAction::SetJobBandwidthLimit { .. }
+                    | PendingDialogAction::SetGlobalBandwidthLimit,
+                ),
+                DialogResult::Canceled,
+            ) => {
+                self.set_status("Bandwidth limit unchanged");
+            }
             (
                 Some(PendingDialogAction::SetDefaultOverwritePolicy),
                 DialogResult::ListboxSubmitted { index, .. },
@@ -955,11 +1605,81 @@ fn panel_sort_dialog_footer(reverse: bool) -> String {
     )
 }
 
+fn delete_confirmation_message(verb: &str, targets: &[PathBuf]) -> String {
+    if targets.len() == 1 {
+        let name = targets[0]
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| targets[0].to_string_lossy().into_owned());
+        format!("{verb} '{name}'?")
+    } else {
+        format!("{verb} {} selected items?", targets.len())
+    }
+}
+
+fn bandwidth_limit_input(limit: u64) -> String {
+    if limit == 0 {
+        String::from("0")
+    } else {
+        format_rate(limit).trim_end_matches("/s").to_string()
+    }
+}
+
+fn bandwidth_limit_label(limit: u64) -> String {
+    if limit == 0 {
+        String::from("unlimited")
+    } else {
+        format_rate(limit)
+    }
+}
+
+fn transfer_overwrite_dialog(
+    kind: TransferKind,
+    selected: usize,
+    preserve: PreserveLevel,
+) -> DialogState {
+    let mut dialog = DialogState::listbox("Overwrite Policy", overwrite_policy_items(), selected);
+    if let DialogKind::Listbox(listbox) = &mut dialog.kind {
+        listbox.footer_hint = transfer_overwrite_footer(kind, preserve, FollowLinks::Never, false);
+    }
+    dialog
+}
+
+/// Only copies and moves carry attributes, so links get no preserve toggle;
+/// only copies can follow symlinks and keep hard links.
+fn transfer_overwrite_footer(
+    kind: TransferKind,
+    preserve: PreserveLevel,
+    follow_links: FollowLinks,
+    hard_links: bool,
+) -> Option<String> {
+    let links = if kind == TransferKind::Copy {
+        format!(
+            " | l links: {} | h hard links: {}",
+            follow_links.label(),
+            hard_links_label(hard_links)
+        )
+    } else {
+        String::new()
+    };
+    matches!(kind, TransferKind::Copy | TransferKind::Move).then(|| {
+        format!(
+            "Preserve: {} | Space/Tab cycle{links} | m masks | p preview | Enter apply | Esc cancel",
+            preserve.label()
+        )
+    })
+}
+
+fn hard_links_label(hard_links: bool) -> &'static str {
+    if hard_links { "keep" } else { "copy" }
+}
+
 fn overwrite_policy_items() -> Vec<String> {
     vec![
         String::from("Overwrite existing"),
         String::from("Skip existing"),
         String::from("Rename destination"),
+        String::from("Ask for each conflict"),
     ]
 }
 
@@ -972,7 +1692,7 @@ fn parse_destination_path(base_dir: &Path, value: &str) -> PathBuf {
     }
 }
 
-fn most_common_file_extension_pattern(sources: &[PathBuf]) -> String {
+pub(crate) fn most_common_file_extension_pattern(sources: &[PathBuf]) -> String {
     let mut common_extension: Option<String> = None;
     for source in sources {
         let Some(name) = source.file_name() else {
@@ -1012,11 +1732,12 @@ fn rename_sources(
     sources
         .iter()
         .map(|source| {
-            let source_name = source
-                .file_name()
-                .map_or_else(|| source.to_string_lossy(), |name| name.to_string_lossy())
-                .into_owned();
-            rename_source_name(&source_name, old_pattern, new_pattern, use_shell_patterns)
+            rename_source_name(
+                &source_name(source),
+                old_pattern,
+                new_pattern,
+                use_shell_patterns,
+            )
         })
         .collect()
 }
@@ -1072,14 +1793,16 @@ fn overwrite_policy_index(policy: OverwritePolicy) -> usize {
         OverwritePolicy::Overwrite => 0,
         OverwritePolicy::Skip => 1,
         OverwritePolicy::Rename => 2,
+        OverwritePolicy::Ask => 3,
     }
 }
 
-fn overwrite_policy_from_index(index: usize) -> OverwritePolicy {
+pub(crate) fn overwrite_policy_from_index(index: usize) -> OverwritePolicy {
     match index {
         0 => OverwritePolicy::Overwrite,
         1 => OverwritePolicy::Skip,
         2 => OverwritePolicy::Rename,
+        3 => OverwritePolicy::Ask,
         _ => OverwritePolicy::Skip,
     }
 }
@@ -147,6 +147,15 @@ Return to [File manager](file-manager).",
 Keys:\n\
   {{jobs_move}} move across jobs\n\
   {{jobs_cancel}} cancel selected job\n\
+  {{jobs_limit}} limit bandwidth of selected job\n\
+  {{jobs_limit_all}} limit bandwidth of all jobs\n\
+  {{jobs_pause}} pause/resume selected copy, move, delete or size job\n\
+  {{jobs_pause_all}} pause all jobs, or resume them when all are paused\n\
+  {{jobs_retry}} retry selected failed or canceled job\n\
+  {{jobs_retry_failed}} retry only the items the selected job did not finish\n\
+  {{jobs_skipped}} list the items the selected job skipped after errors\n\
+  {{jobs_reorder}} move the selected waiting job up/down the queue\n\
+  {{jobs_run_next}} start the selected waiting job next\n\
   {{jobs_close}} close jobs screen\n\
 \n\
 Back to [File manager](file-manager).",
@@ -634,6 +643,15 @@ fn default_replacements() -> HashMap<&'static str, String> {
         ("viewer_hex", String::from("h")),
         ("jobs_move", String::from("Up/Down")),
         ("jobs_cancel", String::from("Alt-J")),
+        ("jobs_limit", String::from("l")),
+        ("jobs_limit_all", String::from("Shift-L")),
+        ("jobs_pause", String::from("p")),
+        ("jobs_pause_all", String::from("Shift-P")),
+        ("jobs_retry", String::from("r")),
+        ("jobs_retry_failed", String::from("Shift-R")),
+        ("jobs_skipped", String::from("s")),
+        ("jobs_reorder", String::from("Shift-Up/Shift-Down")),
+        ("jobs_run_next", String::from("n")),
         ("jobs_close", String::from("Esc/q")),
         ("find_move", String::from("Up/Down")),
         ("find_nav", String::from("PgUp/PgDn/Home/End")),
@@ -0,0 +1,527 @@
+//! Mutation jobs that were still queued or running when rc stopped.
+//!
+//! The queue file is rewritten whenever such a job is queued, finishes a
+//! top-level source or ends, so it also survives a crash. Each job keeps its
+//! original request plus the sources it already finished; resuming queues
+//! the request without those. A copy or move also keeps the destination it
+//! claimed for the source it was working on, which may be half copied, so
+//! resuming completes what it left there instead of treating it as a
+//! conflict. Any other destination is resolved with the job's overwrite
+//! policy as usual. Jobs that only read, such as panel refreshes
+//! and searches, are never written.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::copy_xattrs::PreserveLevel;
+use crate::jobs::{
+    ClaimedDestination, FollowLinks, JobManager, JobRequest, JobStatus, OverwritePolicy,
+    PermissionMask,
+};
+use crate::settings_io::{
+    overwrite_policy_label, parse_bool, parse_overwrite_policy, parse_preserve_level,
+    preserve_level_label, write_atomic,
+};
+use crate::trash::{decode_path, encode_path};
+
+pub const JOB_QUEUE_FILE_NAME: &str = "jobs.queue";
+
+const JOB_QUEUE_HEADER: &str = "# rc job queue";
+const JOB_RECORD_KEY: &str = "job";
+
+/// An unfinished job and the top-level sources it already completed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedJob {
+    pub request: JobRequest,
+    pub completed: Vec<PathBuf>,
+    /// See [`crate::jobs::JobRecord::claimed_destination`].
+    pub claimed: Option<ClaimedDestination>,
+}
+
+impl QueuedJob {
+    /// The request that finishes the job, or `None` when nothing is left.
+    pub fn remaining_request(&self) -> Option<JobRequest> {
+        self.request.without_sources(&self.completed)
+    }
+}
+
+/// Unfinished mutation jobs, in the order they were queued.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JobQueue {
+    jobs: Vec<QueuedJob>,
+}
+
+impl JobQueue {
+    /// Queued, running and paused jobs of `manager` that can be queued again.
+    pub(crate) fn capture(manager: &JobManager) -> Self {
+        let jobs = manager
+            .jobs()
+            .iter()
+            .filter(|job| {
+                matches!(
+                    job.status,
+                    JobStatus::Queued | JobStatus::Running | JobStatus::Paused
+                )
+            })
+            .filter_map(|job| {
+                Some(QueuedJob {
+                    request: job.retry_request.clone()?,
+                    completed: job.completed_sources.clone(),
+                    claimed: job.claimed_destination.clone(),
+                })
+            })
+            .collect();
+        Self { jobs }
+    }
+
+    pub fn jobs(&self) -> &[QueuedJob] {
+        &self.jobs
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    pub(crate) fn extend(&mut self, other: Self) {
+        self.jobs.extend(other.jobs);
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(source) => Ok(Self::parse(&source)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Writes the queue, or removes the file when the queue is empty.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if self.is_empty() {
+            return match fs::remove_file(path) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+                _ => Ok(()),
+            };
+        }
+        write_atomic(path, &self.render())
+    }
+
+    /// Reads the format written by [`JobQueue::render`]. Jobs with unknown
+    /// kinds or missing fields are dropped.
+    pub fn parse(source: &str) -> Self {
+        let mut queue = Self::default();
+        let mut current: Option<JobFields<'_>> = None;
+        for line in source.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            if key == JOB_RECORD_KEY {
+                queue.jobs.extend(current.take().and_then(JobFields::build));
+                current = Some(JobFields::new(value));
+            } else if let Some(fields) = current.as_mut() {
+                fields.push(key, value);
+            }
+        }
+        queue.jobs.extend(current.and_then(JobFields::build));
+        queue
+    }
+
+    pub fn render(&self) -> String {
+        let mut output = format!("{JOB_QUEUE_HEADER}\n");
+        for job in &self.jobs {
+            render_request(&mut output, &job.request);
+            for path in &job.completed {
+                output.push_str(&format!("done={}\n", encode_path(path)));
+            }
+            if let Some(claimed) = &job.claimed {
+                output.push_str(&format!("claimed={}\n", encode_path(&claimed.source)));
+                output.push_str(&format!(
+                    "claimed_to={}\n",
+                    encode_path(&claimed.destination)
+                ));
+            }
+        }
+        output
+    }
+}
+
+fn render_request(output: &mut String, request: &JobRequest) {
+    let mut line = |key: &str, value: &str| output.push_str(&format!("{key}={value}\n"));
+    line(JOB_RECORD_KEY, request.kind().label());
+    let sources = match request {
+        JobRequest::Copy {
+            sources,
+            destination_dir,
+            destination_names,
+            overwrite,
+            verify,
+            preserve,
+            ..
+        }
+        | JobRequest::Move {
+            sources,
+            destination_dir,
+            destination_names,
+            overwrite,
+            verify,
+            preserve,
+        } => {
+            line("destination", &encode_path(destination_dir));
+            line("overwrite", overwrite_policy_label(*overwrite));
+            line("verify", &verify.to_string());
+            line("preserve", preserve_level_label(*preserve));
+            if let JobRequest::Copy {
+                follow_links,
+                hard_links,
+                ..
+            } = request
+            {
+                if *follow_links != FollowLinks::Never {
+                    line("follow_links", follow_links_label(*follow_links));
+                }
+                if *hard_links {
+                    line("hard_links", "true");
+                }
+            }
+            for name in destination_names.iter().flatten() {
+                line("name", &encode_path(Path::new(name)));
+            }
+            sources
+        }
+        JobRequest::Chmod {
+            targets,
+            file_mask,
+            dir_mask,
+            recursive,
+        } => {
+            line("file_mask", &render_mask(*file_mask));
+            line("dir_mask", &render_mask(*dir_mask));
+            line("recursive", &recursive.to_string());
+            targets
+        }
+        JobRequest::Chown {
+            targets,
+            owner,
+            group,
+            file_mask,
+            dir_mask,
+            recursive,
+        } => {
+            if let Some(owner) = owner {
+                line("owner", &owner.to_string());
+            }
+            if let Some(group) = group {
+                line("group", &group.to_string());
+            }
+            line("file_mask", &render_mask(*file_mask));
+            line("dir_mask", &render_mask(*dir_mask));
+            line("recursive", &recursive.to_string());
+            targets
+        }
+        JobRequest::Link {
+            sources,
+            destination_dir,
+            overwrite,
+        } => {
+            line("destination", &encode_path(destination_dir));
+            line("overwrite", overwrite_policy_label(*overwrite));
+            sources
+        }
+        JobRequest::Symlink {
+            sources,
+            destination_dir,
+            relative,
+            overwrite,
+        } => {
+            line("destination", &encode_path(destination_dir));
+            line("relative", &relative.to_string());
+            line("overwrite", overwrite_policy_label(*overwrite));
+            sources
+        }
+        JobRequest::Delete { targets } | JobRequest::Trash { targets } => targets,
+        JobRequest::RestoreTrash { items } | JobRequest::PurgeTrash { items } => items,
+        _ => return,
+    };
+    for source in sources {
+        line("source", &encode_path(source));
+    }
+}
+
+fn render_mask(mask: PermissionMask) -> String {
+    format!("{:o}/{:o}", mask.set, mask.clear)
+}
+
+fn parse_mask(value: &str) -> Option<PermissionMask> {
+    let (set, clear) = value.split_once('/')?;
+    Some(PermissionMask::new(
+        u32::from_str_radix(set, 8).ok()?,
+        u32::from_str_radix(clear, 8).ok()?,
+    ))
+}
+
+fn follow_links_label(follow_links: FollowLinks) -> &'static str {
+    match follow_links {
+        FollowLinks::Never => "never",
+        FollowLinks::CommandLine => "command-line",
+        FollowLinks::Always => "always",
+    }
+}
+
+fn parse_follow_links(value: &str) -> Option<FollowLinks> {
+    FollowLinks::ALL
+        .into_iter()
+        .find(|follow_links| follow_links_label(*follow_links) == value)
+}
+
+/// The lines of one job while it is being read.
+struct JobFields<'a> {
+    kind: &'a str,
+    values: Vec<(&'a str, &'a str)>,
+    sources: Vec<PathBuf>,
+    names: Vec<String>,
+    completed: Vec<PathBuf>,
+}
+
+impl<'a> JobFields<'a> {
+    fn new(kind: &'a str) -> Self {
+        Self {
+            kind,
+            values: Vec::new(),
+            sources: Vec::new(),
+            names: Vec::new(),
+            completed: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, key: &'a str, value: &'a str) {
+        match key {
+            "source" => self.sources.push(decode_path(value)),
+            "name" => self
+                .names
+                .push(decode_path(value).to_string_lossy().into_owned()),
+            "done" => self.completed.push(decode_path(value)),
+            _ => self.values.push((key, value)),
+        }
+    }
+
+    fn value(&self, key: &str) -> Option<&'a str> {
+        self.values
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+    }
+
+    fn destination(&self) -> Option<PathBuf> {
+        self.value("destination").map(decode_path)
+    }
+
+    fn overwrite(&self) -> Option<OverwritePolicy> {
+        self.value("overwrite").and_then(parse_overwrite_policy)
+    }
+
+    fn flag(&self, key: &str) -> Option<bool> {
+        self.value(key).and_then(parse_bool)
+    }
+
+    fn preserve(&self) -> Option<PreserveLevel> {
+        self.value("preserve").and_then(parse_preserve_level)
+    }
+
+    /// Queue files written before copies could follow links have no value.
+    fn follow_links(&self) -> Option<FollowLinks> {
+        match self.value("follow_links") {
+            Some(value) => parse_follow_links(value),
+            None => Some(FollowLinks::Never),
+        }
+    }
+
+    /// Likewise for files written before copies could keep hard links.
+    fn hard_links(&self) -> Option<bool> {
+        match self.value("hard_links") {
+            Some(value) => parse_bool(value),
+            None => Some(false),
+        }
+    }
+
+    fn mask(&self, key: &str) -> Option<PermissionMask> {
+        self.value(key).and_then(parse_mask)
+    }
+
+    fn id(&self, key: &str) -> Option<Option<u32>> {
+        match self.value(key) {
+            Some(value) => value.parse().ok().map(Some),
+            None => Some(None),
+        }
+    }
+
+    /// Files written before claimed destinations were kept have none.
+    fn claimed(&self) -> Option<ClaimedDestination> {
+        Some(ClaimedDestination {
+            source: decode_path(self.value("claimed")?),
+            destination: decode_path(self.value("claimed_to")?),
+        })
+    }
+
+    fn build(self) -> Option<QueuedJob> {
+        let claimed = self.claimed();
+        let request = match self.kind {
+            "copy" | "move" => {
+                let (destination_dir, overwrite) = (self.destination()?, self.overwrite()?);
+                let (verify, preserve) = (self.flag("verify")?, self.preserve()?);
+                let (follow_links, hard_links) = (self.follow_links()?, self.hard_links()?);
+                let destination_names = if self.names.is_empty() {
+                    None
+                } else if self.names.len() == self.sources.len() {
+                    Some(self.names)
+                } else {
+                    return None;
+                };
+                if self.kind == "copy" {
+                    JobRequest::Copy {
+                        sources: self.sources,
+                        destination_dir,
+                        destination_names,
+                        overwrite,
+                        verify,
+                        preserve,
+                        follow_links,
+                        hard_links,
+                    }
+                } else {
+                    JobRequest::Move {
+                        sources: self.sources,
+                        destination_dir,
+                        destination_names,
+                        overwrite,
+                        verify,
+                        preserve,
+                    }
+                }
+            }
+            "delete" => JobRequest::Delete {
+                targets: self.sources,
+            },
+            "trash" => JobRequest::Trash {
+                targets: self.sources,
+            },
+            "restore-trash" => JobRequest::RestoreTrash {
+                items: self.sources,
+            },
+            "purge-trash" => JobRequest::PurgeTrash {
+                items: self.sources,
+            },
+            "chmod" => JobRequest::Chmod {
+                file_mask: self.mask("file_mask")?,
+                dir_mask: self.mask("dir_mask")?,
+                recursive: self.flag("recursive")?,
+                targets: self.sources,
+            },
+            "chown" => JobRequest::Chown {
+                owner: self.id("owner")?,
+                group: self.id("group")?,
+                file_mask: self.mask("file_mask")?,
+                dir_mask: self.mask("dir_mask")?,
+                recursive: self.flag("recursive")?,
+                targets: self.sources,
+            },
+            "link" => JobRequest::Link {
+                destination_dir: self.destination()?,
+                overwrite: self.overwrite()?,
+                sources: self.sources,
+            },
+            "symlink" => JobRequest::Symlink {
+                destination_dir: self.destination()?,
+                relative: self.flag("relative")?,
+                overwrite: self.overwrite()?,
+                sources: self.sources,
+            },
+            _ => return None,
+        };
+        (request.item_count() > 0).then_some(QueuedJob {
+            request,
+            completed: self.completed,
+            claimed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn job_queue_round_trips_requests_and_finished_sources() {
+        let queue = JobQueue {
+            jobs: vec![
+                QueuedJob {
+                    request: JobRequest::Copy {
+                        sources: vec![PathBuf::from("/src/a b"), PathBuf::from("/src/c")],
+                        destination_dir: PathBuf::from("/dst"),
+                        destination_names: None,
+                        overwrite: OverwritePolicy::Ask,
+                        verify: true,
+                        preserve: PreserveLevel::Acls,
+                        follow_links: FollowLinks::CommandLine,
+                        hard_links: true,
+                    },
+                    completed: vec![PathBuf::from("/src/a b")],
+                    claimed: Some(ClaimedDestination {
+                        source: PathBuf::from("/src/c"),
+                        destination: PathBuf::from("/dst/c (1)"),
+                    }),
+                },
+                QueuedJob {
+                    request: JobRequest::Move {
+                        sources: vec![PathBuf::from("/src/x")],
+                        destination_dir: PathBuf::from("/dst"),
+                        destination_names: Some(vec![String::from("y=1")]),
+                        overwrite: OverwritePolicy::Skip,
+                        verify: false,
+                        preserve: PreserveLevel::Basic,
+                    },
+                    completed: Vec::new(),
+                    claimed: None,
+                },
+                QueuedJob {
+                    request: JobRequest::Chown {
+                        targets: vec![PathBuf::from("/srv")],
+                        owner: None,
+                        group: Some(100),
+                        file_mask: PermissionMask::new(0o644, 0o022),
+                        dir_mask: PermissionMask::new(0o755, 0),
+                        recursive: true,
+                    },
+                    completed: Vec::new(),
+                    claimed: None,
+                },
+            ],
+        };
+        assert_eq!(JobQueue::parse(&queue.render()), queue);
+        assert_eq!(
+            queue.jobs()[0]
+                .remaining_request()
+                .map(|request| request.item_count()),
+            Some(1)
+        );
+
+        let damaged = "job=copy\nsource=/a\njob=teleport\nsource=/b\njob=delete\nsource=/c\n";
+        assert_eq!(
+            JobQueue::parse(damaged).jobs(),
+            [QueuedJob {
+                request: JobRequest::Delete {
+                    targets: vec![PathBuf::from("/c")],
+                },
+                completed: Vec::new(),
+                claimed: None,
+            }],
+            "jobs with missing fields or unknown kinds must be dropped"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::*;
+
+impl AppState {
+    /// The unfinished mutation jobs to write to the queue file: jobs left
+    /// over from the previous run that were not answered yet, then the
+    /// jobs of this run. Once quitting, the queue as it was when the quit
+    /// was confirmed, before its jobs were canceled.
+    pub fn job_queue(&self) -> JobQueue {
+        if let Some(queue) = &self.frozen_job_queue {
+            return queue.clone();
+        }
+        let mut queue = self.interrupted_jobs.clone();
+        queue.extend(JobQueue::capture(&self.jobs));
+        queue
+    }
+
+    pub fn take_pending_job_queue_save(&mut self) -> bool {
+        std::mem::take(&mut self.pending_job_queue_save)
+    }
+
+    /// Offers to resume the jobs an earlier run left unfinished.
+    pub fn offer_interrupted_jobs(&mut self, queue: JobQueue) {
+        if queue.is_empty() {
+            return;
+        }
+        self.interrupted_jobs = queue;
+        self.push_interrupted_jobs_dialog();
+        self.set_status(format!(
+            "{} unfinished job(s) from the last session",
+            self.interrupted_jobs.len()
+        ));
+    }
+
+    pub(crate) fn push_interrupted_jobs_dialog(&mut self) {
+        let mut items = vec![String::from("Resume all"), String::from("Discard all")];
+        for job in self.interrupted_jobs.jobs() {
+            items.push(format!(
+                "{}: {} of {} done",
+                job.request.summary(),
+                job.completed.len(),
+                job.request.item_count()
+            ));
+            items.extend(
+                job.completed
+                    .iter()
+                    .map(|path| format!("  done {}", path.to_string_lossy())),
+            );
+        }
+        self.push_dialog(
+            DialogState::listbox_with_hint(
+                "Unfinished jobs",
+                items,
+                0,
+                "Enter on Resume or Discard | Esc ask again next start",
+            ),
+            PendingDialogAction::ResumeInterruptedJobs,
+        );
+    }
+
+    /// Queues what is left of every interrupted job. A source a job was in
+    /// the middle of is completed at the destination it claimed rather than
+    /// meeting its own partial copy as a conflict.
+    pub(crate) fn resume_interrupted_jobs(&mut self) {
+        let queue = std::mem::take(&mut self.interrupted_jobs);
+        self.pending_job_queue_save = true;
+        let mut resumed = 0_usize;
+        for job in queue.jobs() {
+            if let Some(request) = job.remaining_request() {
+                self.queue_worker_job_merging(request, job.claimed.clone());
+                resumed += 1;
+            }
+        }
+        self.set_status(format!("Resumed {resumed} unfinished job(s)"));
+    }
+
+    pub(crate) fn discard_interrupted_jobs(&mut self) {
+        let count = std::mem::take(&mut self.interrupted_jobs).len();
+        self.pending_job_queue_save = true;
+        self.set_status(format!("Discarded {count} unfinished job(s)"));
+    }
+
+    /// Keeps the queue file at its current contents while quitting cancels
+    /// the jobs, so they are offered again on the next start.
+    pub(crate) fn freeze_job_queue(&mut self) {
+        self.frozen_job_queue = Some(self.job_queue());
+        self.pending_job_queue_save = true;
+    }
+}
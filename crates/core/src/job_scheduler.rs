@@ -0,0 +1,495 @@
+//! Keeps filesystem jobs that would get in each other's way from running at
+//! the same time.
+//!
+//! Every filesystem job claims the trees it reads and writes
+//! ([`crate::jobs::JobRequest::path_footprint`]) when it is queued. A job
+//! that would write a tree an earlier job still reads or writes, or read one
+//! it writes, is held back until that job finishes. Jobs over many items
+//! also claim the devices their paths are on
+//! ([`crate::jobs::JobRequest::device_paths`]), so two copies onto one disk
+//! take turns instead of thrashing it; jobs on other devices still run side
+//! by side. A paused job keeps its trees but lets others use its devices
+//! until it resumes. Held jobs start in queue order, which the user may
+//! change as long as jobs on overlapping trees keep theirs.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::jobs::{JobId, PathFootprint, PathWait, WorkerJob};
+
+/// What a queued or running job keeps to itself.
+#[derive(Debug)]
+struct Claim {
+    id: JobId,
+    footprint: PathFootprint,
+    /// Each device the job keeps busy, with one of its paths there.
+    devices: Vec<(u64, PathBuf)>,
+    /// The job's pause flag; its devices are free while it is set.
+    paused: Arc<AtomicBool>,
+}
+
+impl Claim {
+    /// Whether this job must wait for `earlier`: the path they meet on, and
+    /// whether it is only their device they share.
+    fn waits_for(&self, earlier: &Self) -> Option<(PathBuf, bool)> {
+        if let Some(path) = self.footprint.conflict_with(&earlier.footprint) {
+            return Some((path.to_path_buf(), false));
+        }
+        if earlier.paused.load(Ordering::Relaxed) {
+            return None;
+        }
+        self.devices
+            .iter()
+            .find(|(device, _)| earlier.devices.iter().any(|(other, _)| other == device))
+            .map(|(_, path)| (path.clone(), true))
+    }
+}
+
+/// Where to move a held job in the queue.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum QueueMove {
+    Up,
+    Down,
+    /// To the front, to start as soon as what it waits for allows.
+    Next,
+}
+
+#[derive(Debug)]
+pub(crate) struct PathScheduler {
+    /// Claims of the jobs handed to the worker and not finished yet.
+    claims: Vec<Claim>,
+    /// Jobs held back, in the order they are to start.
+    held: Vec<(WorkerJob, Claim)>,
+    device_of: fn(&Path) -> Option<u64>,
+}
+
+impl Default for PathScheduler {
+    fn default() -> Self {
+        Self {
+            claims: Vec::new(),
+            held: Vec::new(),
+            device_of: path_device,
+        }
+    }
+}
+
+impl PathScheduler {
+    /// Returns `job` when it may run now, claiming its paths and devices, or
+    /// holds it at the back of the queue.
+    pub(crate) fn admit(&mut self, job: WorkerJob) -> Option<WorkerJob> {
+        let Some(footprint) = job.request.path_footprint() else {
+            return Some(job);
+        };
+        let mut devices: Vec<(u64, PathBuf)> = Vec::new();
+        for path in job.request.device_paths() {
+            if let Some(device) = (self.device_of)(path)
+                && !devices.iter().any(|(known, _)| *known == device)
+            {
+                devices.push((device, path.to_path_buf()));
+            }
+        }
+        let claim = Claim {
+            id: job.id,
+            footprint,
+            devices,
+            paused: job.pause_flag(),
+        };
+        let blocked = self
+            .claims
+            .iter()
+            .chain(self.held.iter().map(|(_, claim)| claim))
+            .any(|earlier| claim.waits_for(earlier).is_some());
+        if blocked {
+            self.held.push((job, claim));
+            return None;
+        }
+        self.claims.push(claim);
+        Some(job)
+    }
+
+    /// Drops what a finished job claimed, or the job itself while it was
+    /// still held.
+    pub(crate) fn release(&mut self, id: JobId) {
+        self.claims.retain(|claim| claim.id != id);
+        self.held.retain(|(held, _)| held.id != id);
+    }
+
+    /// Takes the held jobs that may run now out of the queue, in queue order,
+    /// claiming their paths and devices.
+    pub(crate) fn take_ready(&mut self) -> Vec<WorkerJob> {
+        let mut ready = Vec::new();
+        let mut index = 0;
+        while index < self.held.len() {
+            if self.wait_at(index).is_some() {
+                index += 1;
+                continue;
+            }
+            let (job, claim) = self.held.remove(index);
+            self.claims.push(claim);
+            ready.push(job);
+        }
+        ready
+    }
+
+    /// Takes a held job out of the queue, as when it is canceled before it
+    /// could start.
+    pub(crate) fn take_held(&mut self, id: JobId) -> Option<WorkerJob> {
+        let index = self.held_index(id)?;
+        Some(self.held.remove(index).0)
+    }
+
+    /// Moves the held job `id` in the queue, never past a job on a tree it
+    /// overlaps. Returns `None` when the job is not held, `Ok(false)` when it
+    /// is already at that end, and the job in its way with the tree both touch
+    /// when it cannot move at all.
+    pub(crate) fn move_held(
+        &mut self,
+        id: JobId,
+        queue_move: QueueMove,
+    ) -> Option<Result<bool, (JobId, PathBuf)>> {
+        let mut index = self.held_index(id)?;
+        let neighbour = |index: usize, len: usize| match queue_move {
+            QueueMove::Up | QueueMove::Next => index.checked_sub(1),
+            QueueMove::Down => Some(index + 1).filter(|next| *next < len),
+        };
+        let Some(mut other) = neighbour(index, self.held.len()) else {
+            return Some(Ok(false));
+        };
+        let mut moved = false;
+        loop {
+            let (earlier, later) = (index.min(other), index.max(other));
+            if let Some(path) = self.held[later]
+                .1
+                .footprint
+                .conflict_with(&self.held[earlier].1.footprint)
+            {
+                if moved {
+                    return Some(Ok(true));
+                }
+                return Some(Err((self.held[other].0.id, path.to_path_buf())));
+            }
+            self.held.swap(index, other);
+            moved = true;
+            index = other;
+            match (queue_move, neighbour(index, self.held.len())) {
+                (QueueMove::Next, Some(next)) => other = next,
+                _ => return Some(Ok(true)),
+            }
+        }
+    }
+
+    /// What each held job waits for now, in queue order.
+    pub(crate) fn waits(&self) -> Vec<(JobId, PathWait)> {
+        (0..self.held.len())
+            .filter_map(|index| Some((self.held[index].0.id, self.wait_at(index)?)))
+            .collect()
+    }
+
+    fn held_index(&self, id: JobId) -> Option<usize> {
+        self.held.iter().position(|(held, _)| held.id == id)
+    }
+
+    /// The running or earlier held job the held job at `index` waits for.
+    fn wait_at(&self, index: usize) -> Option<PathWait> {
+        let claim = &self.held[index].1;
+        self.claims
+            .iter()
+            .chain(self.held[..index].iter().map(|(_, claim)| claim))
+            .find_map(|earlier| {
+                let (path, shared_device) = claim.waits_for(earlier)?;
+                Some(PathWait {
+                    job: earlier.id,
+                    path,
+                    shared_device,
+                    position: index + 1,
+                })
+            })
+    }
+}
+
+/// The device `path`, or the closest ancestor that exists, is on.
+#[cfg(unix)]
+fn path_device(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt as _;
+
+    path.ancestors()
+        .find_map(|path| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.dev())
+}
+
+/// Devices are not told apart here, so only overlapping trees take turns.
+#[cfg(not(unix))]
+fn path_device(_path: &Path) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::{JobManager, JobRequest, OverwritePolicy};
+    use crate::test_support::make_temp_dir;
+    use crate::trash::{Trash, info_path_for};
+    use std::collections::hash_map::DefaultHasher;
+    use std::fs;
+    use std::hash::{Hash, Hasher};
+
+    /// Treats each directory under the root as a device of its own, so
+    /// `/hdd/a` and `/hdd/b` share one.
+    fn top_level_device(path: &Path) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        path.components().nth(1)?.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    fn scheduler() -> PathScheduler {
+        PathScheduler {
+            device_of: top_level_device,
+            ..PathScheduler::default()
+        }
+    }
+
+    fn copy_into(manager: &mut JobManager, source: &str, destination_dir: &str) -> WorkerJob {
+        manager.enqueue(JobRequest::Copy {
+            sources: vec![PathBuf::from(source)],
+            destination_dir: PathBuf::from(destination_dir),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: Default::default(),
+            follow_links: Default::default(),
+            hard_links: false,
+        })
+    }
+
+    fn move_into(manager: &mut JobManager, source: &str, destination_dir: &str) -> WorkerJob {
+        manager.enqueue(JobRequest::Move {
+            sources: vec![PathBuf::from(source)],
+            destination_dir: PathBuf::from(destination_dir),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: Default::default(),
+        })
+    }
+
+    fn delete(manager: &mut JobManager, target: &str) -> WorkerJob {
+        manager.enqueue(JobRequest::Delete {
+            targets: vec![PathBuf::from(target)],
+        })
+    }
+
+    fn ids(jobs: &[WorkerJob]) -> Vec<JobId> {
+        jobs.iter().map(|job| job.id).collect()
+    }
+
+    #[test]
+    fn overlapping_jobs_wait_while_unrelated_ones_run() {
+        let mut manager = JobManager::new();
+        let mut scheduler = scheduler();
+        let moving = move_into(&mut manager, "/data/a", "/data/b");
+        let moving_id = moving.id;
+        assert!(scheduler.admit(moving).is_some());
+
+        let deleting = delete(&mut manager, "/data/b");
+        let deleting_id = deleting.id;
+        assert!(
+            scheduler.admit(deleting).is_none(),
+            "deleting the move's destination should wait"
+        );
+        assert!(scheduler.admit(delete(&mut manager, "/other")).is_some());
+        let later = manager.enqueue(JobRequest::Mkdir {
+            path: PathBuf::from("/data/b/c"),
+        });
+        let later_id = later.id;
+        assert!(scheduler.admit(later).is_none());
+        let waits = scheduler.waits();
+        assert_eq!(waits[0].0, deleting_id);
+        assert_eq!(waits[0].1.job, moving_id);
+        assert_eq!(waits[0].1.path, PathBuf::from("/data/b/a"));
+        assert!(!waits[0].1.shared_device);
+        // Behind the held delete, even though the move does not touch it.
+        assert_eq!(
+            (waits[1].0, waits[1].1.job, waits[1].1.position),
+            (later_id, deleting_id, 2)
+        );
+
+        scheduler.release(moving_id);
+        assert_eq!(ids(&scheduler.take_ready()), vec![deleting_id]);
+        scheduler.release(deleting_id);
+        assert_eq!(ids(&scheduler.take_ready()), vec![later_id]);
+    }
+
+    #[test]
+    fn restores_wait_for_jobs_writing_where_their_items_go_back() {
+        let root = make_temp_dir("scheduler-restore");
+        let trash = Trash::with_home(root.join("Trash"));
+        let trashed = root.join("docs").join("report.txt");
+        fs::create_dir_all(trashed.parent().expect("docs should have a parent"))
+            .expect("docs should be creatable");
+        fs::write(&trashed, "report").expect("report should be writable");
+        let files_path = trash.trash(&trashed).expect("report should be trashed");
+        let info_path = info_path_for(&files_path).expect("report should have trash info");
+
+        let mut manager = JobManager::new();
+        let mut scheduler = PathScheduler::default();
+        let copy = manager.enqueue(JobRequest::Copy {
+            sources: vec![root.join("report.txt")],
+            destination_dir: root.join("docs"),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: Default::default(),
+            follow_links: Default::default(),
+            hard_links: false,
+        });
+        let copy_id = copy.id;
+        assert!(scheduler.admit(copy).is_some());
+        let restore = manager.enqueue(JobRequest::RestoreTrash {
+            items: vec![info_path],
+        });
+        let restore_id = restore.id;
+        assert!(
+            scheduler.admit(restore).is_none(),
+            "restoring onto the copy's destination should wait"
+        );
+        assert_eq!(scheduler.waits()[0].1.path, trashed);
+
+        scheduler.release(copy_id);
+        assert_eq!(ids(&scheduler.take_ready()), vec![restore_id]);
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[test]
+    fn jobs_on_one_device_take_turns_while_other_devices_proceed() {
+        let mut manager = JobManager::new();
+        let mut scheduler = scheduler();
+        let spread = JobRequest::Delete {
+            targets: ["/data/a", "/data/b", "/hdd/c"].map(PathBuf::from).to_vec(),
+        };
+        assert_eq!(
+            spread.device_paths(),
+            vec![Path::new("/data"), Path::new("/hdd")],
+            "each directory should be looked at once"
+        );
+        let first = copy_into(&mut manager, "/data/a", "/hdd/backup");
+        let first_id = first.id;
+        assert!(scheduler.admit(first).is_some());
+        // `/hdd` again, although the trees do not overlap.
+        let second = copy_into(&mut manager, "/ssd/b", "/hdd/archive");
+        let second_id = second.id;
+        assert!(scheduler.admit(second).is_none());
+        let wait = &scheduler.waits()[0].1;
+        assert_eq!(wait.job, first_id);
+        assert_eq!(wait.path, PathBuf::from("/hdd/archive"));
+        assert!(wait.shared_device);
+        assert!(
+            scheduler
+                .admit(copy_into(&mut manager, "/nvme/c", "/usb/c"))
+                .is_some(),
+            "a copy on other devices should run"
+        );
+        assert!(
+            scheduler
+                .admit(manager.enqueue(JobRequest::Mkdir {
+                    path: PathBuf::from("/hdd/new"),
+                }))
+                .is_some(),
+            "single-entry jobs should not queue behind a long copy"
+        );
+        assert!(
+            scheduler.admit(delete(&mut manager, "/hdd/old")).is_some(),
+            "nor should a delete of one entry"
+        );
+
+        scheduler.release(first_id);
+        assert_eq!(ids(&scheduler.take_ready()), vec![second_id]);
+    }
+
+    #[test]
+    fn paused_jobs_free_their_device_but_keep_their_trees() {
+        let mut manager = JobManager::new();
+        let mut scheduler = scheduler();
+        let first = copy_into(&mut manager, "/data/a", "/hdd/a");
+        let first_id = first.id;
+        assert!(scheduler.admit(first).is_some());
+        let second = copy_into(&mut manager, "/ssd/b", "/hdd/b");
+        let second_id = second.id;
+        assert!(scheduler.admit(second).is_none());
+
+        assert!(manager.set_paused(first_id, true));
+        assert_eq!(
+            ids(&scheduler.take_ready()),
+            vec![second_id],
+            "a copy on the device of a paused one should start"
+        );
+        scheduler.release(second_id);
+        assert!(
+            scheduler.admit(delete(&mut manager, "/hdd/a/a")).is_none(),
+            "a paused job keeps its trees"
+        );
+
+        assert!(manager.set_paused(first_id, false));
+        let third = copy_into(&mut manager, "/ssd/c", "/hdd/c");
+        let third_id = third.id;
+        assert!(scheduler.admit(third).is_none());
+        let (_, wait) = scheduler
+            .waits()
+            .into_iter()
+            .find(|(id, _)| *id == third_id)
+            .expect("the copy should wait");
+        assert_eq!(
+            (wait.job, wait.shared_device),
+            (first_id, true),
+            "a resumed job takes its device back"
+        );
+    }
+
+    #[test]
+    fn held_jobs_reorder_but_never_past_an_overlapping_tree() {
+        let mut manager = JobManager::new();
+        let mut scheduler = scheduler();
+        assert!(
+            scheduler
+                .admit(copy_into(&mut manager, "/data/a", "/hdd/a"))
+                .is_some()
+        );
+        let [first, second, third] = [
+            copy_into(&mut manager, "/data/b", "/hdd/b"),
+            copy_into(&mut manager, "/data/c", "/hdd/c"),
+            delete(&mut manager, "/hdd/b"),
+        ]
+        .map(|job| {
+            let id = job.id;
+            assert!(scheduler.admit(job).is_none());
+            id
+        });
+        let order = |scheduler: &PathScheduler| {
+            scheduler
+                .waits()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(scheduler.move_held(second, QueueMove::Up), Some(Ok(true)));
+        assert_eq!(order(&scheduler), vec![second, first, third]);
+        assert_eq!(scheduler.move_held(second, QueueMove::Up), Some(Ok(false)));
+        assert_eq!(
+            scheduler.move_held(first, QueueMove::Down),
+            Some(Err((third, PathBuf::from("/hdd/b/b")))),
+            "the copy into /hdd/b should stay ahead of its delete"
+        );
+        assert_eq!(
+            scheduler.move_held(third, QueueMove::Next),
+            Some(Err((first, PathBuf::from("/hdd/b/b"))))
+        );
+        assert_eq!(scheduler.move_held(second, QueueMove::Down), Some(Ok(true)));
+        assert_eq!(order(&scheduler), vec![first, second, third]);
+        // Jumps the copy that shares only the device, stops behind the other.
+        assert_eq!(scheduler.move_held(third, QueueMove::Next), Some(Ok(true)));
+        assert_eq!(order(&scheduler), vec![first, third, second]);
+        assert_eq!(scheduler.move_held(JobId(99), QueueMove::Up), None);
+        assert_eq!(scheduler.take_held(second).map(|job| job.id), Some(second));
+    }
+}
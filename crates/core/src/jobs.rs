@@ -1,28 +1,47 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Component, Path, PathBuf};
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{Receiver, Sender};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender, TrySendError};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 
 use filetime::FileTime;
 #[cfg(unix)]
 use nix::errno::Errno;
 #[cfg(unix)]
 use nix::unistd::{Gid, Uid, chown};
+use sha2::{Digest, Sha256};
 
+use crate::copy_accel::{self, CopyStrategies, CopyStrategy};
+use crate::copy_resume::{ResumeStaging, ResumeStamp};
+use crate::copy_xattrs::{self, PreserveLevel};
+use crate::destination_fs::{check_destination_names, restricted_filesystem};
+use crate::selection_size::{DirectoryIdentity, directory_identity};
 use crate::settings::Settings;
 use crate::settings_io::{SettingsPaths, save_settings};
+use crate::throttle::{RateWindow, Throttle, format_eta, format_rate, format_size};
+use crate::trash::{self, Trash, TrashedItem};
+use crate::undo::{UndoEntry, UndoOperation};
 use crate::{
     ActivePanel, FileEntry, FindSpec, PanelFilter, PanelListingSource, QuickCdSearchSpec, SortMode,
 };
 
 const COPY_BUFFER_SIZE: usize = 64 * 1024;
+/// Longest single sleep of a throttled copy, so cancellation stays prompt.
+const THROTTLE_SLEEP_SLICE: Duration = Duration::from_millis(50);
+const WORKER_PROMPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// Regular files up to this size go to the copy workers of a directory copy;
+/// larger ones are copied by the walker itself so their progress stays live.
+const PARALLEL_COPY_MAX_FILE_SIZE: u64 = 1024 * 1024;
+/// Files queued per copy worker before the walker waits for them.
+const PARALLEL_COPY_QUEUE_PER_WORKER: usize = 4;
 pub const JOB_CANCELED_MESSAGE: &str = "job canceled";
+const CHECKSUM_MISMATCH_MESSAGE: &str = "checksum mismatch";
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct JobId(pub u64);
@@ -38,8 +57,16 @@ pub enum JobKind {
     Copy,
     Move,
     Delete,
+    Trash,
+    RestoreTrash,
+    PurgeTrash,
     Mkdir,
     Rename,
+    Chmod,
+    Chown,
+    Link,
+    Symlink,
+    EditSymlink,
     PersistSettings,
     RefreshPanel,
     Find,
@@ -48,6 +75,7 @@ pub enum JobKind {
     LoadQuickView,
     MeasureSelection,
     BuildTree,
+    PlanTransfer,
 }
 
 impl JobKind {
@@ -56,8 +84,16 @@ impl JobKind {
             Self::Copy => "copy",
             Self::Move => "move",
             Self::Delete => "delete",
+            Self::Trash => "trash",
+            Self::RestoreTrash => "restore-trash",
+            Self::PurgeTrash => "purge-trash",
             Self::Mkdir => "mkdir",
             Self::Rename => "rename",
+            Self::Chmod => "chmod",
+            Self::Chown => "chown",
+            Self::Link => "link",
+            Self::Symlink => "symlink",
+            Self::EditSymlink => "edit-symlink",
             Self::PersistSettings => "persist-settings",
             Self::RefreshPanel => "refresh-panel",
             Self::Find => "find",
@@ -66,8 +102,17 @@ impl JobKind {
             Self::LoadQuickView => "load-quick-view",
             Self::MeasureSelection => "measure-selection",
             Self::BuildTree => "build-tree",
+            Self::PlanTransfer => "plan-transfer",
         }
     }
+
+    /// Whether [`JobManager::set_paused`] can hold a job of this kind.
+    pub fn is_pausable(self) -> bool {
+        matches!(
+            self,
+            Self::Copy | Self::Move | Self::Delete | Self::MeasureSelection
+        )
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -76,6 +121,8 @@ pub enum OverwritePolicy {
     #[default]
     Skip,
     Rename,
+    /// Pause on every conflict and let the user pick an [`OverwriteDecision`].
+    Ask,
 }
 
 impl OverwritePolicy {
@@ -84,26 +131,468 @@ impl OverwritePolicy {
             Self::Overwrite => "overwrite",
             Self::Skip => "skip",
             Self::Rename => "rename",
+            Self::Ask => "ask",
+        }
+    }
+}
+
+/// Which symlinks a copy replaces with what they point to, as `cp -H` and
+/// `cp -L` do; the others are copied as links.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FollowLinks {
+    #[default]
+    Never,
+    /// Only the selected sources themselves.
+    CommandLine,
+    Always,
+}
+
+impl FollowLinks {
+    pub const ALL: [Self; 3] = [Self::Never, Self::CommandLine, Self::Always];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Never => "never",
+            Self::CommandLine => "command line",
+            Self::Always => "always",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Never => Self::CommandLine,
+            Self::CommandLine => Self::Always,
+            Self::Always => Self::Never,
+        }
+    }
+
+    fn follows(self, top_level: bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::CommandLine => top_level,
+            Self::Always => true,
+        }
+    }
+}
+
+/// Answer to a single destination conflict under [`OverwritePolicy::Ask`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverwriteDecision {
+    Overwrite,
+    Skip,
+    Rename,
+    Append,
+    OverwriteIfNewer,
+    OverwriteIfSizeDiffers,
+}
+
+impl OverwriteDecision {
+    pub const ALL: [Self; 6] = [
+        Self::Overwrite,
+        Self::Skip,
+        Self::Rename,
+        Self::Append,
+        Self::OverwriteIfNewer,
+        Self::OverwriteIfSizeDiffers,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Overwrite => "Overwrite",
+            Self::Skip => "Skip",
+            Self::Rename => "Rename",
+            Self::Append => "Append",
+            Self::OverwriteIfNewer => "Overwrite if newer",
+            Self::OverwriteIfSizeDiffers => "Overwrite if size differs",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OverwriteAnswer {
+    pub decision: OverwriteDecision,
+    /// Reuse `decision` for the remaining conflicts of the same job.
+    pub apply_to_all: bool,
+}
+
+/// Size and modification time of one side of a destination conflict.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConflictEntry {
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+    pub is_dir: bool,
+}
+
+impl ConflictEntry {
+    fn read(path: &Path) -> io::Result<Self> {
+        let metadata = fs::symlink_metadata(path)?;
+        Ok(Self {
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+            is_dir: metadata.is_dir(),
+        })
+    }
+}
+
+/// An existing destination the worker is waiting on the user for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverwriteConflict {
+    pub source: PathBuf,
+    pub destination: PathBuf,
+    pub source_entry: ConflictEntry,
+    pub destination_entry: ConflictEntry,
+}
+
+impl OverwriteConflict {
+    fn read(source: &Path, destination: &Path) -> io::Result<Self> {
+        Ok(Self {
+            source: source.to_path_buf(),
+            destination: destination.to_path_buf(),
+            source_entry: ConflictEntry::read(source)?,
+            destination_entry: ConflictEntry::read(destination)?,
+        })
+    }
+
+    /// Turns the conditional decisions into a plain overwrite or skip.
+    fn resolve(&self, decision: OverwriteDecision) -> OverwriteDecision {
+        let overwrite_if = |condition: bool| {
+            if condition {
+                OverwriteDecision::Overwrite
+            } else {
+                OverwriteDecision::Skip
+            }
+        };
+        match decision {
+            OverwriteDecision::OverwriteIfNewer => overwrite_if(
+                self.source_entry
+                    .modified
+                    .zip(self.destination_entry.modified)
+                    .is_some_and(|(source, destination)| source > destination),
+            ),
+            OverwriteDecision::OverwriteIfSizeDiffers => {
+                overwrite_if(self.source_entry.size != self.destination_entry.size)
+            }
+            decision => decision,
+        }
+    }
+}
+
+/// How the user wants an item handled that a copy, move or delete failed on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ItemErrorAnswer {
+    Retry,
+    Skip,
+    /// Skip this item and every later failure of the same job.
+    SkipAll,
+    Abort,
+}
+
+impl ItemErrorAnswer {
+    pub const ALL: [Self; 4] = [Self::Retry, Self::Skip, Self::SkipAll, Self::Abort];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Retry => "Retry",
+            Self::Skip => "Skip",
+            Self::SkipAll => "Skip all",
+            Self::Abort => "Abort",
+        }
+    }
+}
+
+/// An item a copy, move or delete could not finish.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ItemFailure {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+/// The destination a copy or move was writing `source` to, named while it
+/// was still free, so whatever is there after an interruption was left by
+/// the job itself.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimedDestination {
+    pub source: PathBuf,
+    pub destination: PathBuf,
+}
+
+/// Why a queued job has not started: an earlier job works on `path`, a tree
+/// the queued job touches too, or with `shared_device` on the device `path`
+/// is on. `position` is the job's place among the held jobs, 1 being the
+/// next to start.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PathWait {
+    pub job: JobId,
+    pub path: PathBuf,
+    pub shared_device: bool,
+    pub position: usize,
+}
+
+/// The trees a filesystem job reads and writes, which tell the jobs that
+/// may run side by side from those that must take turns.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PathFootprint {
+    pub reads: Vec<PathBuf>,
+    pub writes: Vec<PathBuf>,
+}
+
+impl PathFootprint {
+    /// The tree both footprints touch while at least one of them writes it,
+    /// or `None` when the jobs cannot get in each other's way. Paths are
+    /// compared lexically; two trees meet when one contains the other.
+    pub fn conflict_with<'a>(&'a self, other: &'a Self) -> Option<&'a Path> {
+        let written_by_self = self.writes.iter().flat_map(|write| {
+            other
+                .reads
+                .iter()
+                .chain(&other.writes)
+                .map(move |path| (write, path))
+        });
+        let written_by_other = self
+            .reads
+            .iter()
+            .flat_map(|read| other.writes.iter().map(move |path| (read, path)));
+        written_by_self
+            .chain(written_by_other)
+            .find_map(|(ours, theirs)| {
+                if ours.starts_with(theirs) {
+                    Some(ours.as_path())
+                } else if theirs.starts_with(ours) {
+                    Some(theirs.as_path())
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+/// Hands the UI's answer to a worker blocked on a question, such as an
+/// [`OverwriteConflict`] or an [`ItemFailure`].
+#[derive(Debug)]
+pub struct WorkerPrompt<T> {
+    answer: Mutex<Option<T>>,
+    answered: Condvar,
+}
+
+impl<T> Default for WorkerPrompt<T> {
+    fn default() -> Self {
+        Self {
+            answer: Mutex::new(None),
+            answered: Condvar::new(),
+        }
+    }
+}
+
+impl<T> WorkerPrompt<T> {
+    fn answer(&self, answer: T) {
+        let mut slot = self
+            .answer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *slot = Some(answer);
+        self.answered.notify_all();
+    }
+
+    /// Blocks the worker thread until answered; cancellation is polled so an
+    /// abandoned prompt never pins the job. The job's `runtime_slot` is given
+    /// back for the wait, so a job waiting on the user does not keep others
+    /// from running.
+    fn wait(
+        &self,
+        cancel_flag: &AtomicBool,
+        runtime_slot: Option<&dyn RuntimeSlot>,
+    ) -> io::Result<T> {
+        if let Some(runtime_slot) = runtime_slot {
+            runtime_slot.release();
+        }
+        let answer = self.wait_for_answer(cancel_flag);
+        if let Some(runtime_slot) = runtime_slot {
+            runtime_slot.reacquire(cancel_flag);
+        }
+        answer
+    }
+
+    fn wait_for_answer(&self, cancel_flag: &AtomicBool) -> io::Result<T> {
+        let mut slot = self
+            .answer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        loop {
+            if let Some(answer) = slot.take() {
+                return Ok(answer);
+            }
+            ensure_not_canceled(cancel_flag)?;
+            slot = self
+                .answered
+                .wait_timeout(slot, WORKER_PROMPT_POLL_INTERVAL)
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .0;
+        }
+    }
+}
+
+/// A running job's share of the concurrency limit of the runtime executing
+/// it, given back while the worker waits on a [`WorkerPrompt`].
+pub trait RuntimeSlot: fmt::Debug + Send + Sync {
+    /// Lets another job take the share until [`RuntimeSlot::reacquire`].
+    fn release(&self);
+    /// Waits for a share again; gives up once `cancel_flag` is set.
+    fn reacquire(&self, cancel_flag: &AtomicBool);
+}
+
+/// The parent directories of `items` when the job walks them recursively or
+/// there are several of them.
+fn walked_paths(items: &[PathBuf], recursive: bool) -> Vec<&Path> {
+    if recursive || items.len() > 1 {
+        items
+            .iter()
+            .map(|item| item.parent().unwrap_or(item))
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+fn verify_summary_suffix(verify: bool) -> &'static str {
+    if verify { ", verify" } else { "" }
+}
+
+fn follow_links_summary_suffix(follow_links: FollowLinks) -> String {
+    match follow_links {
+        FollowLinks::Never => String::new(),
+        follow_links => format!(", follow links {}", follow_links.label()),
+    }
+}
+
+fn hard_links_summary_suffix(hard_links: bool) -> &'static str {
+    if hard_links { ", hard links" } else { "" }
+}
+
+fn preserve_summary_suffix(preserve: PreserveLevel) -> String {
+    match preserve {
+        PreserveLevel::Basic => String::new(),
+        level => format!(", preserve {}", level.label()),
+    }
+}
+
+/// Marks the I/O error of a verified copy whose bytes differ from the source.
+#[derive(Debug)]
+struct ChecksumMismatch {
+    path: PathBuf,
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{CHECKSUM_MISMATCH_MESSAGE} after copying {}",
+            self.path.to_string_lossy()
+        )
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+/// Per-job copy behavior taken from the settings when the job is queued.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CopyOptions {
+    /// Keep interrupted file copies staged and continue them on the next
+    /// copy of the same source to the same destination.
+    pub resume: bool,
+    /// Threads copying the small files of a directory tree; zero or one
+    /// copies the tree serially.
+    pub workers: usize,
+}
+
+/// Permission bits to set and clear, applied as `(mode & !clear) | set`.
+///
+/// Only the low twelve bits (rwx for user/group/other plus setuid, setgid and
+/// sticky) are considered; bits toggled in neither mask keep their per-entry
+/// value, so one mask can be applied to entries with different modes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PermissionMask {
+    pub set: u32,
+    pub clear: u32,
+}
+
+impl PermissionMask {
+    pub const BITS: u32 = 0o7777;
+
+    pub fn new(set: u32, clear: u32) -> Self {
+        Self {
+            set: set & Self::BITS,
+            clear: clear & Self::BITS & !set,
+        }
+    }
+
+    pub fn from_modes(original: u32, requested: u32) -> Self {
+        let changed = (original ^ requested) & Self::BITS;
+        Self::new(requested & changed, !requested & changed)
+    }
+
+    pub fn apply(self, mode: u32) -> u32 {
+        (mode & Self::BITS & !self.clear) | self.set
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.set == 0 && self.clear == 0
+    }
+
+    pub fn label(self) -> String {
+        match (self.set, self.clear) {
+            (0, 0) => String::from("unchanged"),
+            (set, 0) => format!("+{set:04o}"),
+            (0, clear) => format!("-{clear:04o}"),
+            (set, clear) => format!("+{set:04o} -{clear:04o}"),
         }
     }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum JobRequest {
+    /// With `verify`, every copied file is hashed while it is written and
+    /// re-read before it is put in place. `preserve` picks the extended
+    /// attributes copied along with times, permissions and owner.
+    /// `destination_names`, when set, names the copy of each source, and
+    /// `follow_links` picks the symlinks copied as their targets. With
+    /// `hard_links`, files hard-linked to each other are copied once and
+    /// linked to that copy again.
     Copy {
         sources: Vec<PathBuf>,
         destination_dir: PathBuf,
+        destination_names: Option<Vec<String>>,
         overwrite: OverwritePolicy,
+        verify: bool,
+        preserve: PreserveLevel,
+        follow_links: FollowLinks,
+        hard_links: bool,
     },
+    /// `verify` and `preserve` apply to moves that fall back to copying
+    /// across devices; a source is only removed once its copy verified.
     Move {
         sources: Vec<PathBuf>,
         destination_dir: PathBuf,
         destination_names: Option<Vec<String>>,
         overwrite: OverwritePolicy,
+        verify: bool,
+        preserve: PreserveLevel,
     },
     Delete {
         targets: Vec<PathBuf>,
     },
+    /// Moves targets to the freedesktop trash of their filesystem.
+    Trash {
+        targets: Vec<PathBuf>,
+    },
+    /// Moves trashed entries, identified by their `.trashinfo` files, back to
+    /// their original paths.
+    RestoreTrash {
+        items: Vec<PathBuf>,
+    },
+    /// Permanently deletes trashed entries and their `.trashinfo` files.
+    PurgeTrash {
+        items: Vec<PathBuf>,
+    },
     Mkdir {
         path: PathBuf,
     },
@@ -111,6 +600,41 @@ pub enum JobRequest {
         source: PathBuf,
         destination: PathBuf,
     },
+    Chmod {
+        targets: Vec<PathBuf>,
+        file_mask: PermissionMask,
+        dir_mask: PermissionMask,
+        recursive: bool,
+    },
+    /// Changes owner and/or group; advanced chown also carries permission
+    /// masks, which are applied after the ownership change.
+    Chown {
+        targets: Vec<PathBuf>,
+        owner: Option<u32>,
+        group: Option<u32>,
+        file_mask: PermissionMask,
+        dir_mask: PermissionMask,
+        recursive: bool,
+    },
+    /// Hard-links each source into `destination_dir` under its own name.
+    Link {
+        sources: Vec<PathBuf>,
+        destination_dir: PathBuf,
+        overwrite: OverwritePolicy,
+    },
+    /// Symlinks each source into `destination_dir`; relative links point at the
+    /// source as seen from the destination directory.
+    Symlink {
+        sources: Vec<PathBuf>,
+        destination_dir: PathBuf,
+        relative: bool,
+        overwrite: OverwritePolicy,
+    },
+    /// Points an existing symlink at `target`, replacing it atomically.
+    EditSymlink {
+        link: PathBuf,
+        target: PathBuf,
+    },
     PersistSettings {
         paths: SettingsPaths,
         snapshot: Box<Settings>,
@@ -151,6 +675,11 @@ pub enum JobRequest {
         max_depth: usize,
         max_entries: usize,
     },
+    /// Dry run of `request` for the preview of a confirm dialog.
+    PlanTransfer {
+        request: Box<JobRequest>,
+        request_id: u64,
+    },
 }
 
 impl JobRequest {
@@ -159,8 +688,16 @@ impl JobRequest {
             Self::Copy { .. } => JobKind::Copy,
             Self::Move { .. } => JobKind::Move,
             Self::Delete { .. } => JobKind::Delete,
+            Self::Trash { .. } => JobKind::Trash,
+            Self::RestoreTrash { .. } => JobKind::RestoreTrash,
+            Self::PurgeTrash { .. } => JobKind::PurgeTrash,
             Self::Mkdir { .. } => JobKind::Mkdir,
             Self::Rename { .. } => JobKind::Rename,
+            Self::Chmod { .. } => JobKind::Chmod,
+            Self::Chown { .. } => JobKind::Chown,
+            Self::Link { .. } => JobKind::Link,
+            Self::Symlink { .. } => JobKind::Symlink,
+            Self::EditSymlink { .. } => JobKind::EditSymlink,
             Self::PersistSettings { .. } => JobKind::PersistSettings,
             Self::RefreshPanel { .. } => JobKind::RefreshPanel,
             Self::Find { .. } => JobKind::Find,
@@ -169,7 +706,223 @@ impl JobRequest {
             Self::LoadQuickView { .. } => JobKind::LoadQuickView,
             Self::MeasureSelection { .. } => JobKind::MeasureSelection,
             Self::BuildTree { .. } => JobKind::BuildTree,
+            Self::PlanTransfer { .. } => JobKind::PlanTransfer,
+        }
+    }
+
+    /// Whether a failed or canceled job of this request can be queued again.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Copy { .. }
+                | Self::Move { .. }
+                | Self::Delete { .. }
+                | Self::Trash { .. }
+                | Self::RestoreTrash { .. }
+                | Self::PurgeTrash { .. }
+                | Self::Chmod { .. }
+                | Self::Chown { .. }
+                | Self::Link { .. }
+                | Self::Symlink { .. }
+        )
+    }
+
+    /// A copy that follows `follow_links`; other requests are unchanged.
+    pub fn with_follow_links(mut self, follow_links: FollowLinks) -> Self {
+        if let Self::Copy {
+            follow_links: follow,
+            ..
+        } = &mut self
+        {
+            *follow = follow_links;
+        }
+        self
+    }
+
+    /// A copy that keeps hard links with `hard_links`; other requests are
+    /// unchanged.
+    pub fn with_hard_links(mut self, hard_links: bool) -> Self {
+        if let Self::Copy {
+            hard_links: keep, ..
+        } = &mut self
+        {
+            *keep = hard_links;
+        }
+        self
+    }
+
+    /// The same request without the top-level sources in `completed`, or
+    /// `None` when none are left.
+    pub fn without_sources(&self, completed: &[PathBuf]) -> Option<Self> {
+        let completed = completed.iter().collect::<HashSet<_>>();
+        let keep_pending =
+            |paths: &mut Vec<PathBuf>| paths.retain(|path| !completed.contains(path));
+        let mut request = self.clone();
+        match &mut request {
+            Self::Copy {
+                sources,
+                destination_names: Some(names),
+                ..
+            }
+            | Self::Move {
+                sources,
+                destination_names: Some(names),
+                ..
+            } => {
+                // Custom names are matched to sources by position.
+                (*sources, *names) = sources
+                    .drain(..)
+                    .zip(names.drain(..))
+                    .filter(|(source, _)| !completed.contains(source))
+                    .unzip();
+            }
+            Self::Copy { sources, .. }
+            | Self::Move { sources, .. }
+            | Self::Link { sources, .. }
+            | Self::Symlink { sources, .. } => keep_pending(sources),
+            Self::Delete { targets }
+            | Self::Trash { targets }
+            | Self::Chmod { targets, .. }
+            | Self::Chown { targets, .. } => keep_pending(targets),
+            Self::RestoreTrash { items } | Self::PurgeTrash { items } => keep_pending(items),
+            _ => {}
         }
+        (request.item_count() > 0).then_some(request)
+    }
+
+    /// The directories whose devices this request keeps busy, each listed
+    /// once: those of copies and moves, which carry file contents, and of
+    /// deletes, attribute changes and other jobs over several items or a
+    /// recursive walk. Single-item jobs and links return none, so they never
+    /// queue behind a long copy. A source counts as being on the device of
+    /// its parent directory, so many sources from one directory cost the
+    /// scheduler a single look; only a mount point selected as a source
+    /// itself is attributed to the device it is mounted on.
+    pub fn device_paths(&self) -> Vec<&Path> {
+        let mut paths = match self {
+            Self::Copy {
+                sources,
+                destination_dir,
+                ..
+            }
+            | Self::Move {
+                sources,
+                destination_dir,
+                ..
+            } => {
+                let mut paths = walked_paths(sources, true);
+                paths.push(destination_dir.as_path());
+                paths
+            }
+            Self::Delete { targets } | Self::Trash { targets } => walked_paths(targets, false),
+            Self::Chmod {
+                targets, recursive, ..
+            }
+            | Self::Chown {
+                targets, recursive, ..
+            } => walked_paths(targets, *recursive),
+            Self::RestoreTrash { items } | Self::PurgeTrash { items } => walked_paths(items, false),
+            _ => Vec::new(),
+        };
+        let mut seen = HashSet::new();
+        paths.retain(|path| seen.insert(*path));
+        paths
+    }
+
+    /// The paths this request reads and writes, or `None` for jobs that
+    /// leave the filesystem alone. Copies and links write the entry each
+    /// source gets in the destination, not the whole destination directory;
+    /// restores write the paths their items were trashed from.
+    pub fn path_footprint(&self) -> Option<PathFootprint> {
+        let destinations =
+            |sources: &[PathBuf], destination_dir: &Path, names: Option<&[String]>| {
+                sources
+                    .iter()
+                    .enumerate()
+                    .map(
+                        |(index, source)| match names.and_then(|names| names.get(index)) {
+                            Some(name) => destination_dir.join(name),
+                            None => source.file_name().map_or_else(
+                                || destination_dir.to_path_buf(),
+                                |name| destination_dir.join(name),
+                            ),
+                        },
+                    )
+                    .collect::<Vec<_>>()
+            };
+        let writes_only = |writes: Vec<PathBuf>| PathFootprint {
+            reads: Vec::new(),
+            writes,
+        };
+        let footprint = match self {
+            Self::Copy {
+                sources,
+                destination_dir,
+                destination_names,
+                ..
+            } => PathFootprint {
+                reads: sources.clone(),
+                writes: destinations(sources, destination_dir, destination_names.as_deref()),
+            },
+            Self::Move {
+                sources,
+                destination_dir,
+                destination_names,
+                ..
+            } => {
+                let mut writes = sources.clone();
+                writes.extend(destinations(
+                    sources,
+                    destination_dir,
+                    destination_names.as_deref(),
+                ));
+                writes_only(writes)
+            }
+            Self::Link {
+                sources,
+                destination_dir,
+                ..
+            } => PathFootprint {
+                reads: sources.clone(),
+                writes: destinations(sources, destination_dir, None),
+            },
+            Self::Symlink {
+                sources,
+                destination_dir,
+                ..
+            } => writes_only(destinations(sources, destination_dir, None)),
+            Self::Delete { targets }
+            | Self::Trash { targets }
+            | Self::Chmod { targets, .. }
+            | Self::Chown { targets, .. } => writes_only(targets.clone()),
+            Self::RestoreTrash { items } => {
+                let mut writes = items.clone();
+                writes.extend(
+                    items
+                        .iter()
+                        .filter_map(|info_path| TrashedItem::load(info_path).ok())
+                        .map(|item| item.original_path),
+                );
+                writes_only(writes)
+            }
+            Self::PurgeTrash { items } => writes_only(items.clone()),
+            Self::Mkdir { path } => writes_only(vec![path.clone()]),
+            Self::Rename {
+                source,
+                destination,
+            } => writes_only(vec![source.clone(), destination.clone()]),
+            Self::EditSymlink { link, .. } => writes_only(vec![link.clone()]),
+            Self::PersistSettings { .. }
+            | Self::RefreshPanel { .. }
+            | Self::Find { .. }
+            | Self::QuickCdSearch { .. }
+            | Self::LoadViewer { .. }
+            | Self::LoadQuickView { .. }
+            | Self::MeasureSelection { .. }
+            | Self::BuildTree { .. }
+            | Self::PlanTransfer { .. } => return None,
+        };
+        Some(footprint)
     }
 
     pub fn item_count(&self) -> usize {
@@ -177,8 +930,16 @@ impl JobRequest {
             Self::Copy { sources, .. } => sources.len(),
             Self::Move { sources, .. } => sources.len(),
             Self::Delete { targets } => targets.len(),
+            Self::Trash { targets } => targets.len(),
+            Self::RestoreTrash { items } => items.len(),
+            Self::PurgeTrash { items } => items.len(),
             Self::Mkdir { .. } => 1,
             Self::Rename { .. } => 1,
+            Self::Chmod { targets, .. } => targets.len(),
+            Self::Chown { targets, .. } => targets.len(),
+            Self::Link { sources, .. } => sources.len(),
+            Self::Symlink { sources, .. } => sources.len(),
+            Self::EditSymlink { .. } => 1,
             Self::PersistSettings { .. } => 1,
             Self::RefreshPanel { .. } => 1,
             Self::Find { .. } => 1,
@@ -187,6 +948,7 @@ impl JobRequest {
             Self::LoadQuickView { .. } => 1,
             Self::MeasureSelection { paths, .. } => paths.len(),
             Self::BuildTree { .. } => 1,
+            Self::PlanTransfer { request, .. } => request.item_count(),
         }
     }
 
@@ -196,18 +958,28 @@ impl JobRequest {
                 sources,
                 destination_dir,
                 overwrite,
+                verify,
+                preserve,
+                follow_links,
+                hard_links,
+                ..
             } => format!(
-                "copy {} item(s) -> {} [{}]",
+                "copy {} item(s) -> {} [{}{}{}{}{}]",
                 sources.len(),
                 destination_dir.to_string_lossy(),
                 overwrite.label(),
+                verify_summary_suffix(*verify),
+                preserve_summary_suffix(*preserve),
+                follow_links_summary_suffix(*follow_links),
+                hard_links_summary_suffix(*hard_links),
             ),
             Self::Move {
                 sources,
                 destination_dir,
                 overwrite,
                 destination_names,
-                ..
+                verify,
+                preserve,
             } => {
                 let destination_suffix = match destination_names {
                     Some(destination_names) if !destination_names.is_empty() => {
@@ -217,14 +989,19 @@ impl JobRequest {
                     None => String::from(""),
                 };
                 format!(
-                    "move {} item(s) -> {} [{}]{}",
+                    "move {} item(s) -> {} [{}{}{}]{}",
                     sources.len(),
                     destination_dir.to_string_lossy(),
                     overwrite.label(),
+                    verify_summary_suffix(*verify),
+                    preserve_summary_suffix(*preserve),
                     destination_suffix
                 )
             }
             Self::Delete { targets } => format!("delete {} item(s)", targets.len()),
+            Self::Trash { targets } => format!("trash {} item(s)", targets.len()),
+            Self::RestoreTrash { items } => format!("restore {} trashed item(s)", items.len()),
+            Self::PurgeTrash { items } => format!("purge {} trashed item(s)", items.len()),
             Self::Mkdir { path } => format!("mkdir {}", path.to_string_lossy()),
             Self::Rename {
                 source,
@@ -234,14 +1011,79 @@ impl JobRequest {
                 source.to_string_lossy(),
                 destination.to_string_lossy()
             ),
-            Self::PersistSettings { paths, .. } => {
-                let target = paths
-                    .rc_ini_path
-                    .as_ref()
-                    .or(paths.mc_ini_path.as_ref())
-                    .map(|path| path.to_string_lossy().into_owned())
-                    .unwrap_or_else(|| String::from("<none>"));
-                format!("save setup -> {target}")
+            Self::Chmod {
+                targets,
+                file_mask,
+                dir_mask,
+                recursive,
+            } => format!(
+                "chmod {} item(s) [{}]{}",
+                targets.len(),
+                permission_masks_label(*file_mask, *dir_mask),
+                if *recursive { " recursive" } else { "" }
+            ),
+            Self::Chown {
+                targets,
+                owner,
+                group,
+                file_mask,
+                dir_mask,
+                recursive,
+            } => {
+                let id_label = |id: &Option<u32>| id.map(|id| id.to_string()).unwrap_or_default();
+                let mode_suffix = if file_mask.is_empty() && dir_mask.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{}]", permission_masks_label(*file_mask, *dir_mask))
+                };
+                format!(
+                    "chown {} item(s) -> {}:{}{}{}",
+                    targets.len(),
+                    id_label(owner),
+                    id_label(group),
+                    mode_suffix,
+                    if *recursive { " recursive" } else { "" }
+                )
+            }
+            Self::Link {
+                sources,
+                destination_dir,
+                overwrite,
+            } => format!(
+                "link {} item(s) -> {} [{}]",
+                sources.len(),
+                destination_dir.to_string_lossy(),
+                overwrite.label(),
+            ),
+            Self::Symlink {
+                sources,
+                destination_dir,
+                relative,
+                overwrite,
+            } => format!(
+                "{} {} item(s) -> {} [{}]",
+                if *relative {
+                    "relative symlink"
+                } else {
+                    "symlink"
+                },
+                sources.len(),
+                destination_dir.to_string_lossy(),
+                overwrite.label(),
+            ),
+            Self::EditSymlink { link, target } => format!(
+                "edit symlink {} -> {}",
+                link.to_string_lossy(),
+                target.to_string_lossy()
+            ),
+            Self::PersistSettings { paths, .. } => {
+                let target = paths
+                    .rc_ini_path
+                    .as_ref()
+                    .or(paths.mc_ini_path.as_ref())
+                    .map(|path| path.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| String::from("<none>"));
+                format!("save setup -> {target}")
             }
             Self::RefreshPanel {
                 panel,
@@ -300,6 +1142,7 @@ impl JobRequest {
                     max_entries
                 )
             }
+            Self::PlanTransfer { request, .. } => format!("preview {}", request.summary()),
         }
     }
 }
@@ -311,6 +1154,37 @@ pub struct JobProgress {
     pub items_done: u64,
     pub bytes_total: u64,
     pub bytes_done: u64,
+    /// Part of `bytes_done` kept from interrupted copies instead of copied.
+    pub bytes_resumed: u64,
+    /// Part of `bytes_done` hard-linked to an earlier copy instead of copied.
+    pub bytes_linked: u64,
+    pub phase: JobPhase,
+    /// How the copied files' contents were transferred so far.
+    pub copy_strategies: CopyStrategies,
+    /// Symlinks a copy replaced with their targets.
+    pub links_followed: u64,
+    /// Throughput over the last few seconds; zero until it is known.
+    pub bytes_per_second: u64,
+    /// Time left at the current throughput.
+    pub eta_seconds: Option<u64>,
+}
+
+/// What a running job is doing right now, shown on the jobs screen.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JobPhase {
+    #[default]
+    Transferring,
+    /// Re-reading a copied file to compare its checksum with the source.
+    Verifying,
+}
+
+impl JobPhase {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Transferring => "running",
+            Self::Verifying => "verifying",
+        }
+    }
 }
 
 impl JobProgress {
@@ -332,12 +1206,26 @@ impl JobProgress {
         let overall = bytes_pct.max(items_pct).min(100);
         overall as u8
     }
+
+    /// Throughput and time left, e.g. `12.5M/s ETA 1:05`, once measured.
+    pub fn rate_label(&self) -> Option<String> {
+        if self.bytes_per_second == 0 {
+            return None;
+        }
+        let rate = format_rate(self.bytes_per_second);
+        Some(match self.eta_seconds {
+            Some(eta) => format!("{rate} ETA {}", format_eta(eta)),
+            None => rate,
+        })
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum JobStatus {
     Queued,
     Running,
+    /// Held by [`JobManager::set_paused`] at its next progress check.
+    Paused,
     Succeeded,
     Canceled,
     Failed,
@@ -359,6 +1247,8 @@ pub enum JobErrorCode {
     InvalidInput,
     Interrupted,
     Unsupported,
+    /// A verified copy read back different bytes than the source produced.
+    VerificationFailed,
     Dispatch,
     Other,
 }
@@ -392,6 +1282,16 @@ impl JobError {
     pub fn from_io(error: io::Error) -> Self {
         let kind = error.kind();
         let message = error.to_string();
+        if error
+            .get_ref()
+            .is_some_and(|inner| inner.is::<ChecksumMismatch>())
+        {
+            return Self {
+                code: JobErrorCode::VerificationFailed,
+                message,
+                retry_hint: JobRetryHint::Retry,
+            };
+        }
         match kind {
             io::ErrorKind::PermissionDenied => Self {
                 code: JobErrorCode::PermissionDenied,
@@ -470,6 +1370,25 @@ pub struct JobRecord {
     pub status: JobStatus,
     pub progress: Option<JobProgress>,
     pub last_error: Option<String>,
+    /// Problems that did not fail the job, such as attributes a copy could
+    /// not carry over.
+    pub warnings: Vec<String>,
+    /// This job's own bandwidth cap in bytes per second; zero is unlimited.
+    pub bandwidth_limit: u64,
+    /// The request a failed or canceled job is retried with; kept only for
+    /// [`JobRequest::is_retryable`] requests.
+    pub retry_request: Option<JobRequest>,
+    /// Top-level sources of `retry_request` that were finished.
+    pub completed_sources: Vec<PathBuf>,
+    /// Where the unfinished source the job is working on is being written.
+    pub claimed_destination: Option<ClaimedDestination>,
+    /// How the failure of a failed job can be dealt with.
+    pub retry_hint: Option<JobRetryHint>,
+    /// Items that failed and were skipped, to be looked at once the job is
+    /// done.
+    pub skipped_items: Vec<ItemFailure>,
+    /// The earlier job a queued job waits for because their paths overlap.
+    pub waiting_for: Option<PathWait>,
 }
 
 #[derive(Clone, Debug)]
@@ -477,7 +1396,16 @@ pub struct WorkerJob {
     pub id: JobId,
     pub request: JobRequest,
     cancel_flag: Arc<AtomicBool>,
+    overwrite_prompt: Arc<WorkerPrompt<OverwriteAnswer>>,
+    error_prompt: Arc<WorkerPrompt<ItemErrorAnswer>>,
     find_pause_flag: Option<Arc<AtomicBool>>,
+    pause_flag: Arc<AtomicBool>,
+    copy_options: CopyOptions,
+    continue_on_error: bool,
+    throttle: Arc<Throttle>,
+    global_throttle: Arc<Throttle>,
+    runtime_slot: Option<Arc<dyn RuntimeSlot>>,
+    claimed_destination: Option<ClaimedDestination>,
 }
 
 impl WorkerJob {
@@ -489,9 +1417,34 @@ impl WorkerJob {
         self.find_pause_flag = Some(pause_flag);
     }
 
+    pub fn set_copy_options(&mut self, options: CopyOptions) {
+        self.copy_options = options;
+    }
+
+    /// Skip failed items without asking, as if "Skip all" was answered
+    /// before the first failure.
+    pub fn set_continue_on_error(&mut self, continue_on_error: bool) {
+        self.continue_on_error = continue_on_error;
+    }
+
+    pub fn set_runtime_slot(&mut self, runtime_slot: Arc<dyn RuntimeSlot>) {
+        self.runtime_slot = Some(runtime_slot);
+    }
+
+    /// Names the destination an interrupted run of this copy or move left
+    /// half done. It is completed instead of being treated as a conflict:
+    /// entries that match the source are kept, the rest are copied again.
+    pub fn set_claimed_destination(&mut self, claimed: ClaimedDestination) {
+        self.claimed_destination = Some(claimed);
+    }
+
     pub fn find_pause_flag(&self) -> Option<Arc<AtomicBool>> {
         self.find_pause_flag.as_ref().map(Arc::clone)
     }
+
+    pub fn pause_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.pause_flag)
+    }
 }
 
 #[derive(Debug)]
@@ -503,9 +1456,54 @@ pub enum WorkerCommand {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum JobEvent {
-    Started { id: JobId },
-    Progress { id: JobId, progress: JobProgress },
-    Finished { id: JobId, result: JobResult },
+    Started {
+        id: JobId,
+    },
+    Progress {
+        id: JobId,
+        progress: JobProgress,
+    },
+    Finished {
+        id: JobId,
+        result: JobResult,
+    },
+    /// A completed step that the undo journal can revert.
+    Undoable {
+        id: JobId,
+        entry: UndoEntry,
+    },
+    /// The worker is blocked until [`JobManager::answer_overwrite`] is called.
+    OverwriteConflict {
+        id: JobId,
+        conflict: OverwriteConflict,
+    },
+    /// The worker is blocked until [`JobManager::answer_item_error`] is
+    /// called.
+    ItemFailed {
+        id: JobId,
+        failure: ItemFailure,
+    },
+    /// A failed item was skipped; the job keeps going.
+    ItemSkipped {
+        id: JobId,
+        failure: ItemFailure,
+    },
+    /// A step that only partly succeeded; the job keeps going.
+    Warning {
+        id: JobId,
+        message: String,
+    },
+    /// A top-level source of the request is done or was skipped.
+    SourceCompleted {
+        id: JobId,
+        source: PathBuf,
+    },
+    /// A top-level source is about to be written to a destination that was
+    /// free until now.
+    DestinationClaimed {
+        id: JobId,
+        claimed: ClaimedDestination,
+    },
 }
 
 #[derive(Debug)]
@@ -514,6 +1512,12 @@ pub struct JobManager {
     jobs: Vec<JobRecord>,
     index_by_id: HashMap<JobId, usize>,
     cancel_flags: HashMap<JobId, Arc<AtomicBool>>,
+    pause_flags: HashMap<JobId, Arc<AtomicBool>>,
+    started: HashSet<JobId>,
+    overwrite_prompts: HashMap<JobId, Arc<WorkerPrompt<OverwriteAnswer>>>,
+    error_prompts: HashMap<JobId, Arc<WorkerPrompt<ItemErrorAnswer>>>,
+    throttles: HashMap<JobId, Arc<Throttle>>,
+    global_throttle: Arc<Throttle>,
 }
 
 impl Default for JobManager {
@@ -529,6 +1533,12 @@ impl JobManager {
             jobs: Vec::new(),
             index_by_id: HashMap::new(),
             cancel_flags: HashMap::new(),
+            pause_flags: HashMap::new(),
+            started: HashSet::new(),
+            overwrite_prompts: HashMap::new(),
+            error_prompts: HashMap::new(),
+            throttles: HashMap::new(),
+            global_throttle: Arc::new(Throttle::default()),
         }
     }
 
@@ -543,11 +1553,28 @@ impl JobManager {
             status: JobStatus::Queued,
             progress: None,
             last_error: None,
+            warnings: Vec::new(),
+            bandwidth_limit: 0,
+            retry_request: request.is_retryable().then(|| request.clone()),
+            completed_sources: Vec::new(),
+            claimed_destination: None,
+            retry_hint: None,
+            skipped_items: Vec::new(),
+            waiting_for: None,
         };
         self.index_by_id.insert(id, self.jobs.len());
         self.jobs.push(record);
         self.cancel_flags
             .insert(id, Arc::new(AtomicBool::new(false)));
+        let overwrite_prompt = Arc::new(WorkerPrompt::default());
+        self.overwrite_prompts
+            .insert(id, Arc::clone(&overwrite_prompt));
+        let error_prompt = Arc::new(WorkerPrompt::default());
+        self.error_prompts.insert(id, Arc::clone(&error_prompt));
+        let throttle = Arc::new(Throttle::default());
+        self.throttles.insert(id, Arc::clone(&throttle));
+        let pause_flag = Arc::new(AtomicBool::new(false));
+        self.pause_flags.insert(id, Arc::clone(&pause_flag));
 
         WorkerJob {
             id,
@@ -557,17 +1584,119 @@ impl JobManager {
                 .get(&id)
                 .expect("job cancellation flag should exist")
                 .clone(),
+            overwrite_prompt,
+            error_prompt,
             find_pause_flag: None,
+            pause_flag,
+            copy_options: CopyOptions::default(),
+            continue_on_error: false,
+            throttle,
+            global_throttle: Arc::clone(&self.global_throttle),
+            runtime_slot: None,
+            claimed_destination: None,
+        }
+    }
+
+    /// Caps the bandwidth of a queued or running job; takes effect on the
+    /// job's next write. Returns `false` when the job already finished.
+    pub fn set_bandwidth_limit(&mut self, id: JobId, bytes_per_second: u64) -> bool {
+        let Some(throttle) = self.throttles.get(&id) else {
+            return false;
+        };
+        throttle.set_limit(bytes_per_second);
+        if let Some(job) = self.job_mut(id) {
+            job.bandwidth_limit = bytes_per_second;
+        }
+        true
+    }
+
+    /// The cap shared by all jobs, on top of their own.
+    pub fn global_bandwidth_limit(&self) -> u64 {
+        self.global_throttle.limit()
+    }
+
+    pub fn set_global_bandwidth_limit(&mut self, bytes_per_second: u64) {
+        self.global_throttle.set_limit(bytes_per_second);
+    }
+
+    /// Pauses or resumes a queued, running or paused job of a pausable
+    /// kind. A paused job keeps its progress and waits at its next check;
+    /// one paused while queued waits as soon as it starts. Returns `false`
+    /// when the job cannot be paused or is already in the wanted state.
+    pub fn set_paused(&mut self, id: JobId, paused: bool) -> bool {
+        let started = self.started.contains(&id);
+        let Some(flag) = self.pause_flags.get(&id).map(Arc::clone) else {
+            return false;
+        };
+        let Some(job) = self.job_mut(id) else {
+            return false;
+        };
+        if !job.kind.is_pausable()
+            || !matches!(
+                job.status,
+                JobStatus::Queued | JobStatus::Running | JobStatus::Paused
+            )
+            || (job.status == JobStatus::Paused) == paused
+        {
+            return false;
+        }
+        flag.store(paused, Ordering::Relaxed);
+        job.status = match (paused, started) {
+            (true, _) => JobStatus::Paused,
+            (false, true) => JobStatus::Running,
+            (false, false) => JobStatus::Queued,
+        };
+        true
+    }
+
+    /// Pauses every pausable job that is not paused yet, or resumes all of
+    /// them when none is left to pause. Returns the jobs that changed and
+    /// whether they were paused.
+    pub fn toggle_pause_all(&mut self) -> (Vec<JobId>, bool) {
+        let pausable = |job: &&JobRecord, status: &[JobStatus]| {
+            job.kind.is_pausable() && status.contains(&job.status)
+        };
+        let mut ids: Vec<JobId> = self
+            .jobs
+            .iter()
+            .filter(|job| pausable(job, &[JobStatus::Queued, JobStatus::Running]))
+            .map(|job| job.id)
+            .collect();
+        let pause = !ids.is_empty();
+        if !pause {
+            ids = self
+                .jobs
+                .iter()
+                .filter(|job| pausable(job, &[JobStatus::Paused]))
+                .map(|job| job.id)
+                .collect();
         }
+        ids.retain(|id| self.set_paused(*id, pause));
+        (ids, pause)
     }
 
     pub fn handle_event(&mut self, event: &JobEvent) {
         match event {
             JobEvent::Started { id } => {
+                self.started.insert(*id);
+                let paused = self
+                    .pause_flags
+                    .get(id)
+                    .is_some_and(|flag| flag.load(Ordering::Relaxed));
                 if let Some(job) = self.job_mut(*id) {
-                    job.status = JobStatus::Running;
+                    job.status = if paused {
+                        JobStatus::Paused
+                    } else {
+                        JobStatus::Running
+                    };
                     job.progress = None;
                     job.last_error = None;
+                    job.warnings.clear();
+                    job.completed_sources.clear();
+                    job.claimed_destination = None;
+                    job.retry_hint = None;
+                    job.skipped_items.clear();
+                    job.waiting_for = None;
                 }
             }
             JobEvent::Progress { id, progress } => {
@@ -577,6 +1706,7 @@ impl JobManager {
             }
             JobEvent::Finished { id, result } => {
                 if let Some(job) = self.job_mut(*id) {
+                    job.waiting_for = None;
                     match result {
                         Ok(()) => {
                             job.status = JobStatus::Succeeded;
@@ -584,6 +1714,22 @@ impl JobManager {
                                 progress.current_path = None;
                                 progress.items_done = progress.items_total;
                                 progress.bytes_done = progress.bytes_total;
+                                if !progress.copy_strategies.is_empty() {
+                                    job.summary =
+                                        format!("{} via {}", job.summary, progress.copy_strategies);
+                                }
+                                if progress.links_followed > 0 {
+                                    job.summary = format!(
+                                        "{}, {} link(s) followed",
+                                        job.summary, progress.links_followed
+                                    );
+                                }
+                                if progress.bytes_linked > 0 {
+                                    job.summary = format!(
+                                        "{}, {} byte(s) hard-linked",
+                                        job.summary, progress.bytes_linked
+                                    );
+                                }
                             }
                             job.last_error = None;
                         }
@@ -594,12 +1740,42 @@ impl JobManager {
                             } else {
                                 job.status = JobStatus::Failed;
                                 job.last_error = Some(error.message.clone());
+                                job.retry_hint = Some(error.retry_hint);
                             }
                         }
                     }
                 }
                 self.cancel_flags.remove(id);
+                self.pause_flags.remove(id);
+                self.started.remove(id);
+                self.overwrite_prompts.remove(id);
+                self.error_prompts.remove(id);
+                self.throttles.remove(id);
+            }
+            JobEvent::Warning { id, message } => {
+                if let Some(job) = self.job_mut(*id) {
+                    job.warnings.push(message.clone());
+                }
+            }
+            JobEvent::SourceCompleted { id, source } => {
+                if let Some(job) = self.job_mut(*id) {
+                    job.completed_sources.push(source.clone());
+                    job.claimed_destination = None;
+                }
+            }
+            JobEvent::DestinationClaimed { id, claimed } => {
+                if let Some(job) = self.job_mut(*id) {
+                    job.claimed_destination = Some(claimed.clone());
+                }
             }
+            JobEvent::ItemSkipped { id, failure } => {
+                if let Some(job) = self.job_mut(*id) {
+                    job.skipped_items.push(failure.clone());
+                }
+            }
+            JobEvent::Undoable { .. }
+            | JobEvent::OverwriteConflict { .. }
+            | JobEvent::ItemFailed { .. } => {}
         }
     }
 
@@ -607,7 +1783,10 @@ impl JobManager {
         let Some(job) = self.jobs.iter().find(|job| job.id == id) else {
             return false;
         };
-        if !matches!(job.status, JobStatus::Queued | JobStatus::Running) {
+        if !matches!(
+            job.status,
+            JobStatus::Queued | JobStatus::Running | JobStatus::Paused
+        ) {
             return false;
         }
 
@@ -617,6 +1796,33 @@ impl JobManager {
         !flag.swap(true, Ordering::Relaxed)
     }
 
+    /// Unblocks a worker waiting on an overwrite conflict. Returns `false`
+    /// when the job already finished.
+    pub fn answer_overwrite(&mut self, id: JobId, answer: OverwriteAnswer) -> bool {
+        let Some(prompt) = self.overwrite_prompts.get(&id) else {
+            return false;
+        };
+        prompt.answer(answer);
+        true
+    }
+
+    /// Unblocks a worker waiting on a failed item. Returns `false` when the
+    /// job already finished.
+    pub fn answer_item_error(&mut self, id: JobId, answer: ItemErrorAnswer) -> bool {
+        let Some(prompt) = self.error_prompts.get(&id) else {
+            return false;
+        };
+        prompt.answer(answer);
+        true
+    }
+
+    /// Records the job a queued job waits for, or that it waits no more.
+    pub(crate) fn set_waiting_for(&mut self, id: JobId, wait: Option<PathWait>) {
+        if let Some(job) = self.job_mut(id) {
+            job.waiting_for = wait;
+        }
+    }
+
     pub fn clear_cancel_request(&mut self, id: JobId) -> bool {
         let Some(flag) = self.cancel_flags.get(&id) else {
             return false;
@@ -644,7 +1850,7 @@ impl JobManager {
         self.jobs
             .iter()
             .rev()
-            .find(|job| job.status == JobStatus::Running)
+            .find(|job| matches!(job.status, JobStatus::Running | JobStatus::Paused))
             .or_else(|| {
                 self.jobs
                     .iter()
@@ -660,6 +1866,7 @@ impl JobManager {
             match job.status {
                 JobStatus::Queued => counts.queued += 1,
                 JobStatus::Running => counts.running += 1,
+                JobStatus::Paused => counts.paused += 1,
                 JobStatus::Succeeded => counts.succeeded += 1,
                 JobStatus::Canceled => counts.canceled += 1,
                 JobStatus::Failed => counts.failed += 1,
@@ -691,6 +1898,7 @@ impl JobManager {
 pub struct JobStatusCounts {
     pub queued: usize,
     pub running: usize,
+    pub paused: usize,
     pub succeeded: usize,
     pub canceled: usize,
     pub failed: usize,
@@ -878,6 +2086,15 @@ fn run_single_job(job: WorkerJob, event_tx: &Sender<JobEvent>, fs_backend: &dyn
         id,
         request,
         cancel_flag,
+        overwrite_prompt,
+        error_prompt,
+        pause_flag,
+        copy_options,
+        continue_on_error,
+        throttle,
+        global_throttle,
+        runtime_slot,
+        claimed_destination,
         ..
     } = job;
     let _ = event_tx.send(JobEvent::Started { id });
@@ -901,7 +2118,21 @@ fn run_single_job(job: WorkerJob, event_tx: &Sender<JobEvent>, fs_backend: &dyn
         }
     };
 
-    let mut progress = ProgressTracker::new(id, totals, event_tx, cancel_flag);
+    // Advisory only: a failed check leaves it to the job to fail.
+    if let Ok(warnings) = transfer_preflight(&request, totals, cancel_flag.as_ref()) {
+        for message in warnings {
+            let _ = event_tx.send(JobEvent::Warning { id, message });
+        }
+    }
+
+    let mut progress = ProgressTracker::new(id, totals, event_tx, cancel_flag)
+        .with_overwrite_prompt(overwrite_prompt)
+        .with_error_policy(error_prompt, continue_on_error)
+        .with_pause_flag(pause_flag)
+        .with_copy_options(copy_options)
+        .with_throttles(throttle, global_throttle);
+    progress.runtime_slot = runtime_slot;
+    progress.claimed_destination = claimed_destination;
     progress.emit();
     if let Err(error) = progress.ensure_not_canceled() {
         let _ = event_tx.send(JobEvent::Finished {
@@ -926,25 +2157,52 @@ fn execute_job(
         JobRequest::Copy {
             sources,
             destination_dir,
+            destination_names,
             overwrite,
-        } => copy_paths(&sources, &destination_dir, overwrite, progress),
+            verify,
+            preserve,
+            follow_links,
+            hard_links,
+        } => {
+            progress.verify = verify;
+            progress.preserve = preserve;
+            progress.follow_links = follow_links;
+            progress.hard_links = hard_links.then(HashMap::new);
+            copy_paths(
+                &sources,
+                &destination_dir,
+                destination_names.as_deref(),
+                overwrite,
+                progress,
+            )
+        }
         JobRequest::Move {
             sources,
             destination_dir,
             destination_names,
             overwrite,
-        } => move_paths(
-            &sources,
-            &destination_dir,
-            destination_names.as_deref(),
-            overwrite,
-            progress,
-        ),
+            verify,
+            preserve,
+        } => {
+            progress.verify = verify;
+            progress.preserve = preserve;
+            move_paths(
+                &sources,
+                &destination_dir,
+                destination_names.as_deref(),
+                overwrite,
+                progress,
+            )
+        }
         JobRequest::Delete { targets } => delete_paths(&targets, progress),
+        JobRequest::Trash { targets } => trash_paths(&targets, progress),
+        JobRequest::RestoreTrash { items } => restore_trashed_items(&items, progress),
+        JobRequest::PurgeTrash { items } => purge_trashed_items(&items, progress),
         JobRequest::Mkdir { path } => {
             progress.set_current_path(&path);
             fs_backend.create_dir(&path)?;
             progress.complete_item(&path);
+            progress.record_undo(UndoOperation::Mkdir, &path, &path);
             Ok(())
         }
         JobRequest::Rename {
@@ -954,6 +2212,64 @@ fn execute_job(
             progress.set_current_path(&source);
             fs_backend.rename(&source, &destination)?;
             progress.complete_item(&destination);
+            progress.record_undo(UndoOperation::Rename, &source, &destination);
+            Ok(())
+        }
+        JobRequest::Chmod {
+            targets,
+            file_mask,
+            dir_mask,
+            recursive,
+        } => change_attributes(
+            &targets,
+            AttributeChange {
+                owner: None,
+                group: None,
+                file_mask,
+                dir_mask,
+                recursive,
+            },
+            progress,
+        ),
+        JobRequest::Chown {
+            targets,
+            owner,
+            group,
+            file_mask,
+            dir_mask,
+            recursive,
+        } => change_attributes(
+            &targets,
+            AttributeChange {
+                owner,
+                group,
+                file_mask,
+                dir_mask,
+                recursive,
+            },
+            progress,
+        ),
+        JobRequest::Link {
+            sources,
+            destination_dir,
+            overwrite,
+        } => link_paths(&sources, &destination_dir, None, overwrite, progress),
+        JobRequest::Symlink {
+            sources,
+            destination_dir,
+            relative,
+            overwrite,
+        } => link_paths(
+            &sources,
+            &destination_dir,
+            Some(relative),
+            overwrite,
+            progress,
+        ),
+        JobRequest::EditSymlink { link, target } => {
+            progress.set_current_path(&link);
+            retarget_symlink(&link, &target)?;
+            progress.complete_item(&link);
             Ok(())
         }
         JobRequest::PersistSettings { paths, snapshot } => {
@@ -995,26 +2311,73 @@ fn execute_job(
             io::ErrorKind::Unsupported,
             "tree jobs are executed by the runtime adapter",
         )),
+        JobRequest::PlanTransfer { .. } => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "preview jobs are executed by the runtime adapter",
+        )),
     }
 }
 
 fn copy_paths(
     sources: &[PathBuf],
     destination_dir: &Path,
+    destination_names: Option<&[String]>,
     overwrite: OverwritePolicy,
     progress: &mut ProgressTracker<'_>,
 ) -> io::Result<()> {
-    for source in sources {
+    let named_destinations = match destination_names {
+        Some(_) => Some(checked_move_plan(
+            sources,
+            destination_dir,
+            destination_names,
+            false,
+        )?),
+        None => None,
+    };
+    for (index, source) in sources.iter().enumerate() {
         progress.ensure_not_canceled()?;
-        let destination = destination_path(source, destination_dir)?;
-        let source_totals = measure_path_totals(source, progress.cancel_flag.as_ref())?;
-        let Some(destination) =
-            resolve_destination(source, destination, overwrite, source_totals, progress)?
-        else {
-            continue;
+        let destination = match &named_destinations {
+            Some(plan) => plan[index].destination.clone(),
+            None => destination_path(source, destination_dir)?,
         };
-        let copy_result = copy_path(source, &destination.path, progress);
-        destination.finish(copy_result)?;
+        progress.attempt_item(source, |progress| {
+            copy_source(source, destination.clone(), overwrite, progress)
+        })?;
+        progress.complete_source(source);
+    }
+    Ok(())
+}
+
+fn copy_source(
+    source: &Path,
+    destination: PathBuf,
+    overwrite: OverwritePolicy,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    if let Some(claimed) = progress.resumed_destination(source) {
+        return merge_copy(source, &claimed, progress);
+    }
+    let source_totals = measure_path_totals(source, progress.cancel_flag.as_ref())?;
+    let Some(destination) =
+        resolve_destination(source, destination, overwrite, source_totals, progress)?
+    else {
+        return Ok(());
+    };
+    if !destination.append {
+        progress.claim_destination(source, &destination.path);
+    }
+    // Overwritten or appended targets cannot be brought back, so only
+    // fresh copies are undoable.
+    let undoable = destination.overwrite_backup.is_none() && !destination.append;
+    let destination_path = destination.path.clone();
+    let copy_result = if destination.append {
+        append_file(source, &destination_path, progress)
+    } else {
+        copy_path(source, &destination_path, progress)
+    };
+    destination.finish(copy_result)?;
+    if undoable {
+        progress.record_undo(UndoOperation::Copy, source, &destination_path);
     }
     Ok(())
 }
@@ -1026,41 +2389,164 @@ fn move_paths(
     overwrite: OverwritePolicy,
     progress: &mut ProgressTracker<'_>,
 ) -> io::Result<()> {
-    if let Some(destination_names) = destination_names
-        && destination_names.len() != sources.len()
-    {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            "move rename requires one destination name per source",
-        ));
+    let move_plan = checked_move_plan(sources, destination_dir, destination_names, true)?;
+    let parked = park_move_cycles(&move_plan)?;
+
+    for (planned_move, parked) in move_plan.into_iter().zip(parked) {
+        // Progress and undo name the selected source even when it was parked.
+        let selected = planned_move.source;
+        let source = parked.as_deref().unwrap_or(selected);
+        progress.ensure_not_canceled()?;
+        let moved = progress.attempt_item(selected, |progress| {
+            move_source(
+                selected,
+                source,
+                planned_move.destination.clone(),
+                overwrite,
+                progress,
+            )
+        })?;
+        if !moved && source != selected && fs::rename(source, selected).is_err() {
+            progress.warn(format!(
+                "{}: left at {}",
+                selected.to_string_lossy(),
+                source.to_string_lossy()
+            ));
+        }
+        progress.complete_source(selected);
     }
+    Ok(())
+}
 
-    let move_plan = build_move_plan(sources, destination_dir, destination_names)?;
-    validate_move_plan(&move_plan)?;
+/// Moves `source`, which is `selected` or the temporary name it was parked
+/// under, to `destination`.
+fn move_source(
+    selected: &Path,
+    source: &Path,
+    destination: PathBuf,
+    overwrite: OverwritePolicy,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    if let Some(claimed) = progress.resumed_destination(selected) {
+        merge_copy(source, &claimed, progress)?;
+        return remove_path(source);
+    }
+    let source_totals = measure_path_totals(source, progress.cancel_flag.as_ref())?;
+    if is_case_only_rename(source, &destination)? {
+        move_path(source, &destination, source_totals, progress)?;
+        progress.record_undo(UndoOperation::Move, selected, &destination);
+        return Ok(());
+    }
 
-    for planned_move in move_plan {
-        let source = planned_move.source;
-        progress.ensure_not_canceled()?;
-        let source_totals = measure_path_totals(source, progress.cancel_flag.as_ref())?;
-        if is_case_only_rename(source, &planned_move.destination)? {
-            move_path(source, &planned_move.destination, source_totals, progress)?;
+    let Some(destination) =
+        resolve_destination(source, destination, overwrite, source_totals, progress)?
+    else {
+        return Ok(());
+    };
+    let destination_path = destination.path.clone();
+    if !destination.append {
+        progress.claim_destination(selected, &destination_path);
+    }
+    if destination.append {
+        // Fails unless the appended bytes checked out, keeping the source.
+        append_file(source, &destination_path, progress)?;
+        return remove_path(source);
+    }
+    // As with copies, an overwritten target cannot be brought back.
+    let undoable = destination.overwrite_backup.is_none();
+    let move_result = move_path(source, &destination_path, source_totals, progress);
+    destination.finish(move_result)?;
+    if undoable {
+        progress.record_undo(UndoOperation::Move, selected, &destination_path);
+    }
+    Ok(())
+}
+
+/// Renames every source that is part of a cycle of moves to a free temporary
+/// name next to it, so the moves of a swap no longer meet an existing
+/// destination. Returns the parked path of each planned move, if any; when
+/// parking fails the sources parked so far are put back.
+fn park_move_cycles(move_plan: &[PlannedMove<'_>]) -> io::Result<Vec<Option<PathBuf>>> {
+    let cycles = move_cycles(move_plan);
+    let mut parked = vec![None; move_plan.len()];
+    for (index, planned_move) in move_plan.iter().enumerate() {
+        if !cycles[index] {
             continue;
         }
+        let temporary = parking_path(planned_move.source);
+        if let Err(error) = fs::rename(planned_move.source, &temporary) {
+            for (planned_move, parked) in move_plan.iter().zip(&parked) {
+                if let Some(parked) = parked {
+                    let _ = fs::rename(parked, planned_move.source);
+                }
+            }
+            return Err(io::Error::new(
+                error.kind(),
+                format!(
+                    "failed to move {} aside for a swap: {error}",
+                    planned_move.source.to_string_lossy()
+                ),
+            ));
+        }
+        parked[index] = Some(temporary);
+    }
+    Ok(parked)
+}
 
-        let Some(destination) = resolve_destination(
-            source,
-            planned_move.destination,
-            overwrite,
-            source_totals,
-            progress,
-        )?
-        else {
+fn parking_path(source: &Path) -> PathBuf {
+    let parent = source.parent().unwrap_or(Path::new("."));
+    let file_name = source
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| String::from("item"));
+    (1_usize..)
+        .map(|index| parent.join(format!(".{file_name}.rc-rename{index}")))
+        .find(|candidate| fs::symlink_metadata(candidate).is_err())
+        .expect("an unused temporary name exists")
+}
+
+/// Which planned moves belong to a closed cycle, such as a swap, where the
+/// destination of every move is the source of the next one. Open chains are
+/// not cycles; they would depend on the order of the moves.
+fn move_cycles(move_plan: &[PlannedMove<'_>]) -> Vec<bool> {
+    let source_indices: HashMap<&Path, usize> = move_plan
+        .iter()
+        .enumerate()
+        .map(|(index, planned_move)| (planned_move.source, index))
+        .collect();
+    let next = |index: usize| {
+        source_indices
+            .get(move_plan[index].destination.as_path())
+            .copied()
+            .filter(|next| *next != index)
+    };
+    let mut cycles = vec![false; move_plan.len()];
+    let mut visited = vec![false; move_plan.len()];
+    for start in 0..move_plan.len() {
+        if visited[start] {
             continue;
+        }
+        let mut walk = vec![start];
+        visited[start] = true;
+        let mut current = start;
+        let closed = loop {
+            match next(current) {
+                Some(index) if index == start => break true,
+                Some(index) if !visited[index] => {
+                    visited[index] = true;
+                    walk.push(index);
+                    current = index;
+                }
+                _ => break false,
+            }
         };
-        let move_result = move_path(source, &destination.path, source_totals, progress);
-        destination.finish(move_result)?;
+        if closed {
+            for index in walk {
+                cycles[index] = true;
+            }
+        }
     }
-    Ok(())
+    cycles
 }
 
 #[derive(Debug)]
@@ -1101,11 +2587,49 @@ fn build_move_plan<'a>(
         .collect()
 }
 
-fn validate_custom_destination_name(destination_name: &str) -> io::Result<()> {
-    let path = Path::new(destination_name);
-    let mut components = path.components();
-    let is_single_normal_component = matches!(
-        (components.next(), components.next()),
+/// Checks custom names for a copy or move before it is queued: one single
+/// file name per source, and no two sources or a source and a destination
+/// meeting on one path. With `allow_cycles`, as for a move, sources that
+/// trade names in a closed cycle are accepted.
+pub(crate) fn validate_destination_names(
+    sources: &[PathBuf],
+    destination_dir: &Path,
+    destination_names: &[String],
+    allow_cycles: bool,
+) -> io::Result<()> {
+    checked_move_plan(
+        sources,
+        destination_dir,
+        Some(destination_names),
+        allow_cycles,
+    )
+    .map(|_| ())
+}
+
+fn checked_move_plan<'a>(
+    sources: &'a [PathBuf],
+    destination_dir: &Path,
+    destination_names: Option<&[String]>,
+    allow_cycles: bool,
+) -> io::Result<Vec<PlannedMove<'a>>> {
+    if let Some(destination_names) = destination_names
+        && destination_names.len() != sources.len()
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "rename requires one destination name per source",
+        ));
+    }
+    let move_plan = build_move_plan(sources, destination_dir, destination_names)?;
+    validate_move_plan(&move_plan, allow_cycles)?;
+    Ok(move_plan)
+}
+
+fn validate_custom_destination_name(destination_name: &str) -> io::Result<()> {
+    let path = Path::new(destination_name);
+    let mut components = path.components();
+    let is_single_normal_component = matches!(
+        (components.next(), components.next()),
         (Some(Component::Normal(component)), None) if component == path.as_os_str()
     );
     if is_single_normal_component {
@@ -1118,7 +2642,7 @@ fn validate_custom_destination_name(destination_name: &str) -> io::Result<()> {
     ))
 }
 
-fn validate_move_plan(move_plan: &[PlannedMove<'_>]) -> io::Result<()> {
+fn validate_move_plan(move_plan: &[PlannedMove<'_>], allow_cycles: bool) -> io::Result<()> {
     let mut source_indices = HashMap::new();
     let mut destination_indices = HashMap::new();
     let mut source_identities: HashMap<ExistingPathIdentity, Vec<usize>> = HashMap::new();
@@ -1145,8 +2669,16 @@ fn validate_move_plan(move_plan: &[PlannedMove<'_>]) -> io::Result<()> {
         }
     }
 
+    let cycles = if allow_cycles {
+        move_cycles(move_plan)
+    } else {
+        vec![false; move_plan.len()]
+    };
     let mut destination_identities = HashMap::new();
     for (destination_index, planned_move) in move_plan.iter().enumerate() {
+        if cycles[destination_index] {
+            continue;
+        }
         if let Some(source_index) = source_indices.get(planned_move.destination.as_path())
             && *source_index != destination_index
         {
@@ -1218,7 +2750,16 @@ fn move_path(
             Ok(())
         }
         Err(error) if is_cross_device_error(&error) => {
+            let skipped = progress.items_skipped;
             copy_path(source, destination, progress)?;
+            // The copy lacks what was skipped, so the source has to stay.
+            if progress.items_skipped > skipped {
+                progress.warn(format!(
+                    "{}: kept, since items of it were skipped",
+                    source.to_string_lossy()
+                ));
+                return Ok(());
+            }
             remove_path(source)?;
             Ok(())
         }
@@ -1308,6 +2849,24 @@ fn existing_path_identity(path: &Path) -> io::Result<Option<ExistingPathIdentity
     }
 }
 
+/// Serializable form of [`existing_path_identity`], kept by the undo journal.
+pub(crate) fn path_identity_token(path: &Path) -> io::Result<Option<String>> {
+    #[cfg(unix)]
+    {
+        Ok(existing_path_identity(path)?.map(|(dev, ino)| format!("{dev}:{ino}")))
+    }
+
+    #[cfg(windows)]
+    {
+        existing_path_identity(path)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        Ok(existing_path_identity(path)?.map(|identity| identity.to_string_lossy().into_owned()))
+    }
+}
+
 fn existing_paths_refer_to_same_entry(first: &Path, second: &Path) -> io::Result<bool> {
     if first == second {
         return Ok(true);
@@ -1317,446 +2876,2030 @@ fn existing_paths_refer_to_same_entry(first: &Path, second: &Path) -> io::Result
         .is_some_and(|(first, second)| first == second))
 }
 
-fn delete_paths(targets: &[PathBuf], progress: &mut ProgressTracker<'_>) -> io::Result<()> {
-    for target in targets {
-        progress.ensure_not_canceled()?;
-        delete_path(target, progress)?;
-    }
-    Ok(())
-}
-
-fn copy_path(
-    source: &Path,
-    destination: &Path,
+/// Creates hard links (`symlink_relative == None`) or absolute/relative
+/// symlinks for each source, resolving conflicts like copy does.
+fn link_paths(
+    sources: &[PathBuf],
+    destination_dir: &Path,
+    symlink_relative: Option<bool>,
+    overwrite: OverwritePolicy,
     progress: &mut ProgressTracker<'_>,
 ) -> io::Result<()> {
-    progress.ensure_not_canceled()?;
-    let metadata = fs::symlink_metadata(source)?;
-    progress.set_current_path(source);
-    if metadata.file_type().is_symlink() {
-        if let Some(parent) = destination.parent() {
-            fs::create_dir_all(parent)?;
+    let link_totals = JobTotals { items: 1, bytes: 0 };
+    for source in sources {
+        progress.ensure_not_canceled()?;
+        progress.set_current_path(source);
+        let metadata = fs::symlink_metadata(source)?;
+        if symlink_relative.is_none() && metadata.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cannot hard-link a directory: {}", source.to_string_lossy()),
+            ));
         }
-        copy_symlink(source, destination)?;
-        preserve_copied_metadata(destination, &metadata)?;
-        progress.complete_item(source);
-        return Ok(());
-    }
-
-    if metadata.is_dir() {
-        validate_directory_destination_not_inside_source(source, destination, "copy")?;
-
-        if destination.exists() {
+        let destination = destination_path(source, destination_dir)?;
+        let Some(destination) =
+            resolve_destination(source, destination, overwrite, link_totals, progress)?
+        else {
+            progress.complete_source(source);
+            continue;
+        };
+        if destination.append {
             return Err(io::Error::new(
-                io::ErrorKind::AlreadyExists,
+                io::ErrorKind::Unsupported,
                 format!(
-                    "destination already exists: {}",
-                    destination.to_string_lossy()
+                    "cannot append to an existing link target: {}",
+                    destination.path.to_string_lossy()
                 ),
             ));
         }
+        let link_result = match symlink_relative {
+            None => fs::hard_link(source, &destination.path),
+            Some(relative) => {
+                let target = if relative {
+                    relative_link_target(source, &destination.path)
+                } else {
+                    source.clone()
+                };
+                create_symlink(&target, &destination.path)
+            }
+        };
+        let link_path = destination.path.clone();
+        destination.finish(link_result)?;
+        progress.complete_item(&link_path);
+        progress.complete_source(source);
+    }
+    Ok(())
+}
 
-        fs::create_dir_all(destination)?;
-        for entry in fs::read_dir(source)? {
-            let entry = entry?;
-            let child_source = entry.path();
-            let child_destination = destination.join(entry.file_name());
-            copy_path(&child_source, &child_destination, progress)?;
+/// Target for a symlink at `link` pointing to `source`, relative to the
+/// directory holding the link, so the pair can be moved together.
+///
+/// Both parent directories are canonicalized when possible so that panels
+/// opened through symlinked directories still produce working links; the
+/// final components are kept as-is because `source` may itself be a link.
+fn relative_link_target(source: &Path, link: &Path) -> PathBuf {
+    let canonical_parent = |path: &Path| -> PathBuf {
+        match (path.parent(), path.file_name()) {
+            (Some(parent), Some(name)) => fs::canonicalize(parent)
+                .unwrap_or_else(|_| parent.to_path_buf())
+                .join(name),
+            _ => path.to_path_buf(),
         }
-        fs::set_permissions(destination, metadata.permissions())?;
-        preserve_copied_metadata(destination, &metadata)?;
-        progress.complete_item(source);
-        return Ok(());
+    };
+    let source = canonical_parent(source);
+    let link = canonical_parent(link);
+    let link_dir = link.parent().unwrap_or(Path::new(""));
+    relative_path(link_dir, &source)
+}
+
+/// Lexical path from directory `from` to `to`; both should be absolute.
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from_components: Vec<Component<'_>> = from.components().collect();
+    let to_components: Vec<Component<'_>> = to.components().collect();
+    let common = from_components
+        .iter()
+        .zip(&to_components)
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from_components.len() {
+        relative.push("..");
     }
+    for component in &to_components[common..] {
+        relative.push(component.as_os_str());
+    }
+    if relative.as_os_str().is_empty() {
+        relative.push(".");
+    }
+    relative
+}
 
-    if destination.exists() {
+/// Replaces `link` with a symlink to `target` without a window where the
+/// link is missing: the new link is created under a staging name next to it
+/// and renamed over the old one.
+fn retarget_symlink(link: &Path, target: &Path) -> io::Result<()> {
+    if !fs::symlink_metadata(link)?.file_type().is_symlink() {
         return Err(io::Error::new(
-            io::ErrorKind::AlreadyExists,
-            format!(
-                "destination already exists: {}",
-                destination.to_string_lossy()
-            ),
+            io::ErrorKind::InvalidInput,
+            format!("not a symlink: {}", link.to_string_lossy()),
         ));
     }
+    let staging = destination_staging_path(link);
+    create_symlink(target, &staging)?;
+    if let Err(error) = fs::rename(&staging, link) {
+        let _ = fs::remove_file(&staging);
+        return Err(error);
+    }
+    Ok(())
+}
 
-    if let Some(parent) = destination.parent() {
-        fs::create_dir_all(parent)?;
+#[cfg(unix)]
+fn create_symlink(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(not(unix))]
+fn create_symlink(_target: &Path, link: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "symlinks are not supported on this platform: {}",
+            link.to_string_lossy()
+        ),
+    ))
+}
+
+fn delete_paths(targets: &[PathBuf], progress: &mut ProgressTracker<'_>) -> io::Result<()> {
+    for target in targets {
+        progress.ensure_not_canceled()?;
+        progress.attempt_item(target, |progress| delete_path(target, progress))?;
+        progress.complete_source(target);
     }
-    copy_file(source, destination, progress)?;
-    fs::set_permissions(destination, metadata.permissions())?;
-    preserve_copied_metadata(destination, &metadata)?;
-    progress.complete_item(source);
     Ok(())
 }
 
-fn copy_file(
-    source: &Path,
-    destination: &Path,
-    progress: &mut ProgressTracker<'_>,
-) -> io::Result<()> {
-    let staged_destination = destination_staging_path(destination);
-    let write_result = (|| -> io::Result<()> {
-        let mut source_file = fs::File::open(source)?;
-        let mut destination_file = fs::OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(&staged_destination)?;
-        let mut buffer = [0_u8; COPY_BUFFER_SIZE];
+fn trash_paths(targets: &[PathBuf], progress: &mut ProgressTracker<'_>) -> io::Result<()> {
+    let trash = Trash::from_env()?;
+    for target in targets {
+        progress.ensure_not_canceled()?;
+        progress.set_current_path(target);
+        let trashed = trash.trash(target)?;
+        progress.complete_item(target);
+        progress.record_undo(UndoOperation::Trash, target, &trashed);
+        progress.complete_source(target);
+    }
+    Ok(())
+}
 
-        loop {
-            progress.ensure_not_canceled()?;
-            let bytes_read = source_file.read(&mut buffer)?;
-            if bytes_read == 0 {
-                break;
-            }
-            destination_file.write_all(&buffer[..bytes_read])?;
-            progress.advance_bytes(bytes_read as u64);
+fn restore_trashed_items(items: &[PathBuf], progress: &mut ProgressTracker<'_>) -> io::Result<()> {
+    for info_path in items {
+        progress.ensure_not_canceled()?;
+        progress.set_current_path(info_path);
+        let restored = trash::restore(info_path)?;
+        progress.complete_item(&restored);
+        progress.complete_source(info_path);
+    }
+    Ok(())
+}
+
+fn purge_trashed_items(items: &[PathBuf], progress: &mut ProgressTracker<'_>) -> io::Result<()> {
+    for info_path in items {
+        progress.ensure_not_canceled()?;
+        let item = TrashedItem::load(info_path)?;
+        let skipped = progress.items_skipped;
+        match fs::symlink_metadata(&item.files_path) {
+            Ok(_) => delete_path(&item.files_path, progress)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error),
         }
-        destination_file.flush()?;
-        Ok(())
-    })();
-    if let Err(error) = write_result {
-        let _ = fs::remove_file(&staged_destination);
-        return Err(error);
+        // Keep the item listed while some of it is still in the trash.
+        if progress.items_skipped > skipped {
+            continue;
+        }
+        fs::remove_file(info_path)?;
+        progress.complete_source(info_path);
     }
+    Ok(())
+}
 
-    if let Err(error) = fs::rename(&staged_destination, destination) {
-        let _ = fs::remove_file(&staged_destination);
-        return Err(error);
+fn permission_masks_label(file_mask: PermissionMask, dir_mask: PermissionMask) -> String {
+    if file_mask == dir_mask {
+        file_mask.label()
+    } else {
+        format!("files {} | dirs {}", file_mask.label(), dir_mask.label())
     }
+}
 
-    Ok(())
+/// Ownership and permission edits shared by chmod and chown jobs.
+#[derive(Clone, Copy, Debug)]
+struct AttributeChange {
+    owner: Option<u32>,
+    group: Option<u32>,
+    file_mask: PermissionMask,
+    dir_mask: PermissionMask,
+    recursive: bool,
 }
 
-fn destination_staging_path(destination: &Path) -> PathBuf {
-    let parent = destination.parent().unwrap_or(Path::new("."));
-    let file_name = destination
-        .file_name()
-        .and_then(|name| name.to_str())
-        .unwrap_or("item");
+impl AttributeChange {
+    fn changes_ownership(self) -> bool {
+        self.owner.is_some() || self.group.is_some()
+    }
+}
 
-    for index in 1_usize.. {
-        let candidate = parent.join(format!(".{file_name}.rc-stage-{index}"));
-        if !candidate.exists() {
-            return candidate;
+/// Entries whose attributes could not be changed because of EPERM/EACCES.
+///
+/// These are collected instead of aborting the batch, so one root-owned file
+/// in a tree does not stop the remaining entries from being updated.
+#[derive(Debug, Default)]
+struct DeniedEntries {
+    entries: Vec<(PathBuf, io::Error)>,
+}
+
+impl DeniedEntries {
+    const REPORTED_LIMIT: usize = 10;
+
+    /// Returns whether `result` succeeded; permission errors are recorded once
+    /// per path and other errors are propagated.
+    fn record(&mut self, path: &Path, result: io::Result<()>) -> io::Result<bool> {
+        match result {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::PermissionDenied => {
+                if self
+                    .entries
+                    .last()
+                    .is_none_or(|(last_path, _)| last_path != path)
+                {
+                    self.entries.push((path.to_path_buf(), error));
+                }
+                Ok(false)
+            }
+            Err(error) => Err(error),
         }
     }
 
-    unreachable!("staging candidate generator should always return");
+    fn into_result(self) -> io::Result<()> {
+        if self.entries.is_empty() {
+            return Ok(());
+        }
+        let mut details: Vec<String> = self
+            .entries
+            .iter()
+            .take(Self::REPORTED_LIMIT)
+            .map(|(path, error)| format!("{}: {error}", path.to_string_lossy()))
+            .collect();
+        if self.entries.len() > Self::REPORTED_LIMIT {
+            details.push(format!(
+                "and {} more",
+                self.entries.len() - Self::REPORTED_LIMIT
+            ));
+        }
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "{} entr{} not changed: {}",
+                self.entries.len(),
+                if self.entries.len() == 1 { "y" } else { "ies" },
+                details.join("; ")
+            ),
+        ))
+    }
 }
 
-fn delete_path(path: &Path, progress: &mut ProgressTracker<'_>) -> io::Result<()> {
+fn change_attributes(
+    targets: &[PathBuf],
+    change: AttributeChange,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    let mut denied = DeniedEntries::default();
+    for target in targets {
+        progress.ensure_not_canceled()?;
+        change_path_attributes(target, change, &mut denied, progress)?;
+    }
+    denied.into_result()
+}
+
+fn change_path_attributes(
+    path: &Path,
+    change: AttributeChange,
+    denied: &mut DeniedEntries,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
     progress.ensure_not_canceled()?;
     let metadata = fs::symlink_metadata(path)?;
     progress.set_current_path(path);
 
+    // Link permissions are not used on unix, and both chmod(2) and chown(2)
+    // would follow the link and change whatever it points at, possibly outside
+    // the selected tree.
+    if metadata.file_type().is_symlink() {
+        progress.complete_item(path);
+        return Ok(());
+    }
+
+    let mut mode = permission_bits(&metadata);
+    if change.changes_ownership()
+        && denied.record(path, set_ownership(path, change.owner, change.group))?
+    {
+        // The kernel may drop setuid/setgid when ownership changes.
+        mode = permission_bits(&fs::symlink_metadata(path)?);
+    }
+
     if metadata.is_dir() {
-        for entry in fs::read_dir(path)? {
-            let entry = entry?;
-            delete_path(&entry.path(), progress)?;
+        let final_mode = change.dir_mask.apply(mode);
+        if change.recursive {
+            // Grant newly requested access before descending so that adding r/x
+            // to an unreadable directory still reaches its children; removals are
+            // applied once the children are done.
+            let traversal_mode = mode | change.dir_mask.set;
+            if traversal_mode != mode
+                && denied.record(path, set_permission_bits(path, traversal_mode))?
+            {
+                mode = traversal_mode;
+            }
+            for entry in fs::read_dir(path)? {
+                let entry = entry?;
+                change_path_attributes(&entry.path(), change, denied, progress)?;
+            }
+        }
+        if final_mode != mode {
+            denied.record(path, set_permission_bits(path, final_mode))?;
         }
-        fs::remove_dir(path)?;
         progress.complete_item(path);
         return Ok(());
     }
 
-    let bytes = if metadata.is_file() {
-        metadata.len()
-    } else {
-        0
-    };
-    fs::remove_file(path)?;
-    if bytes > 0 {
-        progress.advance_bytes(bytes);
+    let final_mode = change.file_mask.apply(mode);
+    if final_mode != mode {
+        denied.record(path, set_permission_bits(path, final_mode))?;
     }
     progress.complete_item(path);
     Ok(())
 }
 
-fn destination_path(source: &Path, destination_dir: &Path) -> io::Result<PathBuf> {
-    let Some(name) = source.file_name() else {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!("source has no file name: {}", source.to_string_lossy()),
-        ));
-    };
-    Ok(destination_dir.join(name))
+#[cfg(unix)]
+fn permission_bits(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+
+    metadata.permissions().mode() & PermissionMask::BITS
 }
 
-fn resolve_destination(
-    source: &Path,
-    mut destination: PathBuf,
-    overwrite: OverwritePolicy,
-    source_totals: JobTotals,
-    progress: &mut ProgressTracker<'_>,
-) -> io::Result<Option<ResolvedDestination>> {
-    if source == destination {
-        match overwrite {
-            OverwritePolicy::Rename => {
-                destination = renamed_destination(&destination);
-            }
-            OverwritePolicy::Overwrite | OverwritePolicy::Skip => {
-                progress.advance_totals(source, source_totals);
-                return Ok(None);
-            }
-        }
-    }
-
-    if destination.exists() {
-        match overwrite {
-            OverwritePolicy::Overwrite => {
-                let backup = destination_backup_path(&destination);
-                fs::rename(&destination, &backup)?;
-                return Ok(Some(ResolvedDestination {
-                    path: destination,
-                    overwrite_backup: Some(backup),
-                }));
-            }
-            OverwritePolicy::Skip => {
-                progress.advance_totals(source, source_totals);
-                return Ok(None);
-            }
-            OverwritePolicy::Rename => {
-                destination = renamed_destination(&destination);
-            }
-        }
+#[cfg(not(unix))]
+fn permission_bits(metadata: &fs::Metadata) -> u32 {
+    if metadata.permissions().readonly() {
+        0o555
+    } else {
+        0o755
     }
-
-    Ok(Some(ResolvedDestination {
-        path: destination,
-        overwrite_backup: None,
-    }))
 }
 
-fn renamed_destination(destination: &Path) -> PathBuf {
-    let parent = destination.parent().unwrap_or(Path::new("."));
-    let file_name = destination
-        .file_name()
-        .map(|name| name.to_string_lossy().into_owned())
-        .unwrap_or_else(|| String::from("item"));
-
-    for index in 1_usize.. {
-        let suffix = if index == 1 {
-            String::from("copy")
-        } else {
-            format!("copy{index}")
-        };
-        let candidate = parent.join(format!("{file_name}.{suffix}"));
-        if !candidate.exists() {
-            return candidate;
-        }
-    }
+#[cfg(unix)]
+fn set_permission_bits(path: &Path, mode: u32) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
 
-    unreachable!("rename candidate generator should always return");
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
 }
 
-fn validate_move_destination(source: &Path, destination: &Path) -> io::Result<()> {
-    let metadata = fs::symlink_metadata(source)?;
-    if metadata.is_dir() {
-        validate_directory_destination_not_inside_source(source, destination, "move")?;
-    }
-    Ok(())
+#[cfg(not(unix))]
+fn set_permission_bits(path: &Path, _mode: u32) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "changing permission bits is not supported on this platform: {}",
+            path.to_string_lossy()
+        ),
+    ))
 }
 
-fn validate_directory_destination_not_inside_source(
+fn copy_path(
     source: &Path,
     destination: &Path,
-    operation: &str,
+    progress: &mut ProgressTracker<'_>,
 ) -> io::Result<()> {
-    if destination_parent_is_inside_source(source, destination)? {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!(
-                "cannot {operation} directory into itself: {} -> {}",
-                source.to_string_lossy(),
-                destination.to_string_lossy()
-            ),
-        ));
+    // A failed copy may have left directories on the stack.
+    progress.copy_ancestors.clear();
+    let metadata = progress.copy_source_metadata(source)?;
+    if progress.copy_options.workers > 1 && metadata.is_dir() {
+        return copy_tree_parallel(source, destination, metadata, progress);
     }
-    Ok(())
+    copy_path_serial(source, destination, metadata, progress)
 }
 
-fn destination_parent_is_inside_source(source: &Path, destination: &Path) -> io::Result<bool> {
-    let source = fs::canonicalize(source)?;
-    let destination_parent = destination.parent().unwrap_or_else(|| Path::new("."));
-    let destination_parent = canonicalize_allowing_missing_tail(destination_parent)?;
-    Ok(destination_parent == source || destination_parent.starts_with(&source))
-}
-
-fn canonicalize_allowing_missing_tail(path: &Path) -> io::Result<PathBuf> {
-    for ancestor in path.ancestors() {
-        let candidate = if ancestor.as_os_str().is_empty() {
-            Path::new(".")
-        } else {
-            ancestor
-        };
-        match fs::canonicalize(candidate) {
-            Ok(mut resolved) => {
-                let tail = path
-                    .strip_prefix(ancestor)
-                    .unwrap_or_else(|_| Path::new(""));
-                push_normalized_tail(&mut resolved, tail);
-                return Ok(resolved);
-            }
-            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
-            Err(error) => return Err(error),
+/// Copies `source`, whose metadata is `metadata` as
+/// [`ProgressTracker::copy_source_metadata`] gave it.
+fn copy_path_serial(
+    source: &Path,
+    destination: &Path,
+    metadata: fs::Metadata,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    progress.ensure_not_canceled()?;
+    progress.set_current_path(source);
+    if metadata.file_type().is_symlink() {
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
         }
+        copy_symlink(source, destination)?;
+        preserve_copied_metadata(source, destination, &metadata, progress)?;
+        progress.complete_item(source);
+        return Ok(());
     }
 
-    fs::canonicalize(path)
-}
+    if metadata.is_dir() {
+        validate_directory_destination_not_inside_source(source, destination, "copy")?;
 
-fn push_normalized_tail(base: &mut PathBuf, tail: &Path) {
-    for component in tail.components() {
-        match component {
-            Component::CurDir => {}
-            Component::ParentDir => {
-                base.pop();
-            }
-            Component::Normal(part) => base.push(part),
-            Component::Prefix(_) | Component::RootDir => base.push(component.as_os_str()),
+        if destination.exists() {
+            return Err(destination_exists_error(destination));
         }
-    }
-}
 
-fn copy_symlink(source: &Path, destination: &Path) -> io::Result<()> {
-    let target = fs::read_link(source)?;
-    #[cfg(unix)]
-    {
-        std::os::unix::fs::symlink(&target, destination)
-    }
-    #[cfg(windows)]
-    {
-        let parent = source.parent().unwrap_or(Path::new("."));
-        let resolved_target = if target.is_absolute() {
-            target.clone()
-        } else {
-            parent.join(&target)
-        };
-        let is_dir_target = fs::metadata(&resolved_target)
-            .map(|metadata| metadata.is_dir())
-            .unwrap_or(false);
-        if is_dir_target {
-            std::os::windows::fs::symlink_dir(&target, destination)
-        } else {
-            std::os::windows::fs::symlink_file(&target, destination)
+        // Listed before anything is created, so a failed directory leaves
+        // nothing behind to retry over.
+        let entries = fs::read_dir(source)?.collect::<io::Result<Vec<_>>>()?;
+        fs::create_dir_all(destination)?;
+        let entered = progress.enter_copy_directory(source, &metadata);
+        let ancestors = progress.copy_ancestors.len();
+        for entry in entries {
+            let child_source = entry.path();
+            let child_destination = destination.join(entry.file_name());
+            attempt_fresh_copy(&child_source, &child_destination, progress, |progress| {
+                progress.copy_ancestors.truncate(ancestors);
+                let child_metadata = progress.copy_source_metadata(&child_source)?;
+                copy_path_serial(&child_source, &child_destination, child_metadata, progress)
+            })?;
+            progress.copy_ancestors.truncate(ancestors);
         }
-    }
-    #[cfg(not(any(unix, windows)))]
-    {
-        let _ = target;
-        let _ = destination;
-        Err(io::Error::new(
-            io::ErrorKind::Unsupported,
-            "symlink copy is not supported on this platform",
-        ))
-    }
-}
-
-fn preserve_copied_metadata(destination: &Path, metadata: &fs::Metadata) -> io::Result<()> {
-    let atime = FileTime::from_last_access_time(metadata);
-    let mtime = FileTime::from_last_modification_time(metadata);
-
-    if metadata.file_type().is_symlink() {
-        filetime::set_symlink_file_times(destination, atime, mtime)?;
+        progress.leave_copy_directory(entered);
+        fs::set_permissions(destination, metadata.permissions())?;
+        preserve_copied_metadata(source, destination, &metadata, progress)?;
+        progress.complete_item(source);
         return Ok(());
     }
 
-    filetime::set_file_times(destination, atime, mtime)?;
-    preserve_owner_best_effort(destination, metadata)?;
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if !progress.link_to_earlier_copy(&metadata, destination)? {
+        copy_file_with_metadata(source, destination, &metadata, progress)?;
+        progress.note_copied_hard_link(&metadata, destination);
+    }
+    progress.complete_item(source);
     Ok(())
 }
 
+/// Tells files hard-linked to each other apart from other files: regular
+/// files with more than one link share it.
 #[cfg(unix)]
-fn preserve_owner_best_effort(destination: &Path, metadata: &fs::Metadata) -> io::Result<()> {
-    use std::os::unix::fs::MetadataExt;
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct HardLinkIdentity {
+    device: u64,
+    inode: u64,
+}
 
-    let uid = Uid::from_raw(metadata.uid());
-    let gid = Gid::from_raw(metadata.gid());
-    match chown(destination, Some(uid), Some(gid)) {
-        Ok(()) => Ok(()),
-        Err(Errno::EPERM) | Err(Errno::EACCES) => Ok(()),
-        Err(error) => Err(io::Error::other(format!(
-            "failed to preserve owner/group for {}: {error}",
-            destination.to_string_lossy()
-        ))),
-    }
+#[cfg(unix)]
+fn hard_link_identity(metadata: &fs::Metadata) -> Option<HardLinkIdentity> {
+    use std::os::unix::fs::MetadataExt as _;
+
+    (metadata.is_file() && metadata.nlink() > 1).then(|| HardLinkIdentity {
+        device: metadata.dev(),
+        inode: metadata.ino(),
+    })
 }
 
 #[cfg(not(unix))]
-fn preserve_owner_best_effort(_destination: &Path, _metadata: &fs::Metadata) -> io::Result<()> {
-    Ok(())
+type HardLinkIdentity = ();
+
+/// Link counts are not known here, so every file is copied on its own.
+#[cfg(not(unix))]
+fn hard_link_identity(_metadata: &fs::Metadata) -> Option<HardLinkIdentity> {
+    None
 }
 
-fn remove_path(path: &Path) -> io::Result<()> {
-    let metadata = fs::symlink_metadata(path)?;
-    if metadata.is_dir() {
-        fs::remove_dir_all(path)
-    } else {
-        fs::remove_file(path)
+/// Copies a non-directory that is not a symlink, then its permissions and
+/// the metadata of the job's preservation level.
+fn copy_file_with_metadata(
+    source: &Path,
+    destination: &Path,
+    metadata: &fs::Metadata,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    if destination.exists() {
+        return Err(destination_exists_error(destination));
     }
+    copy_file(source, destination, progress)?;
+    fs::set_permissions(destination, metadata.permissions())?;
+    preserve_copied_metadata(source, destination, metadata, progress)
 }
 
-#[derive(Debug)]
-struct ResolvedDestination {
-    path: PathBuf,
-    overwrite_backup: Option<PathBuf>,
+/// Copies `source` to `destination` with `copy` under the job's error
+/// policy. A retry first removes what the failed attempt left at the
+/// destination, unless something was there before the copy.
+fn attempt_fresh_copy(
+    source: &Path,
+    destination: &Path,
+    progress: &mut ProgressTracker<'_>,
+    mut copy: impl FnMut(&mut ProgressTracker<'_>) -> io::Result<()>,
+) -> io::Result<bool> {
+    let existed = fs::symlink_metadata(destination).is_ok();
+    let mut retrying = false;
+    progress.attempt_item(source, |progress| {
+        if retrying && !existed {
+            remove_copy_leftover(destination)?;
+        }
+        retrying = true;
+        copy(progress)
+    })
 }
 
-impl ResolvedDestination {
-    fn finish(self, operation_result: io::Result<()>) -> io::Result<()> {
-        let Some(backup) = self.overwrite_backup else {
-            return operation_result;
-        };
-
-        match operation_result {
-            Ok(()) => remove_path(&backup),
-            Err(operation_error) => {
-                if self.path.exists() {
-                    remove_path(&self.path)?;
-                }
-                fs::rename(&backup, &self.path)?;
-                Err(operation_error)
-            }
+/// Completes the copy of `source` an interrupted run left at `destination`.
+/// Directories are merged, regular files whose size and modification time
+/// match the source are kept, and anything else is copied again.
+fn merge_copy(
+    source: &Path,
+    destination: &Path,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    progress.ensure_not_canceled()?;
+    let metadata = progress.copy_source_metadata(source)?;
+    let Ok(existing) = fs::symlink_metadata(destination) else {
+        return copy_path_serial(source, destination, metadata, progress);
+    };
+    if metadata.is_dir() && existing.is_dir() {
+        progress.set_current_path(source);
+        let entries = fs::read_dir(source)?.collect::<io::Result<Vec<_>>>()?;
+        let entered = progress.enter_copy_directory(source, &metadata);
+        let ancestors = progress.copy_ancestors.len();
+        for entry in entries {
+            let child_source = entry.path();
+            let child_destination = destination.join(entry.file_name());
+            progress.attempt_item(&child_source, |progress| {
+                progress.copy_ancestors.truncate(ancestors);
+                merge_copy(&child_source, &child_destination, progress)
+            })?;
+            progress.copy_ancestors.truncate(ancestors);
         }
+        progress.leave_copy_directory(entered);
+        fs::set_permissions(destination, metadata.permissions())?;
+        preserve_copied_metadata(source, destination, &metadata, progress)?;
+        progress.complete_item(source);
+        return Ok(());
+    }
+    let copied = metadata.is_file()
+        && existing.is_file()
+        && existing.len() == metadata.len()
+        && FileTime::from_last_modification_time(&existing)
+            == FileTime::from_last_modification_time(&metadata);
+    if copied {
+        let totals = JobTotals {
+            items: 1,
+            bytes: metadata.len(),
+        };
+        progress.advance_totals(source, totals);
+        return Ok(());
     }
+    remove_path(destination)?;
+    copy_path_serial(source, destination, metadata, progress)
 }
 
-fn destination_backup_path(destination: &Path) -> PathBuf {
-    let parent = destination.parent().unwrap_or(Path::new("."));
-    let file_name = destination
-        .file_name()
-        .and_then(|name| name.to_str())
-        .unwrap_or("item");
-
-    for index in 1_usize.. {
-        let candidate = parent.join(format!(".{file_name}.rc-backup-{index}"));
-        if !candidate.exists() {
-            return candidate;
-        }
+fn remove_copy_leftover(destination: &Path) -> io::Result<()> {
+    match remove_path(destination) {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
     }
-
-    unreachable!("backup candidate generator should always return");
 }
 
-fn is_cross_device_error(error: &io::Error) -> bool {
-    error.kind() == io::ErrorKind::CrossesDevices || error.raw_os_error() == Some(18)
+fn destination_exists_error(destination: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!(
+            "destination already exists: {}",
+            destination.to_string_lossy()
+        ),
+    )
 }
 
-fn canceled_error() -> io::Error {
-    io::Error::new(io::ErrorKind::Interrupted, JOB_CANCELED_MESSAGE)
-}
+/// Copies a directory tree with [`CopyOptions::workers`] threads.
+///
+/// The calling thread walks the tree in the same order as a serial copy,
+/// creates directories and copies symlinks, special files and large files
+/// itself, and queues small files for the workers. Directory permissions and
+/// times are applied once every file is in place, children before parents.
+/// When several entries fail, the error of the first one in walk order is
+/// returned, as a serial copy would have stopped there.
+fn copy_tree_parallel(
+    source: &Path,
+    destination: &Path,
+    metadata: fs::Metadata,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    let workers = progress.copy_options.workers;
+    let context = progress.copy_worker_context();
+    let (task_tx, task_rx) =
+        mpsc::sync_channel(workers.saturating_mul(PARALLEL_COPY_QUEUE_PER_WORKER));
+    let (update_tx, update_rx) = mpsc::channel();
+    let task_rx = Mutex::new(task_rx);
+    let stop = AtomicBool::new(false);
+    let mut pipeline = CopyPipeline {
+        task_tx: Some(task_tx),
+        updates: update_rx,
+        stop: &stop,
+        next_index: 0,
+        first_error: None,
+        directories: Vec::new(),
+    };
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let (task_rx, update_tx, context, stop) =
+                (&task_rx, update_tx.clone(), context.clone(), &stop);
+            scope.spawn(move || run_copy_worker(task_rx, update_tx, context, stop));
+        }
+        drop(update_tx);
+        pipeline.copy_entry(source, destination, Some(metadata), progress);
+        pipeline.finish(progress);
+    });
+
+    progress.ensure_not_canceled()?;
+    if let Some((_, error)) = pipeline.first_error {
+        return Err(error);
+    }
+    for (source, destination, metadata) in pipeline.directories.iter().rev() {
+        fs::set_permissions(destination, metadata.permissions())?;
+        preserve_copied_metadata(source, destination, metadata, progress)?;
+        progress.complete_item(source);
+    }
+    Ok(())
+}
+
+struct FileCopyTask {
+    index: u64,
+    source: PathBuf,
+    destination: PathBuf,
+    metadata: fs::Metadata,
+}
+
+enum CopyWorkerUpdate {
+    Copied {
+        task: Box<FileCopyTask>,
+        bytes: u64,
+        bytes_resumed: u64,
+        strategies: CopyStrategies,
+        result: io::Result<()>,
+    },
+    Warning(String),
+}
+
+/// What a copy worker needs to build its own [`ProgressTracker`].
+#[derive(Clone)]
+struct CopyWorkerContext {
+    job_id: JobId,
+    cancel_flag: Arc<AtomicBool>,
+    pause_flag: Option<Arc<AtomicBool>>,
+    copy_options: CopyOptions,
+    verify: bool,
+    preserve: PreserveLevel,
+    throttles: Vec<Arc<Throttle>>,
+}
+
+fn run_copy_worker(
+    tasks: &Mutex<Receiver<FileCopyTask>>,
+    updates: Sender<CopyWorkerUpdate>,
+    context: CopyWorkerContext,
+    stop: &AtomicBool,
+) {
+    let (event_tx, event_rx) = mpsc::channel();
+    let mut progress = context.tracker(&event_tx);
+    let (mut reported_bytes, mut reported_resumed) = (0, 0);
+    loop {
+        let task = tasks.lock().unwrap_or_else(PoisonError::into_inner).recv();
+        let Ok(task) = task else {
+            return;
+        };
+        // After a failure the queue is only drained.
+        if stop.load(Ordering::Relaxed) {
+            continue;
+        }
+        let result = progress.ensure_not_canceled().and_then(|()| {
+            copy_file_with_metadata(
+                &task.source,
+                &task.destination,
+                &task.metadata,
+                &mut progress,
+            )
+        });
+        // The worker's own progress events are replaced by the deltas below.
+        for event in event_rx.try_iter() {
+            if let JobEvent::Warning { message, .. } = event {
+                let _ = updates.send(CopyWorkerUpdate::Warning(message));
+            }
+        }
+        let done = &progress.progress;
+        let update = CopyWorkerUpdate::Copied {
+            task: Box::new(task),
+            bytes: done.bytes_done - reported_bytes,
+            bytes_resumed: done.bytes_resumed - reported_resumed,
+            strategies: done.copy_strategies,
+            result,
+        };
+        (reported_bytes, reported_resumed) = (done.bytes_done, done.bytes_resumed);
+        if updates.send(update).is_err() {
+            return;
+        }
+    }
+}
+
+/// The walking side of [`copy_tree_parallel`].
+struct CopyPipeline<'s> {
+    task_tx: Option<SyncSender<FileCopyTask>>,
+    updates: Receiver<CopyWorkerUpdate>,
+    stop: &'s AtomicBool,
+    /// Position of the next entry in walk order.
+    next_index: u64,
+    first_error: Option<(u64, io::Error)>,
+    /// Created directories in walk order, for their metadata at the end.
+    directories: Vec<(PathBuf, PathBuf, fs::Metadata)>,
+}
+
+impl CopyPipeline<'_> {
+    /// Copies `source`; `metadata` is passed when the caller already
+    /// followed the link, if any.
+    fn copy_entry(
+        &mut self,
+        source: &Path,
+        destination: &Path,
+        metadata: Option<fs::Metadata>,
+        progress: &mut ProgressTracker<'_>,
+    ) {
+        let mut metadata = metadata;
+        let result = attempt_fresh_copy(source, destination, progress, |progress| {
+            // A retry reads the metadata again.
+            self.try_copy_entry(source, destination, metadata.take(), progress)
+        });
+        if let Err(error) = result {
+            let index = self.take_index();
+            self.fail(index, error);
+        }
+    }
+
+    fn try_copy_entry(
+        &mut self,
+        source: &Path,
+        destination: &Path,
+        metadata: Option<fs::Metadata>,
+        progress: &mut ProgressTracker<'_>,
+    ) -> io::Result<()> {
+        if self.is_stopped() {
+            return Ok(());
+        }
+        progress.ensure_not_canceled()?;
+        self.apply_ready_updates(progress);
+        let metadata = match metadata {
+            Some(metadata) => metadata,
+            None => progress.copy_source_metadata(source)?,
+        };
+        if metadata.is_dir() {
+            validate_directory_destination_not_inside_source(source, destination, "copy")?;
+            if destination.exists() {
+                return Err(destination_exists_error(destination));
+            }
+            progress.set_current_path(source);
+            // Nothing after the directory is created can fail, so a retry
+            // never finds it half made.
+            let entries = fs::read_dir(source)?.collect::<io::Result<Vec<_>>>()?;
+            fs::create_dir_all(destination)?;
+            let entered = progress.enter_copy_directory(source, &metadata);
+            self.directories
+                .push((source.to_path_buf(), destination.to_path_buf(), metadata));
+            for entry in entries {
+                if self.is_stopped() {
+                    break;
+                }
+                self.copy_entry(
+                    &entry.path(),
+                    &destination.join(entry.file_name()),
+                    None,
+                    progress,
+                );
+            }
+            progress.leave_copy_directory(entered);
+            return Ok(());
+        }
+        if metadata.is_file()
+            && metadata.len() <= PARALLEL_COPY_MAX_FILE_SIZE
+            && !progress.keeps_hard_link(&metadata)
+        {
+            let task = FileCopyTask {
+                index: self.take_index(),
+                source: source.to_path_buf(),
+                destination: destination.to_path_buf(),
+                metadata,
+            };
+            self.dispatch(task, progress);
+            return Ok(());
+        }
+        copy_path_serial(source, destination, metadata, progress)
+    }
+
+    /// Queues `task`, applying worker updates while the queue is full.
+    fn dispatch(&mut self, mut task: FileCopyTask, progress: &mut ProgressTracker<'_>) {
+        loop {
+            let Some(task_tx) = &self.task_tx else {
+                return;
+            };
+            match task_tx.try_send(task) {
+                Ok(()) => return,
+                Err(TrySendError::Full(returned)) => {
+                    task = returned;
+                    self.wait_for_update(progress);
+                }
+                Err(TrySendError::Disconnected(_)) => return,
+            }
+            if self.is_stopped() {
+                return;
+            }
+        }
+    }
+
+    /// Closes the queue and applies updates until every worker has exited.
+    fn finish(&mut self, progress: &mut ProgressTracker<'_>) {
+        self.task_tx = None;
+        while let Ok(update) = self.updates.recv() {
+            self.apply(update, progress);
+        }
+    }
+
+    fn apply_ready_updates(&mut self, progress: &mut ProgressTracker<'_>) {
+        while let Ok(update) = self.updates.try_recv() {
+            self.apply(update, progress);
+        }
+    }
+
+    fn wait_for_update(&mut self, progress: &mut ProgressTracker<'_>) {
+        match self.updates.recv_timeout(PAUSE_POLL_INTERVAL) {
+            Ok(update) => {
+                self.apply(update, progress);
+                self.apply_ready_updates(progress);
+            }
+            Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {}
+        }
+    }
+
+    fn apply(&mut self, update: CopyWorkerUpdate, progress: &mut ProgressTracker<'_>) {
+        match update {
+            CopyWorkerUpdate::Copied {
+                task,
+                bytes,
+                bytes_resumed,
+                strategies,
+                result,
+            } => {
+                progress.merge_worker_progress(bytes, bytes_resumed, strategies);
+                let FileCopyTask {
+                    index,
+                    source,
+                    destination,
+                    metadata,
+                } = *task;
+                let Err(error) = result else {
+                    progress.complete_item(&source);
+                    return;
+                };
+                // Failed files are asked about and retried by the walker.
+                let existed = error.kind() == io::ErrorKind::AlreadyExists;
+                let copied = progress
+                    .retry_after_error(&source, error)
+                    .and_then(|retry| {
+                        if !retry {
+                            return Ok(false);
+                        }
+                        if !existed {
+                            remove_copy_leftover(&destination)?;
+                        }
+                        attempt_fresh_copy(&source, &destination, progress, |progress| {
+                            copy_file_with_metadata(&source, &destination, &metadata, progress)
+                        })
+                    });
+                match copied {
+                    Ok(true) => progress.complete_item(&source),
+                    Ok(false) => {}
+                    Err(error) => self.fail(index, error),
+                }
+            }
+            CopyWorkerUpdate::Warning(message) => progress.warn(message),
+        }
+    }
+
+    fn take_index(&mut self) -> u64 {
+        let index = self.next_index;
+        self.next_index += 1;
+        index
+    }
+
+    /// Keeps the error of the earliest entry in walk order and stops the walk.
+    fn fail(&mut self, index: u64, error: io::Error) {
+        if self
+            .first_error
+            .as_ref()
+            .is_none_or(|(first, _)| index < *first)
+        {
+            self.first_error = Some((index, error));
+        }
+        self.stop.store(true, Ordering::Relaxed);
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stop.load(Ordering::Relaxed)
+    }
+}
+
+fn copy_file(
+    source: &Path,
+    destination: &Path,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    if progress.copy_options.resume {
+        return copy_file_resumable(source, destination, progress);
+    }
+    let staged_destination = destination_staging_path(destination);
+    let write_result = (|| -> io::Result<()> {
+        let mut source_file = fs::File::open(source)?;
+        let mut destination_file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&staged_destination)?;
+        let mut hasher = progress.verify.then(Sha256::new);
+        copy_stream(
+            &mut source_file,
+            &mut destination_file,
+            0,
+            hasher.as_mut(),
+            progress,
+        )?;
+        drop(destination_file);
+        if let Some(hasher) = hasher {
+            verify_staged_copy(&staged_destination, destination, hasher, progress)?;
+        }
+        Ok(())
+    })();
+    if let Err(error) = write_result {
+        let _ = fs::remove_file(&staged_destination);
+        return Err(error);
+    }
+
+    if let Err(error) = fs::rename(&staged_destination, destination) {
+        let _ = fs::remove_file(&staged_destination);
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Copies through a staging file that outlives cancellation and crashes, and
+/// continues after its verified prefix when one is left from an earlier run.
+fn copy_file_resumable(
+    source: &Path,
+    destination: &Path,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    let staging = ResumeStaging::for_destination(destination);
+    let stamp = ResumeStamp::read(source)?;
+    let offset = staging.resumable_prefix(source, &stamp)?;
+    let mut destination_file = if offset == 0 {
+        staging.restart(&stamp)?;
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&staging.partial)?
+    } else {
+        let mut partial = fs::OpenOptions::new().write(true).open(&staging.partial)?;
+        partial.seek(SeekFrom::Start(offset))?;
+        partial
+    };
+    let mut source_file = fs::File::open(source)?;
+    let mut hasher = progress.verify.then(Sha256::new);
+    match hasher.as_mut() {
+        // The kept prefix still has to be part of the source digest.
+        Some(hasher) => {
+            hash_stream(&mut (&mut source_file).take(offset), hasher, progress)?;
+        }
+        None => {
+            source_file.seek(SeekFrom::Start(offset))?;
+        }
+    }
+    progress.resume_bytes(offset);
+    copy_stream(
+        &mut source_file,
+        &mut destination_file,
+        offset,
+        hasher.as_mut(),
+        progress,
+    )?;
+    drop(destination_file);
+    if let Some(hasher) = hasher
+        && let Err(error) = verify_staged_copy(&staging.partial, destination, hasher, progress)
+    {
+        // A staged file that failed verification must not be resumed.
+        staging.discard();
+        return Err(error);
+    }
+
+    fs::rename(&staging.partial, destination)?;
+    staging.discard();
+    Ok(())
+}
+
+/// Copies the source from `offset`, where both files are positioned, to its
+/// end.
+///
+/// The kernel-assisted paths are tried for regular files with a size. A
+/// verified copy needs every byte in userspace for the digest, and pipes or
+/// files that report no size (such as those under /proc) only return their
+/// contents to plain reads, so these run the buffered loop.
+fn copy_stream(
+    source_file: &mut fs::File,
+    destination_file: &mut fs::File,
+    offset: u64,
+    mut hasher: Option<&mut Sha256>,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    let metadata = source_file.metadata()?;
+    // A capped copy goes through the buffered loop so it can be paced in
+    // small steps.
+    if hasher.is_none() && !progress.is_throttled() && metadata.is_file() && metadata.len() > 0 {
+        let strategy =
+            copy_contents_accelerated(source_file, destination_file, &metadata, offset, progress)?;
+        progress.record_copy_strategy(strategy);
+        return Ok(());
+    }
+    let mut buffer = [0_u8; COPY_BUFFER_SIZE];
+    loop {
+        progress.ensure_not_canceled()?;
+        let bytes_read = source_file.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        destination_file.write_all(&buffer[..bytes_read])?;
+        if let Some(hasher) = hasher.as_deref_mut() {
+            hasher.update(&buffer[..bytes_read]);
+        }
+        progress.advance_bytes(bytes_read as u64);
+        progress.throttle(bytes_read as u64)?;
+    }
+    destination_file.flush()?;
+    if hasher.is_some() {
+        // Verification re-reads the file, which must see everything written.
+        destination_file.sync_all()?;
+    }
+    progress.record_copy_strategy(CopyStrategy::Buffered);
+    Ok(())
+}
+
+/// Tries a reflink, then a hole-preserving copy of sparse sources, then
+/// `copy_file_range`, each falling back to positional buffered copying.
+fn copy_contents_accelerated(
+    source_file: &fs::File,
+    destination_file: &fs::File,
+    metadata: &fs::Metadata,
+    offset: u64,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<CopyStrategy> {
+    progress.ensure_not_canceled()?;
+    let length = metadata.len();
+    if offset == 0 && length > 0 && copy_accel::reflink(source_file, destination_file) {
+        progress.advance_bytes(length);
+        return Ok(CopyStrategy::Reflink);
+    }
+    if copy_accel::is_sparse(metadata)
+        && let Some(segments) = copy_accel::data_segments(source_file, offset, length)?
+    {
+        let mut position = offset;
+        for (start, end) in segments {
+            // Holes count as copied, since totals use the apparent size, but
+            // nothing is sent for them, so the caps do not pace them.
+            progress.advance_bytes(start - position);
+            copy_accel::copy_range(
+                source_file,
+                destination_file,
+                start,
+                Some(end),
+                &mut |bytes| progress.advance_copied_bytes(bytes),
+            )?;
+            position = end;
+        }
+        progress.advance_bytes(length - position);
+        destination_file.set_len(length)?;
+        return Ok(CopyStrategy::Sparse);
+    }
+    let mut step = |bytes| progress.advance_copied_bytes(bytes);
+    let used_copy_file_range =
+        copy_accel::copy_range(source_file, destination_file, offset, None, &mut step)?;
+    Ok(if used_copy_file_range {
+        CopyStrategy::CopyFileRange
+    } else {
+        CopyStrategy::Buffered
+    })
+}
+
+fn hash_stream(
+    reader: &mut impl Read,
+    hasher: &mut Sha256,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    let mut buffer = [0_u8; COPY_BUFFER_SIZE];
+    loop {
+        progress.ensure_not_canceled()?;
+        let bytes_read = reader.read(&mut buffer)?;
+        if bytes_read == 0 {
+            return Ok(());
+        }
+        hasher.update(&buffer[..bytes_read]);
+    }
+}
+
+/// Re-reads a staged copy and compares it with the digest of the source bytes
+/// that were written; `destination` only names the copy in the error.
+fn verify_staged_copy(
+    staged: &Path,
+    destination: &Path,
+    source_hasher: Sha256,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    progress.set_phase(JobPhase::Verifying);
+    let mut copied_hasher = Sha256::new();
+    let result = fs::File::open(staged)
+        .and_then(|mut file| hash_stream(&mut file, &mut copied_hasher, progress));
+    progress.set_phase(JobPhase::Transferring);
+    result?;
+    if copied_hasher.finalize() != source_hasher.finalize() {
+        return Err(io::Error::other(ChecksumMismatch {
+            path: destination.to_path_buf(),
+        }));
+    }
+    Ok(())
+}
+
+/// Appends `source` to the existing `destination`, as chosen in an overwrite
+/// prompt. Both sides must be regular files.
+fn append_file(
+    source: &Path,
+    destination: &Path,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    for path in [source, destination] {
+        if !fs::symlink_metadata(path)?.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("append needs regular files: {}", path.to_string_lossy()),
+            ));
+        }
+    }
+    progress.set_current_path(source);
+    let mut source_file = fs::File::open(source)?;
+    let mut destination_file = fs::OpenOptions::new().append(true).open(destination)?;
+    let start = destination_file.metadata()?.len();
+    let mut hasher = progress.verify.then(Sha256::new);
+    let mut appended = 0_u64;
+    let mut buffer = [0_u8; COPY_BUFFER_SIZE];
+    loop {
+        progress.ensure_not_canceled()?;
+        let bytes_read = source_file.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        destination_file.write_all(&buffer[..bytes_read])?;
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.update(&buffer[..bytes_read]);
+        }
+        appended += bytes_read as u64;
+        progress.advance_bytes(bytes_read as u64);
+        progress.throttle(bytes_read as u64)?;
+    }
+    destination_file.flush()?;
+    if hasher.is_some() {
+        destination_file.sync_all()?;
+    }
+    drop(destination_file);
+    check_appended(source, destination, start, appended, hasher, progress)?;
+    progress.complete_item(source);
+    Ok(())
+}
+
+/// Confirms that `destination` grew by exactly the `appended` bytes of
+/// `source` from `start` and, for a verified job, that those bytes hash to
+/// what was read. A move only removes its source once this passed.
+fn check_appended(
+    source: &Path,
+    destination: &Path,
+    start: u64,
+    appended: u64,
+    source_hasher: Option<Sha256>,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<()> {
+    let source_length = fs::metadata(source)?.len();
+    let destination_length = fs::metadata(destination)?.len();
+    if source_length != appended || destination_length != start.saturating_add(appended) {
+        return Err(io::Error::other(format!(
+            "appending {} to {} wrote {appended} of {source_length} bytes",
+            source.to_string_lossy(),
+            destination.to_string_lossy()
+        )));
+    }
+    let Some(source_hasher) = source_hasher else {
+        return Ok(());
+    };
+    progress.set_phase(JobPhase::Verifying);
+    let mut appended_hasher = Sha256::new();
+    let result = fs::File::open(destination).and_then(|mut file| {
+        file.seek(SeekFrom::Start(start))?;
+        hash_stream(&mut file.take(appended), &mut appended_hasher, progress)
+    });
+    progress.set_phase(JobPhase::Transferring);
+    result?;
+    if appended_hasher.finalize() != source_hasher.finalize() {
+        return Err(io::Error::other(ChecksumMismatch {
+            path: destination.to_path_buf(),
+        }));
+    }
+    Ok(())
+}
+
+fn destination_staging_path(destination: &Path) -> PathBuf {
+    let parent = destination.parent().unwrap_or(Path::new("."));
+    let file_name = destination
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("item");
+
+    for index in 1_usize.. {
+        let candidate = parent.join(format!(".{file_name}.rc-stage-{index}"));
+        // `exists` follows links, so a dangling staged symlink would look free.
+        if fs::symlink_metadata(&candidate).is_err() {
+            return candidate;
+        }
+    }
+
+    unreachable!("staging candidate generator should always return");
+}
+
+fn delete_path(path: &Path, progress: &mut ProgressTracker<'_>) -> io::Result<()> {
+    progress.ensure_not_canceled()?;
+    let metadata = fs::symlink_metadata(path)?;
+    progress.set_current_path(path);
+
+    if metadata.is_dir() {
+        let skipped = progress.items_skipped;
+        for entry in fs::read_dir(path)? {
+            let child = entry?.path();
+            progress.attempt_item(&child, |progress| delete_path(&child, progress))?;
+        }
+        // A directory that still holds skipped items cannot be removed.
+        if progress.items_skipped > skipped {
+            return Ok(());
+        }
+        fs::remove_dir(path)?;
+        progress.complete_item(path);
+        return Ok(());
+    }
+
+    let bytes = if metadata.is_file() {
+        metadata.len()
+    } else {
+        0
+    };
+    fs::remove_file(path)?;
+    if bytes > 0 {
+        progress.advance_bytes(bytes);
+    }
+    progress.complete_item(path);
+    Ok(())
+}
+
+fn destination_path(source: &Path, destination_dir: &Path) -> io::Result<PathBuf> {
+    let Some(name) = source.file_name() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("source has no file name: {}", source.to_string_lossy()),
+        ));
+    };
+    Ok(destination_dir.join(name))
+}
+
+/// Decides what `overwrite` does with `destination` without changing the
+/// filesystem; [`PlannedAction::Ask`] is left to the user.
+fn plan_destination(
+    source: &Path,
+    destination: PathBuf,
+    overwrite: OverwritePolicy,
+) -> (PathBuf, PlannedAction) {
+    if source == destination {
+        return match overwrite {
+            OverwritePolicy::Rename => {
+                let renamed = renamed_destination(&destination);
+                (destination, PlannedAction::RenameTo(renamed))
+            }
+            OverwritePolicy::Overwrite | OverwritePolicy::Skip | OverwritePolicy::Ask => {
+                (destination, PlannedAction::Skip)
+            }
+        };
+    }
+    if !destination.exists() {
+        return (destination, PlannedAction::Create);
+    }
+    let action = match overwrite {
+        OverwritePolicy::Overwrite => PlannedAction::Overwrite,
+        OverwritePolicy::Skip => PlannedAction::Skip,
+        OverwritePolicy::Rename => PlannedAction::RenameTo(renamed_destination(&destination)),
+        OverwritePolicy::Ask => PlannedAction::Ask,
+    };
+    (destination, action)
+}
+
+fn resolve_destination(
+    source: &Path,
+    destination: PathBuf,
+    overwrite: OverwritePolicy,
+    source_totals: JobTotals,
+    progress: &mut ProgressTracker<'_>,
+) -> io::Result<Option<ResolvedDestination>> {
+    let fresh = |path| {
+        Ok(Some(ResolvedDestination {
+            path,
+            overwrite_backup: None,
+            append: false,
+        }))
+    };
+    let (destination, decision) = match plan_destination(source, destination, overwrite) {
+        (destination, PlannedAction::Create) => return fresh(destination),
+        (_, PlannedAction::RenameTo(renamed)) => return fresh(renamed),
+        (destination, PlannedAction::Overwrite) => (destination, OverwriteDecision::Overwrite),
+        (destination, PlannedAction::Ask) => {
+            let conflict = OverwriteConflict::read(source, &destination)?;
+            let decision = progress.ask_overwrite(&conflict)?;
+            let decision = conflict.resolve(decision);
+            (destination, decision)
+        }
+        (destination, _) => (destination, OverwriteDecision::Skip),
+    };
+    match decision {
+        OverwriteDecision::Overwrite
+        | OverwriteDecision::OverwriteIfNewer
+        | OverwriteDecision::OverwriteIfSizeDiffers => {
+            let backup = destination_backup_path(&destination);
+            fs::rename(&destination, &backup)?;
+            Ok(Some(ResolvedDestination {
+                path: destination,
+                overwrite_backup: Some(backup),
+                append: false,
+            }))
+        }
+        OverwriteDecision::Skip => {
+            progress.advance_totals(source, source_totals);
+            Ok(None)
+        }
+        OverwriteDecision::Rename => fresh(renamed_destination(&destination)),
+        OverwriteDecision::Append => Ok(Some(ResolvedDestination {
+            path: destination,
+            overwrite_backup: None,
+            append: true,
+        })),
+    }
+}
+
+/// Dry run of a copy, move, delete or trash request: what happens to each
+/// top-level source, the totals the job would process, and warnings about
+/// conflicts and free space. Nothing is created, renamed or removed.
+pub fn plan_request(request: &JobRequest, cancel_flag: &AtomicBool) -> io::Result<TransferPlan> {
+    let mut plan = TransferPlan::default();
+    let sources = match request {
+        JobRequest::Copy { sources, .. } | JobRequest::Move { sources, .. } => sources,
+        JobRequest::Delete { targets } | JobRequest::Trash { targets } => targets,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("{} jobs cannot be previewed", request.kind().label()),
+            ));
+        }
+    };
+    let missing: Vec<PathBuf> = sources
+        .iter()
+        .filter(|source| fs::symlink_metadata(source).is_err())
+        .cloned()
+        .collect();
+    for source in &missing {
+        plan.warnings.push(format!(
+            "{} does not exist and fails the job",
+            source.to_string_lossy()
+        ));
+    }
+    let Some(request) = request.without_sources(&missing) else {
+        return Ok(plan);
+    };
+    let totals = measure_request_totals(&request, cancel_flag)?;
+    plan.items = totals.items;
+    plan.bytes = totals.bytes;
+
+    match &request {
+        JobRequest::Copy {
+            sources,
+            destination_dir,
+            destination_names,
+            overwrite,
+            ..
+        } => {
+            let destinations = match destination_names {
+                Some(_) => {
+                    match checked_move_plan(
+                        sources,
+                        destination_dir,
+                        destination_names.as_deref(),
+                        false,
+                    ) {
+                        Ok(move_plan) => Some(move_plan),
+                        Err(error) => {
+                            plan.warnings.push(error.to_string());
+                            return Ok(plan);
+                        }
+                    }
+                }
+                None => None,
+            };
+            for (index, source) in sources.iter().enumerate() {
+                ensure_not_canceled(cancel_flag)?;
+                let destination = match &destinations {
+                    Some(move_plan) => move_plan[index].destination.clone(),
+                    None => destination_path(source, destination_dir)?,
+                };
+                if fs::symlink_metadata(source)?.is_dir()
+                    && let Err(error) = validate_directory_destination_not_inside_source(
+                        source,
+                        &destination,
+                        "copy",
+                    )
+                {
+                    plan.warnings.push(error.to_string());
+                }
+                let (destination, action) = plan_destination(source, destination, *overwrite);
+                let writes = action != PlannedAction::Skip;
+                plan.push_step(source, destination, action, writes);
+            }
+            plan.check_free_space(destination_dir, cancel_flag)?;
+            plan.check_destination_filesystem(destination_dir, cancel_flag)?;
+        }
+        JobRequest::Move {
+            sources,
+            destination_dir,
+            destination_names,
+            overwrite,
+            ..
+        } => {
+            let move_plan =
+                match build_move_plan(sources, destination_dir, destination_names.as_deref()) {
+                    Ok(move_plan) => move_plan,
+                    Err(error) => {
+                        plan.warnings.push(error.to_string());
+                        return Ok(plan);
+                    }
+                };
+            if let Err(error) = validate_move_plan(&move_plan, true) {
+                plan.warnings.push(error.to_string());
+            }
+            let cycles = move_cycles(&move_plan);
+            for (planned_move, in_cycle) in move_plan.into_iter().zip(cycles) {
+                ensure_not_canceled(cancel_flag)?;
+                let source = planned_move.source;
+                // Swapped names are freed through a temporary name first.
+                if in_cycle || is_case_only_rename(source, &planned_move.destination)? {
+                    plan.push_step(
+                        source,
+                        planned_move.destination,
+                        PlannedAction::Create,
+                        false,
+                    );
+                    continue;
+                }
+                let (destination, mut action) =
+                    plan_destination(source, planned_move.destination, *overwrite);
+                // Renames within a filesystem need no space; other moves copy.
+                let cross_device = same_device(source, destination_dir) == Some(false);
+                if cross_device && action == PlannedAction::Create {
+                    action = PlannedAction::CopyAndDelete;
+                }
+                let writes = cross_device && action != PlannedAction::Skip;
+                plan.push_step(source, destination, action, writes);
+            }
+            plan.check_free_space(destination_dir, cancel_flag)?;
+            plan.check_destination_filesystem(destination_dir, cancel_flag)?;
+        }
+        JobRequest::Delete { targets } => {
+            for target in targets {
+                plan.push_step(target, target.clone(), PlannedAction::Delete, false);
+            }
+        }
+        JobRequest::Trash { targets } => {
+            for target in targets {
+                plan.push_step(target, target.clone(), PlannedAction::Trash, false);
+            }
+        }
+        _ => {}
+    }
+    let conflicts = plan
+        .steps
+        .iter()
+        .filter(|step| {
+            matches!(
+                step.action,
+                PlannedAction::Overwrite | PlannedAction::Ask | PlannedAction::RenameTo(_)
+            ) || (step.action == PlannedAction::Skip && step.source != step.destination)
+        })
+        .count();
+    if conflicts > 0 {
+        plan.warnings
+            .insert(0, format!("{conflicts} destination(s) already exist"));
+    }
+    Ok(plan)
+}
+
+/// Whether `source` and `destination_dir` are on the same filesystem, or
+/// `None` when that cannot be told.
+fn same_device(source: &Path, destination_dir: &Path) -> Option<bool> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        let source = fs::symlink_metadata(source).ok()?;
+        let destination = fs::metadata(destination_dir).ok()?;
+        Some(source.dev() == destination.dev())
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (source, destination_dir);
+        None
+    }
+}
+
+fn renamed_destination(destination: &Path) -> PathBuf {
+    let parent = destination.parent().unwrap_or(Path::new("."));
+    let file_name = destination
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| String::from("item"));
+
+    for index in 1_usize.. {
+        let suffix = if index == 1 {
+            String::from("copy")
+        } else {
+            format!("copy{index}")
+        };
+        let candidate = parent.join(format!("{file_name}.{suffix}"));
+        if !candidate.exists() {
+            return candidate;
+        }
+    }
+
+    unreachable!("rename candidate generator should always return");
+}
+
+fn validate_move_destination(source: &Path, destination: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(source)?;
+    if metadata.is_dir() {
+        validate_directory_destination_not_inside_source(source, destination, "move")?;
+    }
+    Ok(())
+}
+
+fn validate_directory_destination_not_inside_source(
+    source: &Path,
+    destination: &Path,
+    operation: &str,
+) -> io::Result<()> {
+    if destination_parent_is_inside_source(source, destination)? {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "cannot {operation} directory into itself: {} -> {}",
+                source.to_string_lossy(),
+                destination.to_string_lossy()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn destination_parent_is_inside_source(source: &Path, destination: &Path) -> io::Result<bool> {
+    let source = fs::canonicalize(source)?;
+    let destination_parent = destination.parent().unwrap_or_else(|| Path::new("."));
+    let destination_parent = canonicalize_allowing_missing_tail(destination_parent)?;
+    Ok(destination_parent == source || destination_parent.starts_with(&source))
+}
+
+fn canonicalize_allowing_missing_tail(path: &Path) -> io::Result<PathBuf> {
+    for ancestor in path.ancestors() {
+        let candidate = if ancestor.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            ancestor
+        };
+        match fs::canonicalize(candidate) {
+            Ok(mut resolved) => {
+                let tail = path
+                    .strip_prefix(ancestor)
+                    .unwrap_or_else(|_| Path::new(""));
+                push_normalized_tail(&mut resolved, tail);
+                return Ok(resolved);
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    fs::canonicalize(path)
+}
+
+fn push_normalized_tail(base: &mut PathBuf, tail: &Path) {
+    for component in tail.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                base.pop();
+            }
+            Component::Normal(part) => base.push(part),
+            Component::Prefix(_) | Component::RootDir => base.push(component.as_os_str()),
+        }
+    }
+}
+
+fn copy_symlink(source: &Path, destination: &Path) -> io::Result<()> {
+    let target = fs::read_link(source)?;
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(&target, destination)
+    }
+    #[cfg(windows)]
+    {
+        let parent = source.parent().unwrap_or(Path::new("."));
+        let resolved_target = if target.is_absolute() {
+            target.clone()
+        } else {
+            parent.join(&target)
+        };
+        let is_dir_target = fs::metadata(&resolved_target)
+            .map(|metadata| metadata.is_dir())
+            .unwrap_or(false);
+        if is_dir_target {
+            std::os::windows::fs::symlink_dir(&target, destination)
+        } else {
+            std::os::windows::fs::symlink_file(&target, destination)
+        }
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = target;
+        let _ = destination;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "symlink copy is not supported on this platform",
+        ))
+    }
+}
+
+fn preserve_copied_metadata(
+    source: &Path,
+    destination: &Path,
+    metadata: &fs::Metadata,
+    progress: &ProgressTracker<'_>,
+) -> io::Result<()> {
+    let atime = FileTime::from_last_access_time(metadata);
+    let mtime = FileTime::from_last_modification_time(metadata);
+
+    if metadata.file_type().is_symlink() {
+        filetime::set_symlink_file_times(destination, atime, mtime)?;
+        progress.preserve_xattrs(source, destination);
+        return Ok(());
+    }
+
+    filetime::set_file_times(destination, atime, mtime)?;
+    preserve_owner_best_effort(destination, metadata)?;
+    // After the owner: changing it drops `security.capability`.
+    progress.preserve_xattrs(source, destination);
+    Ok(())
+}
+
+#[cfg(unix)]
+fn preserve_owner_best_effort(destination: &Path, metadata: &fs::Metadata) -> io::Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    match change_owner(destination, Some(metadata.uid()), Some(metadata.gid())) {
+        Ok(()) => Ok(()),
+        Err(Errno::EPERM) | Err(Errno::EACCES) => Ok(()),
+        Err(error) => Err(io::Error::other(format!(
+            "failed to preserve owner/group for {}: {error}",
+            destination.to_string_lossy()
+        ))),
+    }
+}
+
+#[cfg(not(unix))]
+fn preserve_owner_best_effort(_destination: &Path, _metadata: &fs::Metadata) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+fn change_owner(path: &Path, owner: Option<u32>, group: Option<u32>) -> Result<(), Errno> {
+    chown(path, owner.map(Uid::from_raw), group.map(Gid::from_raw))
+}
+
+#[cfg(unix)]
+fn set_ownership(path: &Path, owner: Option<u32>, group: Option<u32>) -> io::Result<()> {
+    change_owner(path, owner, group).map_err(io::Error::from)
+}
+
+#[cfg(not(unix))]
+fn set_ownership(path: &Path, _owner: Option<u32>, _group: Option<u32>) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "changing ownership is not supported on this platform: {}",
+            path.to_string_lossy()
+        ),
+    ))
+}
+
+fn remove_path(path: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// What a dry run expects a job to do with one top-level source.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PlannedAction {
+    Create,
+    Overwrite,
+    Skip,
+    RenameTo(PathBuf),
+    /// The user picks an answer when the job reaches the conflict.
+    Ask,
+    /// A move to another filesystem: copied, then the source is deleted.
+    CopyAndDelete,
+    Delete,
+    Trash,
+}
+
+impl PlannedAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Overwrite => "overwrite",
+            Self::Skip => "skip",
+            Self::RenameTo(_) => "rename-to",
+            Self::Ask => "ask",
+            Self::CopyAndDelete => "copy+delete",
+            Self::Delete => "delete",
+            Self::Trash => "trash",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlannedStep {
+    pub source: PathBuf,
+    /// The path the job writes or removes; the source itself for deletes.
+    pub destination: PathBuf,
+    pub action: PlannedAction,
+    /// Whether the step writes the source's data to the destination
+    /// filesystem.
+    writes: bool,
+}
+
+/// The result of [`plan_request`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TransferPlan {
+    pub steps: Vec<PlannedStep>,
+    pub items: u64,
+    pub bytes: u64,
+    pub warnings: Vec<String>,
+}
+
+impl TransferPlan {
+    fn push_step(
+        &mut self,
+        source: &Path,
+        destination: PathBuf,
+        action: PlannedAction,
+        writes: bool,
+    ) {
+        self.steps.push(PlannedStep {
+            source: source.to_path_buf(),
+            destination,
+            action,
+            writes,
+        });
+    }
+
+    /// Warns when the steps that write data need more than the free space
+    /// of `destination_dir`.
+    fn check_free_space(
+        &mut self,
+        destination_dir: &Path,
+        cancel_flag: &AtomicBool,
+    ) -> io::Result<()> {
+        if !self.steps.iter().any(|step| step.writes) {
+            return Ok(());
+        }
+        let Some(usage) = crate::background::read_disk_usage(destination_dir) else {
+            return Ok(());
+        };
+        let needed = if self.steps.iter().all(|step| step.writes) {
+            self.bytes
+        } else {
+            let writing: Vec<PathBuf> = self
+                .steps
+                .iter()
+                .filter(|step| step.writes)
+                .map(|step| step.source.clone())
+                .collect();
+            measure_paths_totals(&writing, cancel_flag)?.bytes
+        };
+        self.warnings.extend(free_space_warning(
+            needed,
+            usage.free_bytes,
+            destination_dir,
+        ));
+        Ok(())
+    }
+
+    /// Warns about names and sizes among the written sources that the
+    /// filesystem of `destination_dir` refuses.
+    fn check_destination_filesystem(
+        &mut self,
+        destination_dir: &Path,
+        cancel_flag: &AtomicBool,
+    ) -> io::Result<()> {
+        let Some(filesystem) = restricted_filesystem(destination_dir) else {
+            return Ok(());
+        };
+        let writes: Vec<(PathBuf, PathBuf)> = self
+            .steps
+            .iter()
+            .filter(|step| step.writes)
+            .map(|step| {
+                let destination = match &step.action {
+                    PlannedAction::RenameTo(renamed) => renamed.clone(),
+                    _ => step.destination.clone(),
+                };
+                (step.source.clone(), destination)
+            })
+            .collect();
+        self.warnings
+            .extend(check_destination_names(filesystem, &writes, cancel_flag)?);
+        Ok(())
+    }
+}
+
+fn free_space_warning(needed: u64, free: u64, destination_dir: &Path) -> Option<String> {
+    (needed > free).then(|| {
+        format!(
+            "needs {} but only {} free in {}",
+            format_size(needed),
+            format_size(free),
+            destination_dir.to_string_lossy()
+        )
+    })
+}
+
+/// What the destination of a copy, or of a move to another filesystem, may
+/// not take: more bytes than it has free, or names and sizes its filesystem
+/// refuses. Checked before the job writes anything.
+fn transfer_preflight(
+    request: &JobRequest,
+    totals: JobTotals,
+    cancel_flag: &AtomicBool,
+) -> io::Result<Vec<String>> {
+    let destination_of = |index: usize,
+                          source: &Path,
+                          destination_dir: &Path,
+                          destination_names: Option<&[String]>| {
+        match destination_names.and_then(|names| names.get(index)) {
+            Some(name) => Ok(destination_dir.join(name)),
+            None => destination_path(source, destination_dir),
+        }
+    };
+    let (destination_dir, writes, bytes) = match request {
+        JobRequest::Copy {
+            sources,
+            destination_dir,
+            destination_names,
+            ..
+        } => {
+            let writes = sources
+                .iter()
+                .enumerate()
+                .map(|(index, source)| {
+                    let destination = destination_of(
+                        index,
+                        source,
+                        destination_dir,
+                        destination_names.as_deref(),
+                    )?;
+                    Ok((source.clone(), destination))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+            (destination_dir, writes, totals.bytes)
+        }
+        JobRequest::Move {
+            sources,
+            destination_dir,
+            destination_names,
+            ..
+        } => {
+            // Only moves to another filesystem copy their data.
+            let mut writes = Vec::new();
+            for (index, source) in sources.iter().enumerate() {
+                if same_device(source, destination_dir) == Some(false) {
+                    let destination = destination_of(
+                        index,
+                        source,
+                        destination_dir,
+                        destination_names.as_deref(),
+                    )?;
+                    writes.push((source.clone(), destination));
+                }
+            }
+            if writes.is_empty() {
+                return Ok(Vec::new());
+            }
+            let bytes = if writes.len() == sources.len() {
+                totals.bytes
+            } else {
+                let copied: Vec<PathBuf> =
+                    writes.iter().map(|(source, _)| source.clone()).collect();
+                measure_paths_totals(&copied, cancel_flag)?.bytes
+            };
+            (destination_dir, writes, bytes)
+        }
+        _ => return Ok(Vec::new()),
+    };
+    let mut warnings = Vec::new();
+    if let Some(usage) = crate::background::read_disk_usage(destination_dir) {
+        warnings.extend(free_space_warning(bytes, usage.free_bytes, destination_dir));
+    }
+    if let Some(filesystem) = restricted_filesystem(destination_dir) {
+        warnings.extend(check_destination_names(filesystem, &writes, cancel_flag)?);
+    }
+    Ok(warnings)
+}
+
+#[derive(Debug)]
+struct ResolvedDestination {
+    path: PathBuf,
+    overwrite_backup: Option<PathBuf>,
+    /// The user chose to append the source to the existing destination.
+    append: bool,
+}
+
+impl ResolvedDestination {
+    fn finish(self, operation_result: io::Result<()>) -> io::Result<()> {
+        let Some(backup) = self.overwrite_backup else {
+            return operation_result;
+        };
+
+        match operation_result {
+            Ok(()) => remove_path(&backup),
+            Err(operation_error) => {
+                if self.path.exists() {
+                    remove_path(&self.path)?;
+                }
+                fs::rename(&backup, &self.path)?;
+                Err(operation_error)
+            }
+        }
+    }
+}
+
+fn destination_backup_path(destination: &Path) -> PathBuf {
+    let parent = destination.parent().unwrap_or(Path::new("."));
+    let file_name = destination
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("item");
+
+    for index in 1_usize.. {
+        let candidate = parent.join(format!(".{file_name}.rc-backup-{index}"));
+        if !candidate.exists() {
+            return candidate;
+        }
+    }
+
+    unreachable!("backup candidate generator should always return");
+}
+
+fn is_cross_device_error(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::CrossesDevices || error.raw_os_error() == Some(18)
+}
+
+fn canceled_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Interrupted, JOB_CANCELED_MESSAGE)
+}
 
 fn is_canceled_message(message: &str) -> bool {
     message == JOB_CANCELED_MESSAGE
 }
 
+fn is_canceled_error(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::Interrupted && is_canceled_message(&error.to_string())
+}
+
 fn classify_message_error(message: &str) -> (JobErrorCode, JobRetryHint) {
     if is_canceled_message(message) {
         return (JobErrorCode::Canceled, JobRetryHint::None);
     }
 
     let normalized = message.to_ascii_lowercase();
+    if normalized.contains(CHECKSUM_MISMATCH_MESSAGE) {
+        return (JobErrorCode::VerificationFailed, JobRetryHint::Retry);
+    }
     if normalized.contains("permission denied")
         || normalized.contains("operation not permitted")
         || normalized.contains("eacces")
@@ -1795,13 +4938,21 @@ fn classify_message_error(message: &str) -> (JobErrorCode, JobRetryHint) {
     (JobErrorCode::Other, JobRetryHint::Retry)
 }
 
-fn ensure_not_canceled(cancel_flag: &AtomicBool) -> io::Result<()> {
+pub(crate) fn ensure_not_canceled(cancel_flag: &AtomicBool) -> io::Result<()> {
     if cancel_flag.load(Ordering::Relaxed) {
         return Err(canceled_error());
     }
     Ok(())
 }
 
+fn wait_for_resume(cancel_flag: &AtomicBool, pause_flag: &AtomicBool) -> io::Result<()> {
+    while pause_flag.load(Ordering::Relaxed) {
+        ensure_not_canceled(cancel_flag)?;
+        thread::sleep(PAUSE_POLL_INTERVAL);
+    }
+    ensure_not_canceled(cancel_flag)
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 struct JobTotals {
     items: u64,
@@ -1810,18 +4961,64 @@ struct JobTotals {
 
 fn measure_request_totals(request: &JobRequest, cancel_flag: &AtomicBool) -> io::Result<JobTotals> {
     match request {
-        JobRequest::Copy { sources, .. } => measure_paths_totals(sources, cancel_flag),
+        JobRequest::Copy {
+            sources,
+            follow_links,
+            ..
+        } => measure_copy_totals(sources, *follow_links, cancel_flag),
         JobRequest::Move { sources, .. } => measure_paths_totals(sources, cancel_flag),
         JobRequest::Delete { targets } => measure_paths_totals(targets, cancel_flag),
+        JobRequest::Trash { targets } => Ok(JobTotals {
+            items: targets.len() as u64,
+            bytes: 0,
+        }),
+        JobRequest::RestoreTrash { items } => Ok(JobTotals {
+            items: items.len() as u64,
+            bytes: 0,
+        }),
+        JobRequest::PurgeTrash { items } => {
+            let payloads = items
+                .iter()
+                .filter_map(|info_path| TrashedItem::load(info_path).ok())
+                .map(|item| item.files_path)
+                .filter(|path| fs::symlink_metadata(path).is_ok())
+                .collect::<Vec<_>>();
+            measure_paths_totals(&payloads, cancel_flag)
+        }
+        JobRequest::Chmod {
+            targets, recursive, ..
+        }
+        | JobRequest::Chown {
+            targets, recursive, ..
+        } => {
+            if *recursive {
+                let totals = measure_paths_totals(targets, cancel_flag)?;
+                Ok(JobTotals {
+                    items: totals.items,
+                    bytes: 0,
+                })
+            } else {
+                Ok(JobTotals {
+                    items: targets.len() as u64,
+                    bytes: 0,
+                })
+            }
+        }
+        JobRequest::Link { sources, .. } | JobRequest::Symlink { sources, .. } => Ok(JobTotals {
+            items: sources.len() as u64,
+            bytes: 0,
+        }),
         JobRequest::Mkdir { .. }
         | JobRequest::Rename { .. }
+        | JobRequest::EditSymlink { .. }
         | JobRequest::PersistSettings { .. }
         | JobRequest::RefreshPanel { .. }
         | JobRequest::QuickCdSearch { .. }
         | JobRequest::LoadViewer { .. }
         | JobRequest::LoadQuickView { .. }
         | JobRequest::MeasureSelection { .. }
-        | JobRequest::BuildTree { .. } => Ok(JobTotals { items: 1, bytes: 0 }),
+        | JobRequest::BuildTree { .. }
+        | JobRequest::PlanTransfer { .. } => Ok(JobTotals { items: 1, bytes: 0 }),
         JobRequest::Find { .. } => Ok(JobTotals { items: 0, bytes: 0 }),
     }
 }
@@ -1837,6 +5034,68 @@ fn measure_paths_totals(paths: &[PathBuf], cancel_flag: &AtomicBool) -> io::Resu
     Ok(totals)
 }
 
+/// Like [`measure_paths_totals`], but counting what the links a copy
+/// follows lead to.
+fn measure_copy_totals(
+    sources: &[PathBuf],
+    follow_links: FollowLinks,
+    cancel_flag: &AtomicBool,
+) -> io::Result<JobTotals> {
+    if follow_links == FollowLinks::Never {
+        return measure_paths_totals(sources, cancel_flag);
+    }
+    let mut totals = JobTotals::default();
+    for source in sources {
+        let mut ancestors = Vec::new();
+        measure_followed_path(
+            source,
+            follow_links,
+            &mut ancestors,
+            &mut totals,
+            cancel_flag,
+        )?;
+    }
+    Ok(totals)
+}
+
+fn measure_followed_path(
+    path: &Path,
+    follow_links: FollowLinks,
+    ancestors: &mut Vec<DirectoryIdentity>,
+    totals: &mut JobTotals,
+    cancel_flag: &AtomicBool,
+) -> io::Result<()> {
+    ensure_not_canceled(cancel_flag)?;
+    let mut metadata = fs::symlink_metadata(path)?;
+    totals.items = totals.items.saturating_add(1);
+
+    if metadata.file_type().is_symlink() {
+        if !follow_links.follows(ancestors.is_empty()) {
+            return Ok(());
+        }
+        let Ok(target) = fs::metadata(path) else {
+            return Ok(());
+        };
+        if target.is_dir() && ancestors.contains(&directory_identity(path, &target)) {
+            return Ok(());
+        }
+        metadata = target;
+    }
+    if metadata.is_dir() {
+        ancestors.push(directory_identity(path, &metadata));
+        for entry in fs::read_dir(path)? {
+            ensure_not_canceled(cancel_flag)?;
+            let entry = entry?;
+            measure_followed_path(&entry.path(), follow_links, ancestors, totals, cancel_flag)?;
+        }
+        ancestors.pop();
+        return Ok(());
+    }
+
+    totals.bytes = totals.bytes.saturating_add(metadata.len());
+    Ok(())
+}
+
 fn measure_path_totals(path: &Path, cancel_flag: &AtomicBool) -> io::Result<JobTotals> {
     let mut totals = JobTotals::default();
     measure_path(path, &mut totals, cancel_flag)?;
@@ -1864,11 +5123,53 @@ fn measure_path(path: &Path, totals: &mut JobTotals, cancel_flag: &AtomicBool) -
     Ok(())
 }
 
+impl CopyWorkerContext {
+    fn tracker<'a>(&self, event_tx: &'a Sender<JobEvent>) -> ProgressTracker<'a> {
+        // Totals are the coordinating tracker's business; these never clamp.
+        let totals = JobTotals {
+            items: u64::MAX,
+            bytes: u64::MAX,
+        };
+        let mut tracker =
+            ProgressTracker::new(self.job_id, totals, event_tx, Arc::clone(&self.cancel_flag))
+                .with_copy_options(self.copy_options);
+        tracker.pause_flag = self.pause_flag.clone();
+        tracker.verify = self.verify;
+        tracker.preserve = self.preserve;
+        tracker.throttles = self.throttles.clone();
+        tracker
+    }
+}
+
 struct ProgressTracker<'a> {
     job_id: JobId,
     progress: JobProgress,
     event_tx: &'a Sender<JobEvent>,
     cancel_flag: Arc<AtomicBool>,
+    pause_flag: Option<Arc<AtomicBool>>,
+    overwrite_prompt: Option<Arc<WorkerPrompt<OverwriteAnswer>>>,
+    overwrite_for_all: Option<OverwriteDecision>,
+    error_prompt: Option<Arc<WorkerPrompt<ItemErrorAnswer>>>,
+    runtime_slot: Option<Arc<dyn RuntimeSlot>>,
+    /// See [`WorkerJob::set_claimed_destination`].
+    claimed_destination: Option<ClaimedDestination>,
+    /// Failed items are skipped without asking.
+    skip_failed_items: bool,
+    /// The user aborted on a failed item; the error is on its way out.
+    aborted_on_error: bool,
+    items_skipped: u64,
+    copy_options: CopyOptions,
+    verify: bool,
+    preserve: PreserveLevel,
+    follow_links: FollowLinks,
+    /// Directories the copy is inside of while it follows links, to tell a
+    /// link that leads back into one of them.
+    copy_ancestors: Vec<DirectoryIdentity>,
+    /// The first copy of each hard-linked source file, when the copy keeps
+    /// hard links.
+    hard_links: Option<HashMap<HardLinkIdentity, PathBuf>>,
+    rate: RateWindow,
+    throttles: Vec<Arc<Throttle>>,
 }
 
 impl<'a> ProgressTracker<'a> {
@@ -1886,10 +5187,172 @@ impl<'a> ProgressTracker<'a> {
                 items_done: 0,
                 bytes_total: totals.bytes,
                 bytes_done: 0,
+                bytes_resumed: 0,
+                bytes_linked: 0,
+                phase: JobPhase::Transferring,
+                copy_strategies: CopyStrategies::default(),
+                links_followed: 0,
+                bytes_per_second: 0,
+                eta_seconds: None,
             },
             event_tx,
             cancel_flag,
+            pause_flag: None,
+            overwrite_prompt: None,
+            overwrite_for_all: None,
+            error_prompt: None,
+            runtime_slot: None,
+            claimed_destination: None,
+            skip_failed_items: false,
+            aborted_on_error: false,
+            items_skipped: 0,
+            copy_options: CopyOptions::default(),
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            copy_ancestors: Vec::new(),
+            hard_links: None,
+            rate: RateWindow::default(),
+            throttles: Vec::new(),
+        }
+    }
+
+    fn with_overwrite_prompt(
+        mut self,
+        overwrite_prompt: Arc<WorkerPrompt<OverwriteAnswer>>,
+    ) -> Self {
+        self.overwrite_prompt = Some(overwrite_prompt);
+        self
+    }
+
+    fn with_error_policy(
+        mut self,
+        error_prompt: Arc<WorkerPrompt<ItemErrorAnswer>>,
+        continue_on_error: bool,
+    ) -> Self {
+        self.error_prompt = Some(error_prompt);
+        self.skip_failed_items = continue_on_error;
+        self
+    }
+
+    fn with_pause_flag(mut self, pause_flag: Arc<AtomicBool>) -> Self {
+        self.pause_flag = Some(pause_flag);
+        self
+    }
+
+    fn with_copy_options(mut self, copy_options: CopyOptions) -> Self {
+        self.copy_options = copy_options;
+        self
+    }
+
+    fn with_throttles(mut self, job: Arc<Throttle>, global: Arc<Throttle>) -> Self {
+        self.throttles = vec![job, global];
+        self
+    }
+
+    fn copy_worker_context(&self) -> CopyWorkerContext {
+        CopyWorkerContext {
+            job_id: self.job_id,
+            cancel_flag: Arc::clone(&self.cancel_flag),
+            pause_flag: self.pause_flag.clone(),
+            copy_options: self.copy_options,
+            verify: self.verify,
+            preserve: self.preserve,
+            throttles: self.throttles.clone(),
+        }
+    }
+
+    /// Adds what a copy worker transferred since its last report.
+    fn merge_worker_progress(
+        &mut self,
+        bytes: u64,
+        bytes_resumed: u64,
+        strategies: CopyStrategies,
+    ) {
+        for strategy in CopyStrategy::ALL {
+            if strategies.contains(strategy) {
+                self.progress.copy_strategies.insert(strategy);
+            }
+        }
+        if bytes_resumed > 0 {
+            self.resume_bytes(bytes_resumed);
+        }
+        if bytes > bytes_resumed {
+            self.advance_bytes(bytes - bytes_resumed);
+        }
+    }
+
+    /// The metadata a copy of `source` works from: that of its target when
+    /// the job follows this symlink. A link back into a directory being
+    /// copied is copied as a link and reported, as following it would never
+    /// end.
+    fn copy_source_metadata(&mut self, source: &Path) -> io::Result<fs::Metadata> {
+        let metadata = fs::symlink_metadata(source)?;
+        if !metadata.file_type().is_symlink()
+            || !self.follow_links.follows(self.copy_ancestors.is_empty())
+        {
+            return Ok(metadata);
         }
+        let target = fs::metadata(source)?;
+        if target.is_dir()
+            && self
+                .copy_ancestors
+                .contains(&directory_identity(source, &target))
+        {
+            self.warn(format!(
+                "{}: link leads back into the copied tree, copied as a link",
+                source.to_string_lossy()
+            ));
+            return Ok(metadata);
+        }
+        self.progress.links_followed = self.progress.links_followed.saturating_add(1);
+        Ok(target)
+    }
+
+    /// The destination an earlier run of this job claimed for `source` and
+    /// left something at, which is completed rather than resolved again.
+    fn resumed_destination(&self, source: &Path) -> Option<PathBuf> {
+        self.claimed_destination
+            .as_ref()
+            .filter(|claimed| claimed.source == source)
+            .map(|claimed| claimed.destination.clone())
+            .filter(|destination| fs::symlink_metadata(destination).is_ok())
+    }
+
+    /// Reports that `source` is about to be written to the free path
+    /// `destination`, so an interrupted run can be completed there.
+    fn claim_destination(&self, source: &Path, destination: &Path) {
+        let _ = self.event_tx.send(JobEvent::DestinationClaimed {
+            id: self.job_id,
+            claimed: ClaimedDestination {
+                source: source.to_path_buf(),
+                destination: destination.to_path_buf(),
+            },
+        });
+    }
+
+    /// Notes that the copy descends into `source`; returns whether it was
+    /// noted, for [`Self::leave_copy_directory`].
+    fn enter_copy_directory(&mut self, source: &Path, metadata: &fs::Metadata) -> bool {
+        if self.follow_links == FollowLinks::Never {
+            return false;
+        }
+        self.copy_ancestors
+            .push(directory_identity(source, metadata));
+        true
+    }
+
+    fn leave_copy_directory(&mut self, entered: bool) {
+        if entered {
+            self.copy_ancestors.pop();
+        }
+    }
+
+    fn warn(&self, message: String) {
+        let _ = self.event_tx.send(JobEvent::Warning {
+            id: self.job_id,
+            message,
+        });
     }
 
     fn emit(&self) {
@@ -1910,9 +5373,120 @@ impl<'a> ProgressTracker<'a> {
             .bytes_done
             .saturating_add(bytes)
             .min(self.progress.bytes_total);
+        self.update_rate();
+        self.emit();
+    }
+
+    fn update_rate(&mut self) {
+        // Resumed and linked bytes were never transferred and would inflate
+        // the rate.
+        let transferred = self
+            .progress
+            .bytes_done
+            .saturating_sub(self.progress.bytes_resumed)
+            .saturating_sub(self.progress.bytes_linked);
+        let rate = self.rate.record(Instant::now(), transferred);
+        self.progress.bytes_per_second = rate;
+        let remaining = self
+            .progress
+            .bytes_total
+            .saturating_sub(self.progress.bytes_done);
+        self.progress.eta_seconds = (rate > 0).then(|| remaining.div_ceil(rate));
+    }
+
+    fn is_throttled(&self) -> bool {
+        self.throttles.iter().any(|throttle| throttle.limit() > 0)
+    }
+
+    /// Waits as long as the tightest bandwidth cap asks for after `bytes`
+    /// were written, staying responsive to cancellation.
+    fn throttle(&mut self, bytes: u64) -> io::Result<()> {
+        let pause = self
+            .throttles
+            .iter()
+            .map(|throttle| throttle.pause_for(bytes))
+            .max()
+            .unwrap_or_default();
+        let resume_at = Instant::now() + pause;
+        loop {
+            self.ensure_not_canceled()?;
+            let left = resume_at.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return Ok(());
+            }
+            thread::sleep(left.min(THROTTLE_SLEEP_SLICE));
+        }
+    }
+
+    /// Progress callback of the accelerated copy paths. A cap set after the
+    /// file started still paces the remaining chunks.
+    fn advance_copied_bytes(&mut self, bytes: u64) -> io::Result<()> {
+        self.advance_bytes(bytes);
+        self.throttle(bytes)
+    }
+
+    fn record_copy_strategy(&mut self, strategy: CopyStrategy) {
+        self.progress.copy_strategies.insert(strategy);
+    }
+
+    fn set_phase(&mut self, phase: JobPhase) {
+        self.progress.phase = phase;
         self.emit();
     }
 
+    /// Counts bytes kept from an interrupted copy as done without copying them.
+    fn resume_bytes(&mut self, bytes: u64) {
+        self.progress.bytes_resumed = self.progress.bytes_resumed.saturating_add(bytes);
+        self.advance_bytes(bytes);
+    }
+
+    /// Makes `destination` a hard link to the earlier copy of a file that is
+    /// hard-linked to the source of `metadata`; returns whether it did. The
+    /// file's bytes count as done without being copied.
+    fn link_to_earlier_copy(
+        &mut self,
+        metadata: &fs::Metadata,
+        destination: &Path,
+    ) -> io::Result<bool> {
+        let Some(first_copy) = hard_link_identity(metadata)
+            .and_then(|identity| self.hard_links.as_ref()?.get(&identity))
+        else {
+            return Ok(false);
+        };
+        fs::hard_link(first_copy, destination)?;
+        let bytes = metadata.len();
+        self.progress.bytes_linked = self.progress.bytes_linked.saturating_add(bytes);
+        self.advance_bytes(bytes);
+        Ok(true)
+    }
+
+    /// Remembers `destination` as the copy later hard links of the source
+    /// of `metadata` link to.
+    fn note_copied_hard_link(&mut self, metadata: &fs::Metadata, destination: &Path) {
+        if let (Some(links), Some(identity)) =
+            (self.hard_links.as_mut(), hard_link_identity(metadata))
+        {
+            links
+                .entry(identity)
+                .or_insert_with(|| destination.to_path_buf());
+        }
+    }
+
+    /// Whether the copy of the source of `metadata` has to be made on the
+    /// walker, as later hard links to it may need it in place.
+    fn keeps_hard_link(&self, metadata: &fs::Metadata) -> bool {
+        self.hard_links.is_some() && hard_link_identity(metadata).is_some()
+    }
+
+    /// Reports that a top-level source of the request needs no more work,
+    /// so retrying the job can leave it out.
+    fn complete_source(&self, source: &Path) {
+        let _ = self.event_tx.send(JobEvent::SourceCompleted {
+            id: self.job_id,
+            source: source.to_path_buf(),
+        });
+    }
+
     fn complete_item(&mut self, path: &Path) {
         self.progress.current_path = Some(path.to_path_buf());
         self.progress.items_done = self
@@ -1923,30 +5497,165 @@ impl<'a> ProgressTracker<'a> {
         self.emit();
     }
 
-    fn advance_totals(&mut self, path: &Path, totals: JobTotals) {
-        self.progress.current_path = Some(path.to_path_buf());
-        self.progress.items_done = self
-            .progress
-            .items_done
-            .saturating_add(totals.items)
-            .min(self.progress.items_total);
-        self.progress.bytes_done = self
-            .progress
-            .bytes_done
-            .saturating_add(totals.bytes)
-            .min(self.progress.bytes_total);
-        self.emit();
+    fn advance_totals(&mut self, path: &Path, totals: JobTotals) {
+        self.progress.current_path = Some(path.to_path_buf());
+        self.progress.items_done = self
+            .progress
+            .items_done
+            .saturating_add(totals.items)
+            .min(self.progress.items_total);
+        self.progress.bytes_done = self
+            .progress
+            .bytes_done
+            .saturating_add(totals.bytes)
+            .min(self.progress.bytes_total);
+        self.emit();
+    }
+
+    fn mark_done(&mut self) {
+        self.progress.current_path = None;
+        self.progress.items_done = self.progress.items_total;
+        self.progress.bytes_done = self.progress.bytes_total;
+        self.emit();
+    }
+
+    /// Fails once the job is canceled, and holds the worker here while the
+    /// job is paused. The `runtime_slot` is given back for the pause, so a
+    /// paused job does not keep others from running.
+    fn ensure_not_canceled(&mut self) -> io::Result<()> {
+        ensure_not_canceled(self.cancel_flag.as_ref())?;
+        let Some(pause_flag) = self.pause_flag.clone() else {
+            return Ok(());
+        };
+        if !pause_flag.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        self.progress.bytes_per_second = 0;
+        self.progress.eta_seconds = None;
+        self.emit();
+        if let Some(runtime_slot) = self.runtime_slot.as_deref() {
+            runtime_slot.release();
+        }
+        let resumed = wait_for_resume(self.cancel_flag.as_ref(), pause_flag.as_ref());
+        if let Some(runtime_slot) = self.runtime_slot.as_deref() {
+            runtime_slot.reacquire(self.cancel_flag.as_ref());
+        }
+        resumed?;
+        // The time spent paused must not drag the rate down afterwards.
+        self.rate = RateWindow::default();
+        Ok(())
+    }
+
+    /// Asks the UI how to handle `conflict` and waits for the answer, unless
+    /// an earlier answer was applied to all. Without a prompt (jobs run
+    /// outside the app) conflicts are skipped.
+    fn ask_overwrite(&mut self, conflict: &OverwriteConflict) -> io::Result<OverwriteDecision> {
+        if let Some(decision) = self.overwrite_for_all {
+            return Ok(decision);
+        }
+        let Some(prompt) = self.overwrite_prompt.clone() else {
+            return Ok(OverwriteDecision::Skip);
+        };
+        let _ = self.event_tx.send(JobEvent::OverwriteConflict {
+            id: self.job_id,
+            conflict: conflict.clone(),
+        });
+        let answer = prompt.wait(self.cancel_flag.as_ref(), self.runtime_slot.as_deref())?;
+        if answer.apply_to_all {
+            self.overwrite_for_all = Some(answer.decision);
+        }
+        Ok(answer.decision)
+    }
+
+    /// Runs `step` for `path` until it succeeds or the failure is skipped.
+    /// Returns whether the item was done.
+    fn attempt_item(
+        &mut self,
+        path: &Path,
+        mut step: impl FnMut(&mut Self) -> io::Result<()>,
+    ) -> io::Result<bool> {
+        loop {
+            match step(self) {
+                Ok(()) => return Ok(true),
+                Err(error) => {
+                    if !self.retry_after_error(path, error)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
     }
 
-    fn mark_done(&mut self) {
-        self.progress.current_path = None;
-        self.progress.items_done = self.progress.items_total;
-        self.progress.bytes_done = self.progress.bytes_total;
-        self.emit();
+    /// Decides whether the item `path` that failed with `error` is tried
+    /// again (`true`) or skipped (`false`), asking the UI unless failures
+    /// are skipped for all. Cancellation and an abort come back as the
+    /// error, as does any failure of a job run without a prompt (outside
+    /// the app) that does not continue on errors. Invalid requests, such as
+    /// copying a directory into itself, fail the job: no retry can fix them.
+    fn retry_after_error(&mut self, path: &Path, error: io::Error) -> io::Result<bool> {
+        if self.aborted_on_error
+            || is_canceled_error(&error)
+            || error.kind() == io::ErrorKind::InvalidInput
+        {
+            return Err(error);
+        }
+        let failure = ItemFailure {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        };
+        if !self.skip_failed_items {
+            let Some(prompt) = self.error_prompt.clone() else {
+                return Err(error);
+            };
+            let _ = self.event_tx.send(JobEvent::ItemFailed {
+                id: self.job_id,
+                failure: failure.clone(),
+            });
+            match prompt.wait(self.cancel_flag.as_ref(), self.runtime_slot.as_deref())? {
+                ItemErrorAnswer::Retry => return Ok(true),
+                ItemErrorAnswer::Skip => {}
+                ItemErrorAnswer::SkipAll => self.skip_failed_items = true,
+                ItemErrorAnswer::Abort => {
+                    self.aborted_on_error = true;
+                    return Err(error);
+                }
+            }
+        }
+        self.items_skipped += 1;
+        let _ = self.event_tx.send(JobEvent::ItemSkipped {
+            id: self.job_id,
+            failure,
+        });
+        Ok(false)
+    }
+
+    /// Copies the extended attributes the job preserves and reports the
+    /// ones left behind as a warning for `destination`.
+    fn preserve_xattrs(&self, source: &Path, destination: &Path) {
+        let failures = copy_xattrs::copy_xattrs(source, destination, self.preserve);
+        if failures.is_empty() {
+            return;
+        }
+        let failures = failures
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.warn(format!(
+            "{}: attributes not preserved: {failures}",
+            destination.to_string_lossy()
+        ));
     }
 
-    fn ensure_not_canceled(&self) -> io::Result<()> {
-        ensure_not_canceled(self.cancel_flag.as_ref())
+    /// Reports a completed step to the undo journal. Steps whose result can
+    /// no longer be inspected are simply not undoable.
+    fn record_undo(&self, operation: UndoOperation, source: &Path, destination: &Path) {
+        if let Ok(Some(entry)) = UndoEntry::capture(operation, source, destination) {
+            let _ = self.event_tx.send(JobEvent::Undoable {
+                id: self.job_id,
+                entry,
+            });
+        }
     }
 }
 
@@ -2074,6 +5783,13 @@ mod tests {
                 items_done: 1,
                 bytes_total: 128,
                 bytes_done: 64,
+                bytes_resumed: 0,
+                bytes_linked: 0,
+                phase: JobPhase::Transferring,
+                copy_strategies: CopyStrategies::default(),
+                links_followed: 0,
+                bytes_per_second: 0,
+                eta_seconds: None,
             },
         });
 
@@ -2197,7 +5913,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file],
             destination_dir: destination,
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         let copy_id = copy_job.id;
         assert!(
@@ -2253,7 +5974,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file],
             destination_dir: destination,
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         let copy_id = copy_job.id;
         command_tx
@@ -2330,7 +6056,12 @@ mod tests {
         let active_job = manager.enqueue(JobRequest::Copy {
             sources: vec![fifo_source.clone()],
             destination_dir: destination,
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         let active_id = active_job.id;
         let queued_job = manager.enqueue(JobRequest::Mkdir {
@@ -2403,51 +6134,334 @@ mod tests {
             }
         }
 
-        writer_release_tx
-            .send(())
-            .expect("fifo writer should be released after queued cancellation");
+        writer_release_tx
+            .send(())
+            .expect("fifo writer should be released after queued cancellation");
+
+        while !active_finished {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("active job should finish after fifo unblocks");
+            manager.handle_event(&event);
+            match event {
+                JobEvent::Finished { id, result } if id == active_id => {
+                    let error = result.expect_err("active job should not succeed during shutdown");
+                    assert!(error.is_canceled(), "active job should be canceled");
+                    active_finished = true;
+                }
+                JobEvent::Finished { id, .. } if id == queued_id => {
+                    panic!("queued job finished more than once")
+                }
+                _ => {}
+            }
+        }
+
+        writer.join().expect("fifo writer should finish");
+        worker
+            .join()
+            .expect("worker thread should terminate cleanly");
+
+        let counts = manager.status_counts();
+        assert_eq!(counts.canceled, 2);
+        assert_eq!(counts.queued, 0);
+        assert_eq!(counts.running, 0);
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn worker_cancel_command_cancels_active_job() {
+        let root = make_temp_dir("active-cancel-command");
+        let source_dir = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+
+        let payload = vec![9_u8; 32 * 1024 * 1024];
+        let source_file = source_dir.join("blob.bin");
+        fs::write(&source_file, payload).expect("source payload should be writable");
+
+        let (command_tx, command_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let worker = thread::spawn(move || run_worker(command_rx, event_tx));
+
+        let mut manager = JobManager::new();
+        let copy_job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source_file],
+            destination_dir: destination,
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        let copy_id = copy_job.id;
+        command_tx
+            .send(WorkerCommand::Run(Box::new(copy_job)))
+            .expect("copy command should send");
+
+        loop {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("worker should emit a start event");
+            manager.handle_event(&event);
+            match event {
+                JobEvent::Started { id } if id == copy_id => {
+                    command_tx
+                        .send(WorkerCommand::Cancel(copy_id))
+                        .expect("cancel command should send");
+                    break;
+                }
+                JobEvent::Finished { .. } => panic!("copy finished before active cancel was sent"),
+                _ => {}
+            }
+        }
+
+        let finished = recv_until_finished(&event_rx, &mut manager);
+        match finished {
+            JobEvent::Finished {
+                result: Err(error), ..
+            } => assert!(error.is_canceled(), "finished error should be cancellation"),
+            _ => panic!("job should finish with a cancellation error"),
+        }
+        assert_eq!(manager.status_counts().canceled, 1);
+
+        command_tx
+            .send(WorkerCommand::Shutdown)
+            .expect("shutdown should send");
+        worker
+            .join()
+            .expect("worker thread should terminate cleanly");
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn measure_request_totals_stops_when_cancel_is_requested() {
+        let root = make_temp_dir("measure-cancel");
+        let source_file = root.join("source.bin");
+        fs::write(&source_file, vec![1_u8; 16 * 1024]).expect("source payload should be writable");
+
+        let request = JobRequest::Delete {
+            targets: vec![source_file],
+        };
+        let cancel_flag = Arc::new(AtomicBool::new(true));
+        let error = measure_request_totals(&request, cancel_flag.as_ref())
+            .expect_err("canceled preflight should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::Interrupted);
+        assert_eq!(error.to_string(), JOB_CANCELED_MESSAGE);
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn copy_skip_policy_preserves_existing_destination() {
+        let root = make_temp_dir("skip-policy");
+        let source_dir = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+
+        let source_file = source_dir.join("demo.txt");
+        let destination_file = destination.join("demo.txt");
+        fs::write(&source_file, "source").expect("source payload should be writable");
+        fs::write(&destination_file, "destination")
+            .expect("destination payload should be writable");
+
+        let (command_tx, command_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let worker = thread::spawn(move || run_worker(command_rx, event_tx));
+
+        let mut manager = JobManager::new();
+        let copy_job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source_file],
+            destination_dir: destination,
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        command_tx
+            .send(WorkerCommand::Run(Box::new(copy_job)))
+            .expect("copy command should send");
+        let finished = recv_until_finished(&event_rx, &mut manager);
+        assert!(
+            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
+            "copy should succeed with skip policy"
+        );
+
+        let content =
+            fs::read_to_string(&destination_file).expect("destination should be readable");
+        assert_eq!(
+            content, "destination",
+            "existing destination should be preserved"
+        );
+
+        command_tx
+            .send(WorkerCommand::Shutdown)
+            .expect("shutdown should send");
+        worker
+            .join()
+            .expect("worker thread should terminate cleanly");
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn copy_overwrite_policy_replaces_existing_destination() {
+        let root = make_temp_dir("overwrite-policy");
+        let source_dir = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+
+        let source_file = source_dir.join("demo.txt");
+        let destination_file = destination.join("demo.txt");
+        fs::write(&source_file, "source").expect("source payload should be writable");
+        fs::write(&destination_file, "destination")
+            .expect("destination payload should be writable");
+
+        let (command_tx, command_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let worker = thread::spawn(move || run_worker(command_rx, event_tx));
+
+        let mut manager = JobManager::new();
+        let copy_job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source_file],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Overwrite,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        command_tx
+            .send(WorkerCommand::Run(Box::new(copy_job)))
+            .expect("copy command should send");
+        let finished = recv_until_finished(&event_rx, &mut manager);
+        assert!(
+            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
+            "copy should succeed with overwrite policy"
+        );
+
+        let content =
+            fs::read_to_string(&destination_file).expect("destination should be readable");
+        assert_eq!(content, "source", "existing destination should be replaced");
+
+        command_tx
+            .send(WorkerCommand::Shutdown)
+            .expect("shutdown should send");
+        worker
+            .join()
+            .expect("worker thread should terminate cleanly");
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn copy_overwrite_restores_destination_when_copy_fails() {
+        let root = make_temp_dir("overwrite-rollback");
+        let source_root = root.join("source");
+        let preserved_destination = source_root.join("source");
+        fs::create_dir_all(&source_root).expect("source root should exist");
+        fs::create_dir_all(&preserved_destination).expect("existing destination should exist");
+        fs::write(source_root.join("new.txt"), "new payload")
+            .expect("source payload should be writable");
+        fs::write(preserved_destination.join("old.txt"), "preserved payload")
+            .expect("existing destination payload should be writable");
 
-        while !active_finished {
-            let event = event_rx
-                .recv_timeout(Duration::from_secs(5))
-                .expect("active job should finish after fifo unblocks");
-            manager.handle_event(&event);
-            match event {
-                JobEvent::Finished { id, result } if id == active_id => {
-                    let error = result.expect_err("active job should not succeed during shutdown");
-                    assert!(error.is_canceled(), "active job should be canceled");
-                    active_finished = true;
-                }
-                JobEvent::Finished { id, .. } if id == queued_id => {
-                    panic!("queued job finished more than once")
-                }
-                _ => {}
+        let (command_tx, command_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let worker = thread::spawn(move || run_worker(command_rx, event_tx));
+
+        let mut manager = JobManager::new();
+        let copy_job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source_root.clone()],
+            destination_dir: source_root.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Overwrite,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        command_tx
+            .send(WorkerCommand::Run(Box::new(copy_job)))
+            .expect("copy command should send");
+        let finished = recv_until_finished(&event_rx, &mut manager);
+        match finished {
+            JobEvent::Finished {
+                result: Err(error), ..
+            } => {
+                assert_eq!(error.code, JobErrorCode::InvalidInput);
+                assert!(
+                    error.message.contains("cannot copy directory into itself"),
+                    "copy should fail with self-copy validation"
+                );
             }
+            _ => panic!("copy should fail and restore destination"),
         }
 
-        writer.join().expect("fifo writer should finish");
+        let preserved_content = fs::read_to_string(preserved_destination.join("old.txt"))
+            .expect("preserved destination payload should remain readable");
+        assert_eq!(
+            preserved_content, "preserved payload",
+            "failed overwrite should restore original destination content"
+        );
+
+        command_tx
+            .send(WorkerCommand::Shutdown)
+            .expect("shutdown should send");
         worker
             .join()
             .expect("worker thread should terminate cleanly");
-
-        let counts = manager.status_counts();
-        assert_eq!(counts.canceled, 2);
-        assert_eq!(counts.queued, 0);
-        assert_eq!(counts.running, 0);
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
+    #[cfg(unix)]
     #[test]
-    fn worker_cancel_command_cancels_active_job() {
-        let root = make_temp_dir("active-cancel-command");
-        let source_dir = root.join("source");
-        let destination = root.join("destination");
-        fs::create_dir_all(&source_dir).expect("source dir should exist");
-        fs::create_dir_all(&destination).expect("destination dir should exist");
+    fn copy_rejects_destination_inside_source_via_symlink_alias() {
+        let root = make_temp_dir("copy-self-symlink");
+        let source_root = root.join("source");
+        let source_alias = root.join("source-alias");
+        fs::create_dir_all(source_root.join("child")).expect("source tree should exist");
+        fs::write(source_root.join("child/data.txt"), "x").expect("source file should exist");
+        std::os::unix::fs::symlink(&source_root, &source_alias)
+            .expect("source symlink should be creatable");
 
-        let payload = vec![9_u8; 32 * 1024 * 1024];
-        let source_file = source_dir.join("blob.bin");
-        fs::write(&source_file, payload).expect("source payload should be writable");
+        let (event_tx, _event_rx) = mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut progress = ProgressTracker::new(
+            JobId(1),
+            JobTotals { items: 2, bytes: 1 },
+            &event_tx,
+            cancel_flag,
+        );
+        let error = copy_path(
+            &source_root,
+            &source_alias.join("nested-copy"),
+            &mut progress,
+        )
+        .expect_err("copy through source symlink alias should be rejected");
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(
+            error
+                .to_string()
+                .contains("cannot copy directory into itself"),
+            "copy should fail with self-copy validation"
+        );
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn copy_directory_allows_missing_destination_root() {
+        let root = make_temp_dir("copy-directory-missing-root");
+        let source_root = root.join("source");
+        let destination_root = root.join("new-destination");
+        fs::create_dir_all(&source_root).expect("source tree should exist");
+        fs::write(source_root.join("data.txt"), "payload").expect("source file should exist");
 
         let (command_tx, command_rx) = mpsc::channel();
         let (event_tx, event_rx) = mpsc::channel();
@@ -2455,40 +6469,27 @@ mod tests {
 
         let mut manager = JobManager::new();
         let copy_job = manager.enqueue(JobRequest::Copy {
-            sources: vec![source_file],
-            destination_dir: destination,
+            sources: vec![source_root.clone()],
+            destination_dir: destination_root.clone(),
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
-        let copy_id = copy_job.id;
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
             .expect("copy command should send");
-
-        loop {
-            let event = event_rx
-                .recv_timeout(Duration::from_secs(5))
-                .expect("worker should emit a start event");
-            manager.handle_event(&event);
-            match event {
-                JobEvent::Started { id } if id == copy_id => {
-                    command_tx
-                        .send(WorkerCommand::Cancel(copy_id))
-                        .expect("cancel command should send");
-                    break;
-                }
-                JobEvent::Finished { .. } => panic!("copy finished before active cancel was sent"),
-                _ => {}
-            }
-        }
-
         let finished = recv_until_finished(&event_rx, &mut manager);
-        match finished {
-            JobEvent::Finished {
-                result: Err(error), ..
-            } => assert!(error.is_canceled(), "finished error should be cancellation"),
-            _ => panic!("job should finish with a cancellation error"),
-        }
-        assert_eq!(manager.status_counts().canceled, 1);
+        assert!(
+            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
+            "directory copy should create missing destination roots"
+        );
+
+        let copied_payload = fs::read_to_string(destination_root.join("source/data.txt"))
+            .expect("copied file should be readable");
+        assert_eq!(copied_payload, "payload");
 
         command_tx
             .send(WorkerCommand::Shutdown)
@@ -2500,26 +6501,107 @@ mod tests {
     }
 
     #[test]
-    fn measure_request_totals_stops_when_cancel_is_requested() {
-        let root = make_temp_dir("measure-cancel");
+    fn copy_directory_rejects_missing_destination_parent_inside_source() {
+        let root = make_temp_dir("copy-directory-missing-parent-inside-source");
+        let source_root = root.join("source");
+        fs::create_dir_all(&source_root).expect("source tree should exist");
+        fs::write(source_root.join("data.txt"), "payload").expect("source file should exist");
+
+        let (event_tx, _event_rx) = mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut progress = ProgressTracker::new(
+            JobId(1),
+            JobTotals { items: 2, bytes: 7 },
+            &event_tx,
+            cancel_flag,
+        );
+        let error = copy_path(
+            &source_root,
+            &source_root.join("missing-parent/source"),
+            &mut progress,
+        )
+        .expect_err("copy into missing parent below source should be rejected");
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(
+            error
+                .to_string()
+                .contains("cannot copy directory into itself"),
+            "copy should fail with self-copy validation"
+        );
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn move_validation_allows_normalized_sibling_destination_path() {
+        let root = make_temp_dir("move-sibling-dotdot");
+        let source_root = root.join("source");
+        fs::create_dir_all(&source_root).expect("source tree should exist");
+        let sibling_destination = source_root.join("..").join("moved");
+
+        validate_move_destination(&source_root, &sibling_destination)
+            .expect("normalized sibling destination should not be treated as self-move");
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn copy_file_canceled_does_not_leave_partial_destination() {
+        let root = make_temp_dir("copy-file-cancel-cleanup");
         let source_file = root.join("source.bin");
-        fs::write(&source_file, vec![1_u8; 16 * 1024]).expect("source payload should be writable");
+        let destination_dir = root.join("destination");
+        let destination_file = destination_dir.join("source.bin");
+        fs::create_dir_all(&destination_dir).expect("destination directory should exist");
+        fs::write(&source_file, vec![9_u8; 256 * 1024]).expect("source payload should be writable");
 
-        let request = JobRequest::Delete {
-            targets: vec![source_file],
-        };
+        let (event_tx, _event_rx) = mpsc::channel();
         let cancel_flag = Arc::new(AtomicBool::new(true));
-        let error = measure_request_totals(&request, cancel_flag.as_ref())
-            .expect_err("canceled preflight should fail");
-        assert_eq!(error.kind(), std::io::ErrorKind::Interrupted);
+        let mut progress = ProgressTracker::new(
+            JobId(1),
+            JobTotals {
+                items: 1,
+                bytes: 256 * 1024,
+            },
+            &event_tx,
+            cancel_flag,
+        );
+        let error = copy_file(&source_file, &destination_file, &mut progress)
+            .expect_err("canceled copy should fail");
+        assert_eq!(error.kind(), io::ErrorKind::Interrupted);
         assert_eq!(error.to_string(), JOB_CANCELED_MESSAGE);
+        assert!(
+            !destination_file.exists(),
+            "canceled copy should not materialize destination file"
+        );
+
+        let stage_prefix = format!(
+            ".{}.rc-stage-",
+            destination_file
+                .file_name()
+                .and_then(|name| name.to_str())
+                .expect("destination file name should be valid")
+        );
+        let leaked_stage_file = fs::read_dir(&destination_dir)
+            .expect("destination directory should be readable")
+            .filter_map(Result::ok)
+            .any(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&stage_prefix)
+            });
+        assert!(
+            !leaked_stage_file,
+            "canceled copy should clean up staged temp files"
+        );
 
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
     #[test]
-    fn copy_skip_policy_preserves_existing_destination() {
-        let root = make_temp_dir("skip-policy");
+    fn copy_rename_policy_creates_alternate_destination() {
+        let root = make_temp_dir("rename-policy");
         let source_dir = root.join("source");
         let destination = root.join("destination");
         fs::create_dir_all(&source_dir).expect("source dir should exist");
@@ -2538,8 +6620,13 @@ mod tests {
         let mut manager = JobManager::new();
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file],
-            destination_dir: destination,
-            overwrite: OverwritePolicy::Skip,
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Rename,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -2547,15 +6634,17 @@ mod tests {
         let finished = recv_until_finished(&event_rx, &mut manager);
         assert!(
             matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
-            "copy should succeed with skip policy"
+            "copy should succeed with rename policy"
         );
 
-        let content =
-            fs::read_to_string(&destination_file).expect("destination should be readable");
-        assert_eq!(
-            content, "destination",
-            "existing destination should be preserved"
-        );
+        let content_original =
+            fs::read_to_string(&destination_file).expect("original destination should be readable");
+        assert_eq!(content_original, "destination");
+
+        let renamed_file = destination.join("demo.txt.copy");
+        let content_renamed =
+            fs::read_to_string(&renamed_file).expect("renamed destination should be readable");
+        assert_eq!(content_renamed, "source");
 
         command_tx
             .send(WorkerCommand::Shutdown)
@@ -2567,18 +6656,16 @@ mod tests {
     }
 
     #[test]
-    fn copy_overwrite_policy_replaces_existing_destination() {
-        let root = make_temp_dir("overwrite-policy");
+    fn copy_ask_policy_waits_for_answer_and_applies_it_to_all_conflicts() {
+        let root = make_temp_dir("ask-policy");
         let source_dir = root.join("source");
         let destination = root.join("destination");
         fs::create_dir_all(&source_dir).expect("source dir should exist");
         fs::create_dir_all(&destination).expect("destination dir should exist");
-
-        let source_file = source_dir.join("demo.txt");
-        let destination_file = destination.join("demo.txt");
-        fs::write(&source_file, "source").expect("source payload should be writable");
-        fs::write(&destination_file, "destination")
-            .expect("destination payload should be writable");
+        for name in ["a.txt", "b.txt"] {
+            fs::write(source_dir.join(name), "-tail").expect("source should be writable");
+            fs::write(destination.join(name), name).expect("destination should be writable");
+        }
 
         let (command_tx, command_rx) = mpsc::channel();
         let (event_tx, event_rx) = mpsc::channel();
@@ -2586,22 +6673,62 @@ mod tests {
 
         let mut manager = JobManager::new();
         let copy_job = manager.enqueue(JobRequest::Copy {
-            sources: vec![source_file],
+            sources: vec![source_dir.join("a.txt"), source_dir.join("b.txt")],
             destination_dir: destination.clone(),
-            overwrite: OverwritePolicy::Overwrite,
+            destination_names: None,
+            overwrite: OverwritePolicy::Ask,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
+        let job_id = copy_job.id;
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
             .expect("copy command should send");
-        let finished = recv_until_finished(&event_rx, &mut manager);
-        assert!(
-            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
-            "copy should succeed with overwrite policy"
-        );
 
-        let content =
-            fs::read_to_string(&destination_file).expect("destination should be readable");
-        assert_eq!(content, "source", "existing destination should be replaced");
+        let mut prompts = 0;
+        let finished = loop {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(1))
+                .expect("worker should emit job events");
+            manager.handle_event(&event);
+            match event {
+                JobEvent::OverwriteConflict { id, conflict } => {
+                    prompts += 1;
+                    assert_eq!(id, job_id);
+                    assert_eq!(conflict.destination, destination.join("a.txt"));
+                    assert_eq!(conflict.destination_entry.size, 5);
+                    assert!(manager.answer_overwrite(
+                        id,
+                        OverwriteAnswer {
+                            decision: OverwriteDecision::Append,
+                            apply_to_all: true,
+                        },
+                    ));
+                }
+                JobEvent::Finished { .. } => break event,
+                _ => {}
+            }
+        };
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(prompts, 1, "apply to all should suppress later prompts");
+        for name in ["a.txt", "b.txt"] {
+            assert_eq!(
+                fs::read_to_string(destination.join(name)).expect("destination should be readable"),
+                format!("{name}-tail")
+            );
+        }
+        assert!(!manager.answer_overwrite(
+            job_id,
+            OverwriteAnswer {
+                decision: OverwriteDecision::Skip,
+                apply_to_all: false,
+            }
+        ));
 
         command_tx
             .send(WorkerCommand::Shutdown)
@@ -2613,50 +6740,132 @@ mod tests {
     }
 
     #[test]
-    fn copy_overwrite_restores_destination_when_copy_fails() {
-        let root = make_temp_dir("overwrite-rollback");
-        let source_root = root.join("source");
-        let preserved_destination = source_root.join("source");
-        fs::create_dir_all(&source_root).expect("source root should exist");
-        fs::create_dir_all(&preserved_destination).expect("existing destination should exist");
-        fs::write(source_root.join("new.txt"), "new payload")
-            .expect("source payload should be writable");
-        fs::write(preserved_destination.join("old.txt"), "preserved payload")
-            .expect("existing destination payload should be writable");
+    fn verified_move_appends_then_removes_the_source() {
+        let root = make_temp_dir("move-append");
+        let source = root.join("log.txt");
+        let destination = root.join("destination");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        fs::write(&source, "-tail").expect("source should be writable");
+        fs::write(destination.join("log.txt"), "head").expect("destination should be writable");
+
+        let mut manager = JobManager::new();
+        let job = manager.enqueue(JobRequest::Move {
+            sources: vec![source.clone()],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Ask,
+            verify: true,
+            preserve: PreserveLevel::Basic,
+        });
+        let (event_tx, event_rx) = mpsc::channel();
+        let worker = thread::spawn(move || execute_worker_job(job, &event_tx));
+        let finished = loop {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(1))
+                .expect("worker should emit job events");
+            match event {
+                JobEvent::OverwriteConflict { id, .. } => {
+                    assert!(manager.answer_overwrite(
+                        id,
+                        OverwriteAnswer {
+                            decision: OverwriteDecision::Append,
+                            apply_to_all: false,
+                        },
+                    ));
+                }
+                JobEvent::Finished { result, .. } => break result,
+                _ => {}
+            }
+        };
+        worker.join().expect("worker thread should finish");
+
+        assert_eq!(finished, Ok(()));
+        assert_eq!(
+            fs::read_to_string(destination.join("log.txt")).expect("destination should exist"),
+            "head-tail"
+        );
+        assert!(!source.exists(), "appended source should be moved away");
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_asks_about_failed_items_and_retries_or_skips_them() {
+        let root = make_temp_dir("item-errors");
+        let source = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source).expect("source dir should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        fs::write(source.join("a.txt"), "a").expect("source file should be writable");
+        // Followed dangling links fail to copy, even for root.
+        std::os::unix::fs::symlink(root.join("late-target"), source.join("late"))
+            .expect("late link should be created");
+        std::os::unix::fs::symlink(root.join("missing"), source.join("gone"))
+            .expect("dangling link should be created");
 
         let (command_tx, command_rx) = mpsc::channel();
         let (event_tx, event_rx) = mpsc::channel();
         let worker = thread::spawn(move || run_worker(command_rx, event_tx));
 
         let mut manager = JobManager::new();
-        let copy_job = manager.enqueue(JobRequest::Copy {
-            sources: vec![source_root.clone()],
-            destination_dir: source_root.clone(),
-            overwrite: OverwritePolicy::Overwrite,
+        let mut copy_job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source.clone()],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Always,
+            hard_links: false,
+        });
+        copy_job.set_copy_options(CopyOptions {
+            resume: false,
+            workers: 4,
         });
+        let job_id = copy_job.id;
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
             .expect("copy command should send");
-        let finished = recv_until_finished(&event_rx, &mut manager);
-        match finished {
-            JobEvent::Finished {
-                result: Err(error), ..
-            } => {
-                assert_eq!(error.code, JobErrorCode::InvalidInput);
-                assert!(
-                    error.message.contains("cannot copy directory into itself"),
-                    "copy should fail with self-copy validation"
-                );
-            }
-            _ => panic!("copy should fail and restore destination"),
-        }
 
-        let preserved_content = fs::read_to_string(preserved_destination.join("old.txt"))
-            .expect("preserved destination payload should remain readable");
+        let mut prompted = Vec::new();
+        let finished = loop {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(1))
+                .expect("worker should emit job events");
+            manager.handle_event(&event);
+            match event {
+                JobEvent::ItemFailed { id, failure } => {
+                    let answer = if failure.path == source.join("late") {
+                        fs::write(root.join("late-target"), "late")
+                            .expect("link target should be writable");
+                        ItemErrorAnswer::Retry
+                    } else {
+                        ItemErrorAnswer::Skip
+                    };
+                    prompted.push(failure.path);
+                    assert!(manager.answer_item_error(id, answer));
+                }
+                JobEvent::Finished { .. } => break event,
+                _ => {}
+            }
+        };
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        prompted.sort();
+        assert_eq!(prompted, vec![source.join("gone"), source.join("late")]);
+        let job = manager.job(job_id).expect("job should be recorded");
+        assert_eq!(job.skipped_items.len(), 1);
+        assert_eq!(job.skipped_items[0].path, source.join("gone"));
+        let copied = destination.join("source");
         assert_eq!(
-            preserved_content, "preserved payload",
-            "failed overwrite should restore original destination content"
+            fs::read_to_string(copied.join("late")).expect("retried link should be copied"),
+            "late"
         );
+        assert!(copied.join("a.txt").exists());
+        assert!(fs::symlink_metadata(copied.join("gone")).is_err());
+        assert!(!manager.answer_item_error(job_id, ItemErrorAnswer::Skip));
 
         command_tx
             .send(WorkerCommand::Shutdown)
@@ -2669,71 +6878,92 @@ mod tests {
 
     #[cfg(unix)]
     #[test]
-    fn copy_rejects_destination_inside_source_via_symlink_alias() {
-        let root = make_temp_dir("copy-self-symlink");
-        let source_root = root.join("source");
-        let source_alias = root.join("source-alias");
-        fs::create_dir_all(source_root.join("child")).expect("source tree should exist");
-        fs::write(source_root.join("child/data.txt"), "x").expect("source file should exist");
-        std::os::unix::fs::symlink(&source_root, &source_alias)
-            .expect("source symlink should be creatable");
-
-        let (event_tx, _event_rx) = mpsc::channel();
-        let cancel_flag = Arc::new(AtomicBool::new(false));
-        let mut progress = ProgressTracker::new(
-            JobId(1),
-            JobTotals { items: 2, bytes: 1 },
-            &event_tx,
-            cancel_flag,
-        );
-        let error = copy_path(
-            &source_root,
-            &source_alias.join("nested-copy"),
-            &mut progress,
-        )
-        .expect_err("copy through source symlink alias should be rejected");
-
-        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
-        assert!(
-            error
-                .to_string()
-                .contains("cannot copy directory into itself"),
-            "copy should fail with self-copy validation"
-        );
-
-        fs::remove_dir_all(&root).expect("temp tree should be removable");
-    }
-
-    #[test]
-    fn copy_directory_allows_missing_destination_root() {
-        let root = make_temp_dir("copy-directory-missing-root");
-        let source_root = root.join("source");
-        let destination_root = root.join("new-destination");
-        fs::create_dir_all(&source_root).expect("source tree should exist");
-        fs::write(source_root.join("data.txt"), "payload").expect("source file should exist");
+    fn item_errors_are_skipped_when_continuing_and_fail_the_job_once_aborted() {
+        let root = make_temp_dir("item-errors-policy");
+        let source = root.join("source");
+        fs::create_dir_all(source.join("nested")).expect("source dirs should exist");
+        fs::write(source.join("nested/a.txt"), "a").expect("source file should be writable");
+        std::os::unix::fs::symlink(root.join("missing"), source.join("nested/gone"))
+            .expect("dangling link should be created");
 
         let (command_tx, command_rx) = mpsc::channel();
         let (event_tx, event_rx) = mpsc::channel();
         let worker = thread::spawn(move || run_worker(command_rx, event_tx));
-
         let mut manager = JobManager::new();
-        let copy_job = manager.enqueue(JobRequest::Copy {
-            sources: vec![source_root.clone()],
-            destination_dir: destination_root.clone(),
+        let copy = |destination: &Path| JobRequest::Copy {
+            sources: vec![source.clone()],
+            destination_dir: destination.to_path_buf(),
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
-        });
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Always,
+            hard_links: false,
+        };
+
+        let continued = root.join("continued");
+        fs::create_dir_all(&continued).expect("destination dir should exist");
+        let mut copy_job = manager.enqueue(copy(&continued));
+        copy_job.set_continue_on_error(true);
+        let continued_id = copy_job.id;
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
             .expect("copy command should send");
-        let finished = recv_until_finished(&event_rx, &mut manager);
-        assert!(
-            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
-            "directory copy should create missing destination roots"
-        );
-
-        let copied_payload = fs::read_to_string(destination_root.join("source/data.txt"))
-            .expect("copied file should be readable");
-        assert_eq!(copied_payload, "payload");
+        let mut prompts = 0;
+        let finished = loop {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(1))
+                .expect("worker should emit job events");
+            manager.handle_event(&event);
+            match event {
+                JobEvent::ItemFailed { .. } => prompts += 1,
+                JobEvent::Finished { .. } => break event,
+                _ => {}
+            }
+        };
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(prompts, 0, "continuing on errors should not ask");
+        let skipped = &manager
+            .job(continued_id)
+            .expect("job should be recorded")
+            .skipped_items;
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, source.join("nested/gone"));
+        assert!(continued.join("source/nested/a.txt").exists());
+
+        let aborted = root.join("aborted");
+        fs::create_dir_all(&aborted).expect("destination dir should exist");
+        let copy_job = manager.enqueue(copy(&aborted));
+        let aborted_id = copy_job.id;
+        command_tx
+            .send(WorkerCommand::Run(Box::new(copy_job)))
+            .expect("copy command should send");
+        let mut prompts = 0;
+        let finished = loop {
+            let event = event_rx
+                .recv_timeout(Duration::from_secs(1))
+                .expect("worker should emit job events");
+            manager.handle_event(&event);
+            match event {
+                JobEvent::ItemFailed { id, .. } => {
+                    prompts += 1;
+                    assert!(manager.answer_item_error(id, ItemErrorAnswer::Abort));
+                }
+                JobEvent::Finished { .. } => break event,
+                _ => {}
+            }
+        };
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Err(_), .. }
+        ));
+        assert_eq!(prompts, 1, "an abort should not be asked about again");
+        let job = manager.job(aborted_id).expect("job should be recorded");
+        assert_eq!(job.status, JobStatus::Failed);
+        assert!(job.skipped_items.is_empty());
 
         command_tx
             .send(WorkerCommand::Shutdown)
@@ -2744,154 +6974,712 @@ mod tests {
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
-    #[test]
-    fn copy_directory_rejects_missing_destination_parent_inside_source() {
-        let root = make_temp_dir("copy-directory-missing-parent-inside-source");
-        let source_root = root.join("source");
-        fs::create_dir_all(&source_root).expect("source tree should exist");
-        fs::write(source_root.join("data.txt"), "payload").expect("source file should exist");
+    #[test]
+    fn overwrite_conflict_resolves_conditional_decisions() {
+        let older = SystemTime::UNIX_EPOCH;
+        let newer = older + Duration::from_secs(60);
+        let entry = |size, modified| ConflictEntry {
+            size,
+            modified: Some(modified),
+            is_dir: false,
+        };
+        let conflict = OverwriteConflict {
+            source: PathBuf::from("/src/demo"),
+            destination: PathBuf::from("/dst/demo"),
+            source_entry: entry(10, newer),
+            destination_entry: entry(10, older),
+        };
+        assert_eq!(
+            conflict.resolve(OverwriteDecision::OverwriteIfNewer),
+            OverwriteDecision::Overwrite
+        );
+        assert_eq!(
+            conflict.resolve(OverwriteDecision::OverwriteIfSizeDiffers),
+            OverwriteDecision::Skip
+        );
+        assert_eq!(
+            conflict.resolve(OverwriteDecision::Rename),
+            OverwriteDecision::Rename
+        );
+    }
+
+    #[test]
+    fn resumable_copy_continues_after_verified_staged_prefix() {
+        let root = make_temp_dir("resume-copy");
+        let destination = root.join("destination");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        let source = root.join("large.bin");
+        let payload: Vec<u8> = (0..200_000_u32).map(|value| value as u8).collect();
+        fs::write(&source, &payload).expect("source should be writable");
+
+        let staging = ResumeStaging::for_destination(&destination.join("large.bin"));
+        staging
+            .restart(&ResumeStamp::read(&source).expect("stamp should be readable"))
+            .expect("sidecar should be writable");
+        fs::write(&staging.partial, &payload[..150_000]).expect("partial should be writable");
+
+        let mut manager = JobManager::new();
+        let mut job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source.clone()],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        job.set_copy_options(CopyOptions {
+            resume: true,
+            ..CopyOptions::default()
+        });
+        let (event_tx, event_rx) = mpsc::channel();
+        execute_worker_job(job, &event_tx);
+        drop(event_tx);
+
+        let events: Vec<JobEvent> = event_rx.iter().collect();
+        assert!(matches!(
+            events.last(),
+            Some(JobEvent::Finished { result: Ok(()), .. })
+        ));
+        let resumed = events
+            .iter()
+            .filter_map(|event| match event {
+                JobEvent::Progress { progress, .. } => Some(progress.bytes_resumed),
+                _ => None,
+            })
+            .max();
+        assert_eq!(resumed, Some(150_000));
+        assert_eq!(
+            fs::read(destination.join("large.bin")).expect("copy should be readable"),
+            payload
+        );
+        assert!(!staging.partial.exists());
+        assert!(!staging.sidecar.exists());
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn verified_copy_rereads_destination_and_reports_mismatches() {
+        let root = make_temp_dir("verify-copy");
+        let destination = root.join("destination");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        let source = root.join("payload.bin");
+        fs::write(&source, b"verified payload").expect("source should be writable");
+
+        let mut manager = JobManager::new();
+        let job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source.clone()],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: true,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        let (event_tx, event_rx) = mpsc::channel();
+        execute_worker_job(job, &event_tx);
+        drop(event_tx);
+        let events: Vec<JobEvent> = event_rx.iter().collect();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            JobEvent::Progress { progress, .. } if progress.phase == JobPhase::Verifying
+        )));
+        assert!(matches!(
+            events.last(),
+            Some(JobEvent::Finished { result: Ok(()), .. })
+        ));
+        assert_eq!(
+            fs::read(destination.join("payload.bin")).expect("copy should be readable"),
+            b"verified payload"
+        );
 
         let (event_tx, _event_rx) = mpsc::channel();
-        let cancel_flag = Arc::new(AtomicBool::new(false));
         let mut progress = ProgressTracker::new(
             JobId(1),
-            JobTotals { items: 2, bytes: 7 },
+            JobTotals::default(),
             &event_tx,
-            cancel_flag,
+            Arc::new(AtomicBool::new(false)),
         );
-        let error = copy_path(
-            &source_root,
-            &source_root.join("missing-parent/source"),
+        let mut hasher = Sha256::new();
+        hasher.update(b"different payload");
+        let error = verify_staged_copy(
+            &destination.join("payload.bin"),
+            &destination.join("payload.bin"),
+            hasher,
             &mut progress,
         )
-        .expect_err("copy into missing parent below source should be rejected");
-
-        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
-        assert!(
-            error
-                .to_string()
-                .contains("cannot copy directory into itself"),
-            "copy should fail with self-copy validation"
+        .expect_err("a digest mismatch must fail verification");
+        assert_eq!(progress.progress.phase, JobPhase::Transferring);
+        let error = JobError::from_io(error);
+        assert_eq!(error.code, JobErrorCode::VerificationFailed);
+        assert_eq!(
+            JobError::from_message(error.message).code,
+            JobErrorCode::VerificationFailed
         );
 
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
+    #[cfg(target_os = "linux")]
     #[test]
-    fn move_validation_allows_normalized_sibling_destination_path() {
-        let root = make_temp_dir("move-sibling-dotdot");
-        let source_root = root.join("source");
-        fs::create_dir_all(&source_root).expect("source tree should exist");
-        let sibling_destination = source_root.join("..").join("moved");
+    fn sparse_copy_keeps_holes_and_reports_the_strategy() {
+        use std::os::unix::fs::{FileExt, MetadataExt};
 
-        validate_move_destination(&source_root, &sibling_destination)
-            .expect("normalized sibling destination should not be treated as self-move");
+        let root = make_temp_dir("sparse-copy");
+        let destination = root.join("destination");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        let source = root.join("disk.img");
+        let length = 8 * 1024 * 1024;
+        let file = fs::File::create(&source).expect("source should be creatable");
+        file.set_len(length).expect("source should be extendable");
+        file.write_all_at(b"head", 0)
+            .expect("head should be writable");
+        file.write_all_at(b"tail", 6 * 1024 * 1024)
+            .expect("tail should be writable");
+        drop(file);
+        let source_sparse = copy_accel::is_sparse(&fs::metadata(&source).expect("metadata"));
+
+        let mut manager = JobManager::new();
+        let job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source.clone()],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        let job_id = job.id;
+        let (event_tx, event_rx) = mpsc::channel();
+        execute_worker_job(job, &event_tx);
+        drop(event_tx);
+        for event in event_rx.iter() {
+            manager.handle_event(&event);
+        }
+
+        let record = manager.job(job_id).expect("job should be tracked");
+        assert_eq!(record.status, JobStatus::Succeeded);
+        assert!(record.summary.contains(" via "), "{}", record.summary);
+        let copied = destination.join("disk.img");
+        assert_eq!(
+            fs::read(&copied).expect("copy should be readable"),
+            fs::read(&source).expect("source should be readable")
+        );
+        if source_sparse {
+            let metadata = fs::metadata(&copied).expect("copy metadata");
+            assert_eq!(metadata.len(), length);
+            assert!(
+                metadata.blocks() * 512 < length,
+                "holes must not be filled in"
+            );
+        }
 
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
+    #[cfg(target_os = "linux")]
     #[test]
-    fn copy_file_canceled_does_not_leave_partial_destination() {
-        let root = make_temp_dir("copy-file-cancel-cleanup");
-        let source_file = root.join("source.bin");
-        let destination_dir = root.join("destination");
-        let destination_file = destination_dir.join("source.bin");
-        fs::create_dir_all(&destination_dir).expect("destination directory should exist");
-        fs::write(&source_file, vec![9_u8; 256 * 1024]).expect("source payload should be writable");
+    fn capped_sparse_copy_paces_only_the_data_it_sends() {
+        use std::os::unix::fs::FileExt;
+
+        let root = make_temp_dir("sparse-capped");
+        let source = root.join("disk.img");
+        let length = 8 * 1024 * 1024;
+        let file = fs::File::create(&source).expect("source should be creatable");
+        file.set_len(length).expect("source should be extendable");
+        file.write_all_at(b"tail", length - 4)
+            .expect("tail should be writable");
+        drop(file);
+        let metadata = fs::metadata(&source).expect("metadata");
+        let source_file = fs::File::open(&source).expect("source should open");
+        if !copy_accel::is_sparse(&metadata)
+            || copy_accel::data_segments(&source_file, 0, length)
+                .expect("holes should be readable")
+                .is_none()
+        {
+            fs::remove_dir_all(&root).expect("temp tree should be removable");
+            return;
+        }
+        let copied = root.join("copy.img");
+        let destination_file = fs::File::create(&copied).expect("copy should be creatable");
 
         let (event_tx, _event_rx) = mpsc::channel();
-        let cancel_flag = Arc::new(AtomicBool::new(true));
+        let cap = Arc::new(Throttle::default());
+        cap.set_limit(1024 * 1024);
         let mut progress = ProgressTracker::new(
             JobId(1),
             JobTotals {
                 items: 1,
-                bytes: 256 * 1024,
+                bytes: length,
             },
             &event_tx,
-            cancel_flag,
-        );
-        let error = copy_file(&source_file, &destination_file, &mut progress)
-            .expect_err("canceled copy should fail");
-        assert_eq!(error.kind(), io::ErrorKind::Interrupted);
-        assert_eq!(error.to_string(), JOB_CANCELED_MESSAGE);
-        assert!(
-            !destination_file.exists(),
-            "canceled copy should not materialize destination file"
+            Arc::new(AtomicBool::new(false)),
+        )
+        .with_throttles(cap, Arc::new(Throttle::default()));
+        let started = Instant::now();
+        let strategy =
+            copy_contents_accelerated(&source_file, &destination_file, &metadata, 0, &mut progress)
+                .expect("sparse copy should succeed");
+        let elapsed = started.elapsed();
+
+        // Charging the 8M of holes to the 1M/s cap would take 8 seconds.
+        assert_eq!(strategy, CopyStrategy::Sparse);
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+        assert_eq!(progress.progress.bytes_done, length);
+        assert_eq!(
+            fs::read(&copied).expect("copy should be readable"),
+            fs::read(&source).expect("source should be readable")
         );
 
-        let stage_prefix = format!(
-            ".{}.rc-stage-",
-            destination_file
-                .file_name()
-                .and_then(|name| name.to_str())
-                .expect("destination file name should be valid")
-        );
-        let leaked_stage_file = fs::read_dir(&destination_dir)
-            .expect("destination directory should be readable")
-            .filter_map(Result::ok)
-            .any(|entry| {
-                entry
-                    .file_name()
-                    .to_string_lossy()
-                    .starts_with(&stage_prefix)
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_carries_user_xattrs_only_at_the_chosen_level() {
+        let root = make_temp_dir("xattr-copy");
+        let source = root.join("tagged.txt");
+        fs::write(&source, "tagged").expect("source should be writable");
+        if rustix::fs::setxattr(
+            &source,
+            "user.rc.origin",
+            b"panel",
+            rustix::fs::XattrFlags::empty(),
+        )
+        .is_err()
+        {
+            // The temp filesystem does not support user attributes.
+            fs::remove_dir_all(&root).expect("temp tree should be removable");
+            return;
+        }
+
+        for (level, expected) in [
+            (PreserveLevel::Basic, None),
+            (PreserveLevel::Xattrs, Some(b"panel".to_vec())),
+        ] {
+            let destination = root.join(level.label());
+            fs::create_dir_all(&destination).expect("destination dir should exist");
+            let mut manager = JobManager::new();
+            let job = manager.enqueue(JobRequest::Copy {
+                sources: vec![source.clone()],
+                destination_dir: destination.clone(),
+                destination_names: None,
+                overwrite: OverwritePolicy::Skip,
+                verify: false,
+                preserve: level,
+                follow_links: FollowLinks::Never,
+                hard_links: false,
             });
+            let job_id = job.id;
+            let (event_tx, event_rx) = mpsc::channel();
+            execute_worker_job(job, &event_tx);
+            drop(event_tx);
+            for event in event_rx.iter() {
+                manager.handle_event(&event);
+            }
+
+            let record = manager.job(job_id).expect("job should be tracked");
+            assert_eq!(record.status, JobStatus::Succeeded);
+            assert!(record.warnings.is_empty(), "{:?}", record.warnings);
+            let mut value = [0_u8; 16];
+            let copied =
+                rustix::fs::getxattr(destination.join("tagged.txt"), "user.rc.origin", &mut value)
+                    .ok()
+                    .map(|length| value[..length].to_vec());
+            assert_eq!(copied, expected, "level {}", level.label());
+        }
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn copy_honours_the_global_bandwidth_cap() {
+        let root = make_temp_dir("bandwidth-cap");
+        let source = root.join("large.bin");
+        let destination = root.join("destination");
+        fs::write(&source, vec![7_u8; 256 * 1024]).expect("source should be writable");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+
+        let mut manager = JobManager::new();
+        manager.set_global_bandwidth_limit(1024 * 1024);
+        let job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        let job_id = job.id;
+        assert!(manager.set_bandwidth_limit(job_id, 4 * 1024 * 1024));
+        let (event_tx, event_rx) = mpsc::channel();
+        let started = Instant::now();
+        execute_worker_job(job, &event_tx);
+        let elapsed = started.elapsed();
+        drop(event_tx);
+        for event in event_rx.iter() {
+            manager.handle_event(&event);
+        }
+
+        // The first 64K go out unpaced; the rest wait for the 1M/s global cap,
+        // which is tighter than the job's own.
+        assert!(elapsed >= Duration::from_millis(150), "{elapsed:?}");
+        assert_eq!(
+            manager.job(job_id).map(|job| job.status),
+            Some(JobStatus::Succeeded)
+        );
+        assert_eq!(
+            fs::metadata(destination.join("large.bin"))
+                .map(|metadata| metadata.len())
+                .ok(),
+            Some(256 * 1024)
+        );
         assert!(
-            !leaked_stage_file,
-            "canceled copy should clean up staged temp files"
+            !manager.set_bandwidth_limit(job_id, 0),
+            "finished jobs have no cap to change"
         );
-
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
     #[test]
-    fn copy_rename_policy_creates_alternate_destination() {
-        let root = make_temp_dir("rename-policy");
-        let source_dir = root.join("source");
+    fn paused_copy_waits_until_resumed() {
+        let root = make_temp_dir("pause-copy");
+        let source = root.join("a.txt");
         let destination = root.join("destination");
-        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::write(&source, "a").expect("source should be writable");
         fs::create_dir_all(&destination).expect("destination dir should exist");
 
-        let source_file = source_dir.join("demo.txt");
-        let destination_file = destination.join("demo.txt");
-        fs::write(&source_file, "source").expect("source payload should be writable");
-        fs::write(&destination_file, "destination")
-            .expect("destination payload should be writable");
+        let mut manager = JobManager::new();
+        let mkdir = manager.enqueue(JobRequest::Mkdir {
+            path: root.join("made"),
+        });
+        assert!(!manager.set_paused(mkdir.id, true), "mkdir is not pausable");
+        let job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        let job_id = job.id;
+        assert!(manager.set_paused(job_id, true));
+        assert_eq!(manager.status_counts().paused, 1);
 
-        let (command_tx, command_rx) = mpsc::channel();
         let (event_tx, event_rx) = mpsc::channel();
-        let worker = thread::spawn(move || run_worker(command_rx, event_tx));
+        let worker = thread::spawn(move || execute_worker_job(job, &event_tx));
+        let started = event_rx
+            .recv_timeout(Duration::from_secs(2))
+            .expect("job should start");
+        manager.handle_event(&started);
+        assert_eq!(
+            manager.job(job_id).map(|job| job.status),
+            Some(JobStatus::Paused),
+            "a job paused while queued stays paused when it starts"
+        );
+        thread::sleep(Duration::from_millis(200));
+        assert!(!destination.join("a.txt").exists());
+
+        assert!(manager.set_paused(job_id, false));
+        assert_eq!(
+            manager.job(job_id).map(|job| job.status),
+            Some(JobStatus::Running)
+        );
+        worker.join().expect("worker should finish");
+        for event in event_rx.try_iter() {
+            manager.handle_event(&event);
+        }
+        assert_eq!(
+            manager.job(job_id).map(|job| job.status),
+            Some(JobStatus::Succeeded)
+        );
+        assert!(destination.join("a.txt").exists());
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn parallel_copy_copies_tree_and_sets_directory_metadata_last() {
+        let root = make_temp_dir("parallel-copy");
+        let source = root.join("tree");
+        let destination = root.join("destination");
+        let nested = source.join("nested").join("deeper");
+        fs::create_dir_all(&nested).expect("source tree should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        for index in 0..40 {
+            let directory = if index % 2 == 0 { &source } else { &nested };
+            fs::write(
+                directory.join(format!("small-{index}")),
+                format!("file {index}"),
+            )
+            .expect("small file should be writable");
+        }
+        fs::write(source.join("large.bin"), vec![3_u8; 2 * 1024 * 1024])
+            .expect("large file should be writable");
+        let directory_mtime = FileTime::from_unix_time(946_684_800, 0);
+        fs::set_permissions(&nested, fs::Permissions::from_mode(0o555))
+            .expect("nested dir should become read-only");
+        filetime::set_file_mtime(&nested, directory_mtime).expect("mtime should be settable");
 
         let mut manager = JobManager::new();
-        let copy_job = manager.enqueue(JobRequest::Copy {
-            sources: vec![source_file],
+        let mut job = manager.enqueue(JobRequest::Copy {
+            sources: vec![source],
             destination_dir: destination.clone(),
-            overwrite: OverwritePolicy::Rename,
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
-        command_tx
-            .send(WorkerCommand::Run(Box::new(copy_job)))
-            .expect("copy command should send");
-        let finished = recv_until_finished(&event_rx, &mut manager);
-        assert!(
-            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
-            "copy should succeed with rename policy"
+        job.set_copy_options(CopyOptions {
+            workers: 4,
+            ..CopyOptions::default()
+        });
+        let job_id = job.id;
+        let (event_tx, event_rx) = mpsc::channel();
+        execute_worker_job(job, &event_tx);
+        drop(event_tx);
+        let mut last_progress = None;
+        for event in event_rx.iter() {
+            if let JobEvent::Progress { progress, .. } = &event {
+                last_progress = Some(progress.clone());
+            }
+            manager.handle_event(&event);
+        }
+
+        assert_eq!(
+            manager.job(job_id).map(|job| job.status),
+            Some(JobStatus::Succeeded),
+            "{:?}",
+            manager.job(job_id).and_then(|job| job.last_error.clone())
+        );
+        let last_progress = last_progress.expect("progress should be reported");
+        assert_eq!(last_progress.items_done, last_progress.items_total);
+        assert_eq!(last_progress.bytes_done, last_progress.bytes_total);
+        let copied_nested = destination.join("tree").join("nested").join("deeper");
+        assert_eq!(
+            fs::read_to_string(copied_nested.join("small-39")).expect("copy should exist"),
+            "file 39"
+        );
+        assert_eq!(
+            fs::metadata(destination.join("tree").join("large.bin"))
+                .expect("large copy should exist")
+                .len(),
+            2 * 1024 * 1024
+        );
+        let copied_metadata = fs::metadata(&copied_nested).expect("copied dir should exist");
+        assert_eq!(
+            FileTime::from_last_modification_time(&copied_metadata),
+            directory_mtime,
+            "directory times must be set after their children were written"
         );
+        assert_eq!(copied_metadata.permissions().mode() & 0o777, 0o555);
 
-        let content_original =
-            fs::read_to_string(&destination_file).expect("original destination should be readable");
-        assert_eq!(content_original, "destination");
+        fs::set_permissions(&nested, fs::Permissions::from_mode(0o755))
+            .expect("source dir should become writable");
+        fs::set_permissions(&copied_nested, fs::Permissions::from_mode(0o755))
+            .expect("copied dir should become writable");
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
 
-        let renamed_file = destination.join("demo.txt.copy");
-        let content_renamed =
-            fs::read_to_string(&renamed_file).expect("renamed destination should be readable");
-        assert_eq!(content_renamed, "source");
+    #[test]
+    fn parallel_copy_reports_the_earliest_failure_in_walk_order() {
+        let (_update_tx, updates) = mpsc::channel();
+        let stop = AtomicBool::new(false);
+        let mut pipeline = CopyPipeline {
+            task_tx: None,
+            updates,
+            stop: &stop,
+            next_index: 0,
+            first_error: None,
+            directories: Vec::new(),
+        };
+        pipeline.fail(5, io::Error::other("fifth"));
+        assert!(pipeline.is_stopped());
+        pipeline.fail(2, io::Error::other("second"));
+        pipeline.fail(9, io::Error::other("ninth"));
+        let (index, error) = pipeline.first_error.expect("an error should be kept");
+        assert_eq!((index, error.to_string()), (2, String::from("second")));
+    }
+
+    #[test]
+    fn failed_copy_records_finished_sources_for_retry() {
+        let root = make_temp_dir("retry-sources");
+        let present = root.join("present.txt");
+        fs::write(&present, "present").expect("source should be writable");
+        // Copying a directory into itself fails after the first source.
+        let tree = root.join("tree");
+        let destination = tree.join("inner");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+
+        let mut manager = JobManager::new();
+        let job = manager.enqueue(JobRequest::Copy {
+            sources: vec![present.clone(), tree.clone()],
+            destination_dir: destination,
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        });
+        let job_id = job.id;
+        let (event_tx, event_rx) = mpsc::channel();
+        execute_worker_job(job, &event_tx);
+        drop(event_tx);
+        for event in event_rx.iter() {
+            manager.handle_event(&event);
+        }
+
+        let record = manager.job(job_id).expect("job should be tracked");
+        assert_eq!(record.status, JobStatus::Failed);
+        assert_eq!(record.completed_sources, vec![present]);
+        assert_eq!(
+            record.claimed_destination,
+            Some(ClaimedDestination {
+                source: tree.clone(),
+                destination: tree.join("inner").join("tree"),
+            }),
+            "the free destination of the failed source should be claimed"
+        );
+        let retry = record
+            .retry_request
+            .as_ref()
+            .and_then(|request| request.without_sources(&record.completed_sources));
+        assert!(matches!(
+            retry,
+            Some(JobRequest::Copy { ref sources, .. }) if sources == &vec![tree]
+        ));
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[test]
+    fn without_sources_keeps_custom_move_names_aligned() {
+        let request = JobRequest::Move {
+            sources: vec![
+                PathBuf::from("/a"),
+                PathBuf::from("/b"),
+                PathBuf::from("/c"),
+            ],
+            destination_dir: PathBuf::from("/dest"),
+            overwrite: OverwritePolicy::Skip,
+            destination_names: Some(vec![
+                String::from("A"),
+                String::from("B"),
+                String::from("C"),
+            ]),
+            verify: false,
+            preserve: PreserveLevel::Basic,
+        };
+        let Some(JobRequest::Move {
+            sources,
+            destination_names,
+            ..
+        }) = request.without_sources(&[PathBuf::from("/b")])
+        else {
+            panic!("a move with pending sources should remain");
+        };
+        assert_eq!(sources, vec![PathBuf::from("/a"), PathBuf::from("/c")]);
+        assert_eq!(
+            destination_names,
+            Some(vec![String::from("A"), String::from("C")])
+        );
+        assert_eq!(
+            JobRequest::Delete {
+                targets: vec![PathBuf::from("/a")],
+            }
+            .without_sources(&[PathBuf::from("/a")]),
+            None
+        );
+        assert!(
+            !JobRequest::Mkdir {
+                path: PathBuf::from("/a"),
+            }
+            .is_retryable()
+        );
+    }
+
+    #[test]
+    fn plan_request_previews_conflicts_without_touching_the_filesystem() {
+        let root = make_temp_dir("plan");
+        let source_dir = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        fs::write(source_dir.join("new.txt"), "new").expect("source should be writable");
+        fs::write(source_dir.join("taken.txt"), "taken").expect("source should be writable");
+        fs::write(destination.join("taken.txt"), "old").expect("conflict should be writable");
+
+        let copy = |overwrite| JobRequest::Copy {
+            sources: vec![
+                source_dir.join("new.txt"),
+                source_dir.join("taken.txt"),
+                source_dir.join("missing.txt"),
+            ],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        };
+        let cancel_flag = AtomicBool::new(false);
+        let plan = plan_request(&copy(OverwritePolicy::Skip), &cancel_flag)
+            .expect("copy should be plannable");
+        assert_eq!(
+            plan.steps
+                .iter()
+                .map(|step| step.action.clone())
+                .collect::<Vec<_>>(),
+            vec![PlannedAction::Create, PlannedAction::Skip]
+        );
+        assert_eq!((plan.items, plan.bytes), (2, 8));
+        assert!(
+            plan.warnings
+                .iter()
+                .any(|warning| warning.contains("1 destination(s) already exist")),
+            "{:?}",
+            plan.warnings
+        );
+        assert!(
+            plan.warnings
+                .iter()
+                .any(|warning| warning.contains("missing.txt")),
+            "{:?}",
+            plan.warnings
+        );
 
-        command_tx
-            .send(WorkerCommand::Shutdown)
-            .expect("shutdown should send");
-        worker
-            .join()
-            .expect("worker thread should terminate cleanly");
-        fs::remove_dir_all(&root).expect("temp tree should be removable");
+        let plan = plan_request(&copy(OverwritePolicy::Rename), &cancel_flag)
+            .expect("copy should be plannable");
+        assert!(matches!(
+            &plan.steps[1].action,
+            PlannedAction::RenameTo(target) if target != &destination.join("taken.txt")
+        ));
+        let plan = plan_request(
+            &JobRequest::Delete {
+                targets: vec![source_dir.join("new.txt")],
+            },
+            &cancel_flag,
+        )
+        .expect("delete should be plannable");
+        assert_eq!(plan.steps[0].action, PlannedAction::Delete);
+
+        assert!(!destination.join("new.txt").exists());
+        assert!(source_dir.join("new.txt").exists());
+        assert_eq!(
+            fs::read_to_string(destination.join("taken.txt")).expect("conflict should remain"),
+            "old"
+        );
+        let _ = fs::remove_dir_all(root);
     }
 
     #[test]
@@ -2918,7 +7706,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file],
             destination_dir: destination.clone(),
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -2968,7 +7761,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file],
             destination_dir: destination.clone(),
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -3019,7 +7817,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_dir.join("link.txt")],
             destination_dir: destination.clone(),
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -3047,6 +7850,199 @@ mod tests {
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn copy_follows_links_by_mode_and_copies_loops_as_links() {
+        use std::os::unix::fs::symlink;
+
+        let root = make_temp_dir("follow-links");
+        let source_dir = root.join("source");
+        let outside = root.join("outside");
+        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::create_dir_all(&outside).expect("outside dir should exist");
+        fs::write(source_dir.join("file.txt"), "file").expect("file should exist");
+        fs::write(outside.join("inner.txt"), "inner").expect("inner file should exist");
+        symlink("../outside", source_dir.join("sub")).expect("dir link should be creatable");
+        symlink(".", source_dir.join("loop")).expect("loop link should be creatable");
+        symlink("source", root.join("top")).expect("top link should be creatable");
+
+        let copy = |label: &str, follow_links: FollowLinks, workers: usize| {
+            let destination = root.join(label);
+            fs::create_dir_all(&destination).expect("destination dir should exist");
+            let mut manager = JobManager::new();
+            let mut job = manager.enqueue(JobRequest::Copy {
+                sources: vec![root.join("top")],
+                destination_dir: destination.clone(),
+                destination_names: None,
+                overwrite: OverwritePolicy::Skip,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+                follow_links,
+                hard_links: false,
+            });
+            job.set_copy_options(CopyOptions {
+                resume: false,
+                workers,
+            });
+            let id = job.id;
+            let (event_tx, event_rx) = mpsc::channel();
+            execute_worker_job_with_backend(job, &event_tx, &LocalFsBackend);
+            let finished = recv_until_finished(&event_rx, &mut manager);
+            assert!(
+                matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
+                "{label}: copy should succeed: {finished:?}"
+            );
+            let record = manager.job(id).expect("job should be tracked").clone();
+            (destination.join("top"), record)
+        };
+
+        let (top, record) = copy("never", FollowLinks::Never, 0);
+        assert!(
+            fs::symlink_metadata(&top)
+                .expect("copy exists")
+                .is_symlink()
+        );
+        assert!(!record.summary.contains("followed"), "{}", record.summary);
+
+        let (top, record) = copy("command-line", FollowLinks::CommandLine, 0);
+        assert!(fs::symlink_metadata(&top).expect("copy exists").is_dir());
+        assert!(
+            fs::symlink_metadata(top.join("sub"))
+                .expect("sub exists")
+                .is_symlink()
+        );
+        assert!(
+            record.summary.ends_with(", 1 link(s) followed"),
+            "{}",
+            record.summary
+        );
+
+        for (label, workers) in [("always", 0), ("always-parallel", 2)] {
+            let (top, record) = copy(label, FollowLinks::Always, workers);
+            assert_eq!(
+                fs::read_to_string(top.join("sub").join("inner.txt"))
+                    .expect("followed directory should be copied"),
+                "inner"
+            );
+            assert_eq!(
+                fs::read_to_string(top.join("file.txt")).expect("file should be copied"),
+                "file"
+            );
+            assert!(
+                fs::symlink_metadata(top.join("loop"))
+                    .expect("loop exists")
+                    .is_symlink(),
+                "{label}: a link back into the tree should stay a link"
+            );
+            assert!(
+                record.summary.contains("follow links always")
+                    && record.summary.ends_with(", 2 link(s) followed"),
+                "{label}: {}",
+                record.summary
+            );
+            assert_eq!(record.warnings.len(), 1, "{label}: {:?}", record.warnings);
+            assert!(record.warnings[0].contains("leads back into the copied tree"));
+        }
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_keeps_hard_links_and_counts_their_bytes_as_linked() {
+        use std::os::unix::fs::MetadataExt as _;
+
+        let root = make_temp_dir("copy-hard-links");
+        let source = root.join("snapshot");
+        fs::create_dir_all(source.join("a")).expect("source dirs should exist");
+        fs::create_dir_all(source.join("b")).expect("source dirs should exist");
+        fs::write(source.join("a/object"), "0123456789").expect("object should be writable");
+        fs::hard_link(source.join("a/object"), source.join("b/object"))
+            .expect("hard link should be creatable");
+        fs::hard_link(source.join("a/object"), source.join("b/again"))
+            .expect("hard link should be creatable");
+        fs::write(source.join("b/single"), "single").expect("file should be writable");
+
+        let copy = |label: &str, hard_links: bool, workers: usize| {
+            let destination = root.join(label);
+            fs::create_dir_all(&destination).expect("destination dir should exist");
+            let mut manager = JobManager::new();
+            let mut job = manager.enqueue(JobRequest::Copy {
+                sources: vec![source.clone()],
+                destination_dir: destination.clone(),
+                destination_names: None,
+                overwrite: OverwritePolicy::Skip,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+                follow_links: FollowLinks::Never,
+                hard_links,
+            });
+            job.set_copy_options(CopyOptions {
+                resume: false,
+                workers,
+            });
+            let id = job.id;
+            let (event_tx, event_rx) = mpsc::channel();
+            execute_worker_job_with_backend(job, &event_tx, &LocalFsBackend);
+            let mut bytes_linked = 0;
+            let finished = loop {
+                let event = event_rx
+                    .recv_timeout(Duration::from_secs(1))
+                    .expect("worker should emit job events");
+                manager.handle_event(&event);
+                match event {
+                    JobEvent::Progress { progress, .. } => bytes_linked = progress.bytes_linked,
+                    JobEvent::Finished { .. } => break event,
+                    _ => {}
+                }
+            };
+            assert!(
+                matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
+                "{label}: copy should succeed: {finished:?}"
+            );
+            let record = manager.job(id).expect("job should be tracked").clone();
+            (destination.join("snapshot"), record, bytes_linked)
+        };
+        let inode = |path: PathBuf| fs::metadata(path).expect("copy should exist").ino();
+
+        let (copied, record, bytes_linked) = copy("plain", false, 0);
+        assert_ne!(
+            inode(copied.join("a/object")),
+            inode(copied.join("b/object"))
+        );
+        assert_eq!(bytes_linked, 0);
+        assert!(
+            !record.summary.contains("hard-linked"),
+            "{}",
+            record.summary
+        );
+
+        for (label, workers) in [("linked", 0), ("linked-parallel", 4)] {
+            let (copied, record, bytes_linked) = copy(label, true, workers);
+            let first = inode(copied.join("a/object"));
+            assert_eq!(inode(copied.join("b/object")), first, "{label}");
+            assert_eq!(inode(copied.join("b/again")), first, "{label}");
+            assert_ne!(
+                inode(copied.join("a/object")),
+                inode(source.join("a/object"))
+            );
+            assert_ne!(inode(copied.join("b/single")), first, "{label}");
+            assert_eq!(
+                fs::read_to_string(copied.join("b/again")).expect("link should be readable"),
+                "0123456789"
+            );
+            assert_eq!(bytes_linked, 20, "{label}: two links of ten bytes");
+            assert!(
+                record.summary.contains("[skip, hard links]")
+                    && record.summary.ends_with(", 20 byte(s) hard-linked"),
+                "{label}: {}",
+                record.summary
+            );
+        }
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
     #[test]
     fn batch_move_rejects_destination_that_is_another_source_before_mutating() {
         let root = make_temp_dir("move-target-source-collision");
@@ -3062,6 +8058,8 @@ mod tests {
                 destination_dir: root.clone(),
                 destination_names: Some(vec![String::from("a.bak"), String::from("a.bak.bak")]),
                 overwrite: OverwritePolicy::Overwrite,
+                verify: false,
+                preserve: PreserveLevel::Basic,
             },
             &LocalFsBackend,
         );
@@ -3096,6 +8094,55 @@ mod tests {
         fs::remove_dir_all(&root).expect("temp tree should be removable");
     }
 
+    #[test]
+    fn batch_move_swaps_and_rotates_names_through_temporary_names() {
+        let root = make_temp_dir("move-name-cycles");
+        for (name, payload) in [("a", "A"), ("b", "B"), ("x", "X"), ("y", "Y"), ("z", "Z")] {
+            fs::write(root.join(name), payload).expect("source should exist");
+        }
+
+        let (finished, counts) = execute_request_with_backend(
+            JobRequest::Move {
+                sources: ["a", "b", "x", "y", "z"]
+                    .iter()
+                    .map(|name| root.join(name))
+                    .collect(),
+                destination_dir: root.clone(),
+                destination_names: Some(
+                    ["b", "a", "y", "z", "x"]
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect(),
+                ),
+                overwrite: OverwritePolicy::Skip,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+            },
+            &LocalFsBackend,
+        );
+
+        assert!(
+            matches!(finished, JobEvent::Finished { result: Ok(()), .. }),
+            "cycles of renames should succeed: {finished:?}"
+        );
+        assert_eq!(counts.failed, 0);
+        for (name, payload) in [("a", "B"), ("b", "A"), ("x", "Z"), ("y", "X"), ("z", "Y")] {
+            assert_eq!(
+                fs::read_to_string(root.join(name)).expect("renamed file should exist"),
+                payload
+            );
+        }
+        assert_eq!(
+            fs::read_dir(&root)
+                .expect("temp tree should be listable")
+                .count(),
+            5,
+            "no temporary names should be left behind"
+        );
+
+        fs::remove_dir_all(&root).expect("temp tree should be removable");
+    }
+
     #[test]
     fn batch_move_rejects_duplicate_destinations_before_mutating() {
         let root = make_temp_dir("move-duplicate-destination");
@@ -3114,6 +8161,8 @@ mod tests {
                     String::from("renamed.txt"),
                 ]),
                 overwrite: OverwritePolicy::Overwrite,
+                verify: false,
+                preserve: PreserveLevel::Basic,
             },
             &LocalFsBackend,
         );
@@ -3172,6 +8221,8 @@ mod tests {
                     destination_dir: destination_dir.clone(),
                     destination_names: Some(vec![invalid_name]),
                     overwrite: OverwritePolicy::Overwrite,
+                    verify: false,
+                    preserve: PreserveLevel::Basic,
                 },
                 &LocalFsBackend,
             );
@@ -3217,6 +8268,8 @@ mod tests {
                 destination_dir: root.clone(),
                 destination_names: None,
                 overwrite: OverwritePolicy::Skip,
+                verify: false,
+                preserve: PreserveLevel::Basic,
             },
             &LocalFsBackend,
         );
@@ -3259,6 +8312,8 @@ mod tests {
                     destination_dir: policy_root.clone(),
                     destination_names: Some(vec![String::from("FOO.txt")]),
                     overwrite,
+                    verify: false,
+                    preserve: PreserveLevel::Basic,
                 },
                 &LocalFsBackend,
             );
@@ -3309,6 +8364,8 @@ mod tests {
             destination_dir: source_root.clone(),
             destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(move_job)))
@@ -3355,6 +8412,8 @@ mod tests {
             destination_dir: source_root.clone(),
             destination_names: None,
             overwrite: OverwritePolicy::Overwrite,
+            verify: false,
+            preserve: PreserveLevel::Basic,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(move_job)))
@@ -3429,7 +8488,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file.clone()],
             destination_dir: copy_dest.clone(),
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -3449,6 +8513,8 @@ mod tests {
             destination_dir: move_dest.clone(),
             destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(move_job)))
@@ -3510,7 +8576,12 @@ mod tests {
         let copy_job = manager.enqueue(JobRequest::Copy {
             sources: vec![source_file],
             destination_dir: destination,
+            destination_names: None,
             overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
         });
         command_tx
             .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -3591,4 +8662,431 @@ mod tests {
             "elevated hint should be surfaced in user-facing message"
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn recursive_chmod_applies_separate_file_and_directory_masks() {
+        let root = make_temp_dir("chmod-recursive-masks");
+        let tree = root.join("tree");
+        let nested = tree.join("nested");
+        fs::create_dir_all(&nested).expect("nested dir should exist");
+        let file = nested.join("file.txt");
+        fs::write(&file, b"payload").expect("file should be writable");
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o640))
+            .expect("file mode should be settable");
+        fs::set_permissions(&nested, fs::Permissions::from_mode(0o700))
+            .expect("nested mode should be settable");
+        fs::set_permissions(&tree, fs::Permissions::from_mode(0o700))
+            .expect("tree mode should be settable");
+
+        let (finished, counts) = execute_request_with_backend(
+            JobRequest::Chmod {
+                targets: vec![tree.clone()],
+                file_mask: PermissionMask::new(0o044, 0),
+                dir_mask: PermissionMask::new(0o055, 0),
+                recursive: true,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(counts.succeeded, 1);
+
+        let mode_of = |path: &Path| {
+            fs::symlink_metadata(path)
+                .expect("metadata should be readable")
+                .permissions()
+                .mode()
+                & 0o7777
+        };
+        assert_eq!(mode_of(&tree), 0o755);
+        assert_eq!(mode_of(&nested), 0o755);
+        assert_eq!(mode_of(&file), 0o644);
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn recursive_chmod_updates_children_before_locking_directory() {
+        let root = make_temp_dir("chmod-recursive-lock");
+        let tree = root.join("tree");
+        fs::create_dir_all(&tree).expect("tree should exist");
+        let file = tree.join("file.txt");
+        fs::write(&file, b"payload").expect("file should be writable");
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o666))
+            .expect("file mode should be settable");
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::Chmod {
+                targets: vec![tree.clone()],
+                file_mask: PermissionMask::new(0, 0o022),
+                dir_mask: PermissionMask::new(0, 0o577),
+                recursive: true,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+
+        let tree_mode = fs::symlink_metadata(&tree)
+            .expect("tree metadata should be readable")
+            .permissions()
+            .mode()
+            & 0o7777;
+        assert_eq!(tree_mode, 0o200);
+        fs::set_permissions(&tree, fs::Permissions::from_mode(0o755))
+            .expect("tree mode should be restorable");
+        let file_mode = fs::symlink_metadata(&file)
+            .expect("file metadata should be readable")
+            .permissions()
+            .mode()
+            & 0o7777;
+        assert_eq!(file_mode, 0o644);
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn chown_applies_owner_and_advanced_mode_to_tree() {
+        use std::os::unix::fs::MetadataExt;
+
+        let root = make_temp_dir("chown-tree");
+        let tree = root.join("tree");
+        fs::create_dir_all(&tree).expect("tree should exist");
+        let file = tree.join("file.txt");
+        fs::write(&file, b"payload").expect("file should be writable");
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o600))
+            .expect("file mode should be settable");
+        let metadata = fs::metadata(&file).expect("file metadata should be readable");
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::Chown {
+                targets: vec![tree.clone()],
+                owner: Some(metadata.uid()),
+                group: Some(metadata.gid()),
+                file_mask: PermissionMask::new(0o040, 0),
+                dir_mask: PermissionMask::new(0o050, 0),
+                recursive: true,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        let file_metadata = fs::metadata(&file).expect("file metadata should be readable");
+        assert_eq!(file_metadata.uid(), metadata.uid());
+        assert_eq!(file_metadata.permissions().mode() & 0o7777, 0o640);
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[test]
+    fn denied_entries_are_reported_per_path_without_stopping() {
+        let mut denied = DeniedEntries::default();
+        let first = PathBuf::from("/tmp/first");
+        let second = PathBuf::from("/tmp/second");
+        let denied_error = || io::Error::from(io::ErrorKind::PermissionDenied);
+
+        assert!(
+            !denied
+                .record(&first, Err(denied_error()))
+                .expect("permission errors should be collected")
+        );
+        assert!(
+            !denied
+                .record(&first, Err(denied_error()))
+                .expect("permission errors should be collected")
+        );
+        assert!(denied.record(&second, Ok(())).expect("success should pass"));
+        assert!(
+            !denied
+                .record(&second, Err(denied_error()))
+                .expect("permission errors should be collected")
+        );
+        assert!(
+            denied
+                .record(&second, Err(io::Error::from(io::ErrorKind::NotFound)))
+                .is_err()
+        );
+
+        let error = denied
+            .into_result()
+            .expect_err("denied entries should fail the job");
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        let message = error.to_string();
+        assert!(message.starts_with("2 entries not changed: "), "{message}");
+        assert!(message.contains("/tmp/first: "), "{message}");
+        assert!(message.contains("/tmp/second: "), "{message}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn chown_reports_denied_entries_and_keeps_going() {
+        use std::os::unix::fs::MetadataExt;
+
+        if nix::unistd::Uid::effective().is_root() {
+            return;
+        }
+        let root = make_temp_dir("chown-denied");
+        let first = root.join("first.txt");
+        let second = root.join("second.txt");
+        fs::write(&first, b"first").expect("first file should be writable");
+        fs::write(&second, b"second").expect("second file should be writable");
+        fs::set_permissions(&second, fs::Permissions::from_mode(0o600))
+            .expect("second mode should be settable");
+        let owner = fs::metadata(&first)
+            .expect("metadata should be readable")
+            .uid();
+
+        let (finished, counts) = execute_request_with_backend(
+            JobRequest::Chown {
+                targets: vec![first.clone(), second.clone()],
+                owner: Some(owner.wrapping_add(1)),
+                group: None,
+                file_mask: PermissionMask::new(0o040, 0),
+                dir_mask: PermissionMask::new(0o040, 0),
+                recursive: false,
+            },
+            &RecordingFsBackend::default(),
+        );
+        let JobEvent::Finished {
+            result: Err(error), ..
+        } = finished
+        else {
+            panic!("denied chown should fail the job");
+        };
+        assert_eq!(error.code, JobErrorCode::PermissionDenied);
+        assert!(error.message.contains("first.txt"), "{}", error.message);
+        assert!(error.message.contains("second.txt"), "{}", error.message);
+        assert_eq!(counts.failed, 1);
+        let second_mode = fs::metadata(&second)
+            .expect("metadata should be readable")
+            .permissions()
+            .mode();
+        assert_eq!(second_mode & 0o7777, 0o640);
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[test]
+    fn relative_path_walks_up_to_common_ancestor() {
+        assert_eq!(
+            relative_path(
+                Path::new("/data/links/nested"),
+                Path::new("/data/files/a.txt")
+            ),
+            PathBuf::from("../../files/a.txt")
+        );
+        assert_eq!(
+            relative_path(Path::new("/data"), Path::new("/data/a.txt")),
+            PathBuf::from("a.txt")
+        );
+        assert_eq!(
+            relative_path(Path::new("/data"), Path::new("/data")),
+            PathBuf::from(".")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_symlinks_survive_moving_the_tree() {
+        let root = make_temp_dir("symlink-relative");
+        let tree = root.join("tree");
+        let files = tree.join("files");
+        let links = tree.join("links");
+        fs::create_dir_all(&files).expect("files dir should exist");
+        fs::create_dir_all(&links).expect("links dir should exist");
+        let source = files.join("a.txt");
+        fs::write(&source, b"payload").expect("source should be writable");
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::Symlink {
+                sources: vec![source.clone()],
+                destination_dir: links.clone(),
+                relative: true,
+                overwrite: OverwritePolicy::Skip,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(
+            fs::read_link(links.join("a.txt")).expect("link should be readable"),
+            PathBuf::from("../files/a.txt")
+        );
+
+        let moved = root.join("moved");
+        fs::rename(&tree, &moved).expect("tree should be movable");
+        assert_eq!(
+            fs::read(moved.join("links/a.txt")).expect("moved link should resolve"),
+            b"payload"
+        );
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn link_jobs_follow_overwrite_policy_on_conflicts() {
+        use std::os::unix::fs::MetadataExt;
+
+        let root = make_temp_dir("link-conflicts");
+        let source_dir = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source_dir).expect("source dir should exist");
+        fs::create_dir_all(&destination).expect("destination dir should exist");
+        let source = source_dir.join("a.txt");
+        fs::write(&source, b"source").expect("source should be writable");
+        fs::write(destination.join("a.txt"), b"existing").expect("conflict should be writable");
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::Link {
+                sources: vec![source.clone()],
+                destination_dir: destination.clone(),
+                overwrite: OverwritePolicy::Skip,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(
+            fs::read(destination.join("a.txt")).expect("conflict should remain"),
+            b"existing"
+        );
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::Link {
+                sources: vec![source.clone()],
+                destination_dir: destination.clone(),
+                overwrite: OverwritePolicy::Rename,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        let renamed = destination.join("a.txt.copy");
+        assert_eq!(
+            fs::metadata(&renamed)
+                .expect("renamed link should exist")
+                .ino(),
+            fs::metadata(&source).expect("source should exist").ino()
+        );
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::Symlink {
+                sources: vec![source.clone()],
+                destination_dir: destination.clone(),
+                relative: false,
+                overwrite: OverwritePolicy::Overwrite,
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(
+            fs::read_link(destination.join("a.txt")).expect("overwritten link should exist"),
+            source
+        );
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn edit_symlink_replaces_link_and_leaves_no_staging_entry() {
+        let root = make_temp_dir("edit-symlink");
+        fs::write(root.join("old.txt"), b"old").expect("old target should be writable");
+        fs::write(root.join("new.txt"), b"new").expect("new target should be writable");
+        let link = root.join("current");
+        std::os::unix::fs::symlink("old.txt", &link).expect("link should be creatable");
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::EditSymlink {
+                link: link.clone(),
+                target: PathBuf::from("new.txt"),
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(
+            fs::read_link(&link).expect("link should remain a symlink"),
+            PathBuf::from("new.txt")
+        );
+        let mut names: Vec<String> = fs::read_dir(&root)
+            .expect("root should be listable")
+            .map(|entry| {
+                entry
+                    .expect("entry should be readable")
+                    .file_name()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["current", "new.txt", "old.txt"]);
+
+        let (finished, _) = execute_request_with_backend(
+            JobRequest::EditSymlink {
+                link: root.join("old.txt"),
+                target: PathBuf::from("new.txt"),
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Err(_), .. }
+        ));
+        assert_eq!(
+            fs::read(root.join("old.txt")).expect("regular file should be untouched"),
+            b"old"
+        );
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
+
+    #[test]
+    fn purge_trash_removes_payload_tree_and_info_file() {
+        let root = make_temp_dir("purge-trash");
+        let trash = Trash::with_home(root.join("Trash"));
+        let victim = root.join("victim");
+        fs::create_dir_all(victim.join("nested")).expect("victim tree should be creatable");
+        fs::write(victim.join("nested/file.txt"), b"data").expect("file should be writable");
+        let payload = trash.trash(&victim).expect("victim should be trashed");
+        let info_path = root.join("Trash/info/victim.trashinfo");
+
+        let (finished, counts) = execute_request_with_backend(
+            JobRequest::PurgeTrash {
+                items: vec![info_path.clone()],
+            },
+            &RecordingFsBackend::default(),
+        );
+        assert!(matches!(
+            finished,
+            JobEvent::Finished { result: Ok(()), .. }
+        ));
+        assert_eq!(counts.succeeded, 1);
+        assert!(fs::symlink_metadata(&payload).is_err());
+        assert!(!info_path.exists());
+        assert!(trash.list().expect("trash should be listable").is_empty());
+
+        fs::remove_dir_all(&root).expect("temp root should be removable");
+    }
 }
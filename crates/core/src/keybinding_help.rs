@@ -479,6 +479,74 @@ impl AppState {
             "jobs_cancel",
             self.keybinding_joined_or_fallback(KeyContext::Jobs, AppCommand::CancelJob, "Alt-J", 1),
         );
+        replacements.insert(
+            "jobs_limit",
+            self.keybinding_primary_or_fallback(
+                KeyContext::Jobs,
+                AppCommand::OpenJobBandwidthLimit,
+                "l",
+            ),
+        );
+        replacements.insert(
+            "jobs_limit_all",
+            self.keybinding_primary_or_fallback(
+                KeyContext::Jobs,
+                AppCommand::OpenGlobalBandwidthLimit,
+                "Shift-L",
+            ),
+        );
+        replacements.insert(
+            "jobs_pause",
+            self.keybinding_primary_or_fallback(KeyContext::Jobs, AppCommand::ToggleJobPause, "p"),
+        );
+        replacements.insert(
+            "jobs_pause_all",
+            self.keybinding_primary_or_fallback(
+                KeyContext::Jobs,
+                AppCommand::TogglePauseAllJobs,
+                "Shift-P",
+            ),
+        );
+        replacements.insert(
+            "jobs_retry",
+            self.keybinding_primary_or_fallback(KeyContext::Jobs, AppCommand::RetryJob, "r"),
+        );
+        replacements.insert(
+            "jobs_retry_failed",
+            self.keybinding_primary_or_fallback(
+                KeyContext::Jobs,
+                AppCommand::RetryFailedItems,
+                "Shift-R",
+            ),
+        );
+        replacements.insert(
+            "jobs_skipped",
+            self.keybinding_primary_or_fallback(
+                KeyContext::Jobs,
+                AppCommand::ShowSkippedItems,
+                "s",
+            ),
+        );
+        replacements.insert(
+            "jobs_reorder",
+            format!(
+                "{}/{}",
+                self.keybinding_primary_or_fallback(
+                    KeyContext::Jobs,
+                    AppCommand::MoveJobUp,
+                    "Shift-Up",
+                ),
+                self.keybinding_primary_or_fallback(
+                    KeyContext::Jobs,
+                    AppCommand::MoveJobDown,
+                    "Shift-Down",
+                ),
+            ),
+        );
+        replacements.insert(
+            "jobs_run_next",
+            self.keybinding_primary_or_fallback(KeyContext::Jobs, AppCommand::RunJobNext, "n"),
+        );
         replacements.insert(
             "jobs_close",
             self.keybinding_joined_or_fallback(
@@ -10,6 +10,7 @@ pub enum KeyContext {
     FindResults,
     Tree,
     Hotlist,
+    Trash,
     Dialog,
     Input,
     FindDialog,
@@ -45,6 +46,7 @@ impl KeyContext {
             "find" | "findresults" => Some(Self::FindResults),
             "tree" => Some(Self::Tree),
             "hotlist" => Some(Self::Hotlist),
+            "trash" => Some(Self::Trash),
             "dialog" => Some(Self::Dialog),
             "input" => Some(Self::Input),
             "finddialog" => Some(Self::FindDialog),
@@ -137,10 +139,30 @@ pub enum KeyCommand {
     OpenPanelFilter,
     SortNext,
     SortReverse,
+    ChangeMode,
+    ChangeOwn,
+    ChangeOwnAdvanced,
+    Link,
+    LinkSymbolic,
+    LinkSymbolicRelative,
+    LinkSymbolicEdit,
     Copy,
     Move,
     Delete,
+    DeletePermanently,
+    Undo,
+    BulkRename,
     CancelJob,
+    LimitJobBandwidth,
+    LimitAllBandwidth,
+    PauseJob,
+    PauseAllJobs,
+    RetryJob,
+    RetryFailedItems,
+    ShowSkippedItems,
+    MoveJobUp,
+    MoveJobDown,
+    RunJobNext,
     OpenJobs,
     CloseJobs,
     OpenFindDialog,
@@ -152,6 +174,9 @@ pub enum KeyCommand {
     AddHotlist,
     EditHotlist,
     RemoveHotlist,
+    OpenTrash,
+    RestoreTrash,
+    PurgeTrash,
     OpenConfirmDialog,
     OpenInputDialog,
     OpenListboxDialog,
@@ -230,10 +255,30 @@ impl KeyCommand {
             "filter" => Self::OpenPanelFilter,
             "sortnext" => Self::SortNext,
             "sortreverse" => Self::SortReverse,
+            "changemode" | "chmod" => Self::ChangeMode,
+            "changeown" | "chown" => Self::ChangeOwn,
+            "changeownadvanced" | "advancedchown" => Self::ChangeOwnAdvanced,
+            "link" | "hardlink" => Self::Link,
+            "linksymbolic" | "symlink" => Self::LinkSymbolic,
+            "linksymbolicrelative" | "relativesymlink" => Self::LinkSymbolicRelative,
+            "linksymbolicedit" | "editsymlink" => Self::LinkSymbolicEdit,
             "copy" | "filecopy" => Self::Copy,
             "move" | "renmov" | "rename" => Self::Move,
             "delete" | "filedelete" | "remove" => Self::Delete,
+            "deletepermanently" | "deletepermanent" => Self::DeletePermanently,
+            "undo" | "undolast" | "undooperation" => Self::Undo,
+            "bulkrename" | "renamebulk" | "editnames" => Self::BulkRename,
             "canceljob" | "jobcancel" => Self::CancelJob,
+            "limitjobbandwidth" | "throttlejob" => Self::LimitJobBandwidth,
+            "limitallbandwidth" | "throttleall" => Self::LimitAllBandwidth,
+            "pausejob" | "resumejob" => Self::PauseJob,
+            "pausealljobs" | "resumealljobs" => Self::PauseAllJobs,
+            "retryjob" => Self::RetryJob,
+            "retryfaileditems" | "retryfailed" => Self::RetryFailedItems,
+            "showskippeditems" | "skippeditems" => Self::ShowSkippedItems,
+            "movejobup" | "jobup" => Self::MoveJobUp,
+            "movejobdown" | "jobdown" => Self::MoveJobDown,
+            "runjobnext" | "jobnext" => Self::RunJobNext,
             "openjobs" | "jobsopen" => Self::OpenJobs,
             "jobs" => Self::OpenJobs,
             "closejobs" | "jobsclose" => Self::CloseJobs,
@@ -248,6 +293,9 @@ impl KeyCommand {
             "addhotlist" | "hotlistadd" => Self::AddHotlist,
             "edithotlist" | "hotlistedit" => Self::EditHotlist,
             "removehotlist" | "hotlistremove" | "deletehotlist" => Self::RemoveHotlist,
+            "trash" | "opentrash" | "trashbrowser" => Self::OpenTrash,
+            "restoretrash" | "trashrestore" => Self::RestoreTrash,
+            "purgetrash" | "trashpurge" => Self::PurgeTrash,
             "openconfirmdialog" | "democonfirmdialog" => Self::OpenConfirmDialog,
             "openinputdialog" | "demoinputdialog" | "makedir" | "mkdir" => Self::OpenInputDialog,
             "openlistboxdialog" | "demolistboxdialog" => Self::OpenListboxDialog,
@@ -1592,6 +1640,12 @@ Reread = ctrl-r
             "PanelInfo",
             "PanelQuickView",
             "CycleListingFormat",
+            "ChangeMode",
+            "ChangeOwn",
+            "Link",
+            "LinkSymbolic",
+            "LinkSymbolicRelative",
+            "LinkSymbolicEdit",
         ] {
             assert!(
                 !report
@@ -63,6 +63,8 @@ pub const TREE_WIDTH: u16 = 88;
 pub const TREE_HEIGHT: u16 = 28;
 pub const HOTLIST_WIDTH: u16 = 88;
 pub const HOTLIST_HEIGHT: u16 = 22;
+pub const TRASH_WIDTH: u16 = 96;
+pub const TRASH_HEIGHT: u16 = 24;
 
 pub const fn centered_overlay_rect(
     viewport: ScreenRect,
@@ -145,6 +147,10 @@ pub const fn hotlist_layout(viewport: ScreenRect) -> ListOverlayLayout {
     list_overlay_layout(viewport, HOTLIST_WIDTH, HOTLIST_HEIGHT, 0, 1)
 }
 
+pub const fn trash_layout(viewport: ScreenRect) -> ListOverlayLayout {
+    list_overlay_layout(viewport, TRASH_WIDTH, TRASH_HEIGHT, 1, 1)
+}
+
 pub const fn listbox_dialog_layout(viewport: ScreenRect, footer_height: u16) -> ListOverlayLayout {
     list_overlay_layout(
         viewport,
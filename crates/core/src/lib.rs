@@ -1,14 +1,24 @@
 #![forbid(unsafe_code)]
 
+pub mod accounts;
 mod background;
+mod bulk_rename;
+mod bulk_rename_flow;
 mod command_dispatch;
 mod command_map;
+pub mod copy_accel;
+mod copy_resume;
+pub mod copy_xattrs;
+mod destination_fs;
 pub mod dialog;
 mod dialog_flow;
 mod find_engine;
 mod find_flow;
 pub mod help;
 mod hotlist_flow;
+pub mod job_queue;
+mod job_queue_flow;
+mod job_scheduler;
 pub mod jobs;
 mod keybinding_help;
 pub mod keymap;
@@ -22,6 +32,7 @@ mod quick_cd;
 mod quick_cd_search;
 mod quick_view_flow;
 mod refresh_flow;
+pub mod rename_mask;
 mod route_flow;
 mod selection_size;
 mod selection_size_flow;
@@ -30,7 +41,16 @@ mod settings_flow;
 pub mod settings_io;
 pub mod slo;
 mod state_flow;
+#[cfg(test)]
+mod test_support;
+pub mod throttle;
+mod transfer_mask_flow;
+mod transfer_preview_flow;
+pub mod trash;
+mod trash_flow;
 mod tree;
+pub mod undo;
+mod undo_flow;
 mod viewer;
 mod viewer_flow;
 
@@ -42,24 +62,31 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, atomic::AtomicBool};
 use std::time::{Instant, SystemTime};
 
+pub use accounts::AccountEntry;
 pub use background::{
     BackgroundEvent, PanelRefreshResult, PanelRefreshStreamRequest, build_tree_ready_event,
     read_disk_usage, refresh_panel_entries, refresh_panel_event, stream_refresh_panel_entries,
 };
+pub use copy_xattrs::PreserveLevel;
 pub use dialog::{
-    DialogButtonFocus, DialogKind, DialogResult, DialogState, FilterDialogField, FilterDialogState,
-    FindDialogField, FindDialogState, PairInputDialogState, PairInputField, QuickCdDialogState,
-    QuickCdSearchStatus,
+    ChmodDialogField, ChmodDialogState, ChownDialogField, ChownDialogState, DialogButtonFocus,
+    DialogKind, DialogResult, DialogState, FilterDialogField, FilterDialogState, FindDialogField,
+    FindDialogState, MaskDialogField, MaskDialogState, OverwriteDialogState, PairInputDialogState,
+    PairInputField, QuickCdDialogState, QuickCdSearchStatus,
 };
 pub use find_engine::{
     FindNameMode, FindSearchError, FindSearchIssue, FindSearchIssueKind, FindSearchReport,
     FindSpec, run_find_entries, stream_find_entries,
 };
 pub use help::{HelpLine, HelpSpan, HelpState};
+pub use job_queue::{JobQueue, QueuedJob};
 pub use jobs::{
-    JOB_CANCELED_MESSAGE, JobError, JobErrorCode, JobEvent, JobId, JobKind, JobManager,
-    JobProgress, JobRecord, JobRequest, JobRetryHint, JobStatus, JobStatusCounts, OverwritePolicy,
-    WorkerCommand, WorkerJob, execute_worker_job, run_worker,
+    ClaimedDestination, ConflictEntry, CopyOptions, FollowLinks, ItemErrorAnswer, ItemFailure,
+    JOB_CANCELED_MESSAGE, JobError, JobErrorCode, JobEvent, JobId, JobKind, JobManager, JobPhase,
+    JobProgress, JobRecord, JobRequest, JobRetryHint, JobStatus, JobStatusCounts, OverwriteAnswer,
+    OverwriteConflict, OverwriteDecision, OverwritePolicy, PathFootprint, PathWait, PermissionMask,
+    PlannedAction, PlannedStep, RuntimeSlot, TransferPlan, WorkerCommand, WorkerJob,
+    execute_worker_job, plan_request, run_worker,
 };
 #[cfg(test)]
 use panel::read_entries;
@@ -77,8 +104,10 @@ pub use quick_cd_search::{
 };
 pub use quick_view_flow::QuickViewState;
 pub use rc_shell::{LocalProcessBackend, ProcessBackend, ProcessExit, ProcessOutputLimits};
+pub use rename_mask::RenameMask;
 pub use selection_size::{
     SELECTION_SIZE_CANCELED_MESSAGE, SelectionSizeReport, measure_selection_size,
+    measure_selection_size_pausable,
 };
 pub use selection_size_flow::SelectionSizeState;
 pub use settings::{
@@ -90,6 +119,8 @@ pub use settings::{
 pub use slo::{FOUNDATION_SLO, SloBudgets};
 #[cfg(test)]
 use std::sync::atomic::Ordering as AtomicOrdering;
+pub use trash::{Trash, TrashedItem};
+pub use trash_flow::TrashState;
 pub use tree::{
     TreeBuildResult, TreeEntry, TreeLoadState, TreeNavigationMode, TreeScanIssue, TreeScanSummary,
     TreeState,
@@ -97,8 +128,10 @@ pub use tree::{
 pub(crate) use tree::{
     TreeMutationTracker, TreeRescanPlan, TreeScanCompletion, build_tree_entries,
 };
+pub use undo::{UndoEntry, UndoJournal, UndoOperation, UndoRecord};
 pub use viewer::ViewerState;
 
+use crate::job_scheduler::QueueMove;
 use crate::keymap::{KeyChord, KeyCode, KeyContext, KeyModifiers, Keymap, KeymapParseReport};
 use crate::panel::read_entries_with_visibility;
 use crate::panel_filter::apply_panel_filter;
@@ -132,6 +165,8 @@ pub enum AppCommand {
     CloseTree,
     OpenHotlist,
     CloseHotlist,
+    OpenTrash,
+    CloseTrash,
     OpenPanelizeDialog,
     RestorePanelizedResults,
     PanelizePresetAdd,
@@ -153,7 +188,18 @@ pub enum AppCommand {
     Copy,
     Move,
     Delete,
+    DeletePermanently,
+    UndoLastOperation,
+    BulkRename,
     CancelJob,
+    ToggleJobPause,
+    TogglePauseAllJobs,
+    RetryJob,
+    RetryFailedItems,
+    ShowSkippedItems,
+    MoveJobUp,
+    MoveJobDown,
+    RunJobNext,
     OpenJobsScreen,
     CloseJobsScreen,
     OpenEntry,
@@ -181,10 +227,22 @@ pub enum AppCommand {
     HotlistEditSelected,
     HotlistRemoveSelected,
     HotlistSelectAt(usize),
+    TrashRestoreSelected,
+    TrashPurgeSelected,
+    TrashSelectAt(usize),
     OpenConfirmDialog,
     OpenInputDialog,
     OpenListboxDialog,
     OpenSkinDialog,
+    OpenJobBandwidthLimit,
+    OpenGlobalBandwidthLimit,
+    OpenChmodDialog,
+    OpenChownDialog,
+    OpenAdvancedChownDialog,
+    OpenLinkDialog,
+    OpenSymlinkDialog,
+    OpenRelativeSymlinkDialog,
+    OpenEditSymlinkDialog,
     OpenOptionsConfiguration,
     OpenOptionsLayout,
     OpenOptionsPanelOptions,
@@ -299,6 +357,7 @@ pub enum NavigationTarget {
     FindResults,
     Tree,
     Hotlist,
+    Trash,
     Viewer,
 }
 
@@ -349,6 +408,8 @@ impl AppCommand {
             | Self::CloseTree
             | Self::OpenHotlist
             | Self::CloseHotlist
+            | Self::OpenTrash
+            | Self::CloseTrash
             | Self::OpenPanelizeDialog
             | Self::PanelizePresetAdd
             | Self::PanelizePresetEdit
@@ -376,7 +437,8 @@ impl AppCommand {
                 NavigationTarget::FileManager
                 | NavigationTarget::FindResults
                 | NavigationTarget::Tree
-                | NavigationTarget::Hotlist => CommandDomain::Navigation,
+                | NavigationTarget::Hotlist
+                | NavigationTarget::Trash => CommandDomain::Navigation,
                 NavigationTarget::Viewer => CommandDomain::Viewer,
             },
             Self::ToggleTag
@@ -395,7 +457,18 @@ impl AppCommand {
             | Self::Copy
             | Self::Move
             | Self::Delete
+            | Self::DeletePermanently
+            | Self::UndoLastOperation
+            | Self::BulkRename
             | Self::CancelJob
+            | Self::ToggleJobPause
+            | Self::TogglePauseAllJobs
+            | Self::RetryJob
+            | Self::RetryFailedItems
+            | Self::ShowSkippedItems
+            | Self::MoveJobUp
+            | Self::MoveJobDown
+            | Self::RunJobNext
             | Self::RestorePanelizedResults
             | Self::OpenEntry
             | Self::EditEntry
@@ -422,7 +495,10 @@ impl AppCommand {
             | Self::HotlistAddCurrentDirectory
             | Self::HotlistEditSelected
             | Self::HotlistRemoveSelected
-            | Self::HotlistSelectAt(_) => CommandDomain::Navigation,
+            | Self::HotlistSelectAt(_)
+            | Self::TrashRestoreSelected
+            | Self::TrashPurgeSelected
+            | Self::TrashSelectAt(_) => CommandDomain::Navigation,
             Self::ViewerSearchForward
             | Self::ViewerSearchBackward
             | Self::ViewerSearchContinue
@@ -435,6 +511,15 @@ impl AppCommand {
             | Self::OpenQuickCd
             | Self::OpenListboxDialog
             | Self::OpenSkinDialog
+            | Self::OpenJobBandwidthLimit
+            | Self::OpenGlobalBandwidthLimit
+            | Self::OpenChmodDialog
+            | Self::OpenChownDialog
+            | Self::OpenAdvancedChownDialog
+            | Self::OpenLinkDialog
+            | Self::OpenSymlinkDialog
+            | Self::OpenRelativeSymlinkDialog
+            | Self::OpenEditSymlinkDialog
             | Self::OpenListingFormat
             | Self::OpenSortOrder
             | Self::OpenPanelFilter
@@ -604,22 +689,38 @@ const fn side_menu_entries(panel: ActivePanel) -> [MenuEntry; 16] {
 const LEFT_SIDE_MENU_ENTRIES: [MenuEntry; 16] = side_menu_entries(ActivePanel::Left);
 const RIGHT_SIDE_MENU_ENTRIES: [MenuEntry; 16] = side_menu_entries(ActivePanel::Right);
 
-const FILE_MENU_ENTRIES: [MenuEntry; 22] = [
+const FILE_MENU_ENTRIES: [MenuEntry; 26] = [
     MenuEntry::action_with_shortcut("View", "F3", AppCommand::OpenEntry),
     MenuEntry::stub("View file...", ""),
     MenuEntry::stub("Filtered view", "M-!"),
     MenuEntry::action_with_shortcut("Edit", "F4", AppCommand::EditEntry),
     MenuEntry::action_with_shortcut("Copy", "F5", AppCommand::Copy),
-    MenuEntry::stub("Chmod", "C-x c"),
-    MenuEntry::stub("Link", "C-x l"),
-    MenuEntry::stub("Symlink", "C-x s"),
-    MenuEntry::stub("Relative symlink", "C-x v"),
-    MenuEntry::stub("Edit symlink", "C-x C-s"),
-    MenuEntry::stub("Chown", "C-x o"),
-    MenuEntry::stub("Advanced chown", ""),
+    MenuEntry::action_with_literal_shortcut("Chmod", "C-x c", AppCommand::OpenChmodDialog),
+    MenuEntry::action_with_literal_shortcut("Link", "C-x l", AppCommand::OpenLinkDialog),
+    MenuEntry::action_with_literal_shortcut("Symlink", "C-x s", AppCommand::OpenSymlinkDialog),
+    MenuEntry::action_with_literal_shortcut(
+        "Relative symlink",
+        "C-x v",
+        AppCommand::OpenRelativeSymlinkDialog,
+    ),
+    MenuEntry::action_with_literal_shortcut(
+        "Edit symlink",
+        "C-x C-s",
+        AppCommand::OpenEditSymlinkDialog,
+    ),
+    MenuEntry::action_with_literal_shortcut("Chown", "C-x o", AppCommand::OpenChownDialog),
+    MenuEntry::action("Advanced chown", AppCommand::OpenAdvancedChownDialog),
     MenuEntry::action_with_shortcut("Rename/Move", "F6", AppCommand::Move),
     MenuEntry::action_with_shortcut("Mkdir", "F7", AppCommand::OpenInputDialog),
     MenuEntry::action_with_shortcut("Delete", "F8", AppCommand::Delete),
+    MenuEntry::action_with_shortcut("Delete permanently", "S-Del", AppCommand::DeletePermanently),
+    MenuEntry::action_with_literal_shortcut("Trash", "C-x t", AppCommand::OpenTrash),
+    MenuEntry::action_with_literal_shortcut(
+        "Undo last operation",
+        "C-x u",
+        AppCommand::UndoLastOperation,
+    ),
+    MenuEntry::action_with_literal_shortcut("Bulk rename", "C-x n", AppCommand::BulkRename),
     MenuEntry::action_with_shortcut("Quick cd", "M-c", AppCommand::OpenQuickCd),
     MenuEntry::separator(),
     MenuEntry::stub("Select group", "+"),
@@ -1711,7 +1812,12 @@ impl SettingsEntry {
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum SettingsEntryAction {
     CycleDefaultOverwritePolicy,
+    CyclePreserveLevel,
+    EditBandwidthLimit,
     ToggleMacosOptionSymbols,
+    ToggleResumeCopies,
+    ToggleVerifyCopies,
+    ToggleContinueOnError,
     ToggleLayoutShowMenuBar,
     ToggleLayoutShowButtonBar,
     ToggleLayoutShowDebugStatus,
@@ -1724,6 +1830,7 @@ enum SettingsEntryAction {
     ToggleConfirmOverwrite,
     ToggleConfirmQuit,
     ToggleConfirmHotlistDelete,
+    ToggleDeleteToTrash,
     OpenSkinDialog,
     ToggleUtf8Output,
     ToggleEightBitInput,
@@ -1739,6 +1846,46 @@ enum SettingsEntryAction {
 enum TransferKind {
     Copy,
     Move,
+    Link,
+    Symlink,
+    RelativeSymlink,
+}
+
+impl TransferKind {
+    fn title(self) -> &'static str {
+        match self {
+            Self::Copy => "Copy",
+            Self::Move => "Move",
+            Self::Link => "Link",
+            Self::Symlink => "Symlink",
+            Self::RelativeSymlink => "Relative symlink",
+        }
+    }
+}
+
+/// How a delete removes its targets; the delete dialog offers both.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DeleteMode {
+    Trash,
+    Permanent,
+}
+
+impl DeleteMode {
+    const ALL: [Self; 2] = [Self::Trash, Self::Permanent];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Trash => "Move to trash",
+            Self::Permanent => "Delete permanently",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|mode| *mode == self)
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -1767,6 +1914,19 @@ enum PendingDialogAction {
         targets: Vec<PathBuf>,
         origin: OperationOrigin,
     },
+    ConfirmPermanentDelete {
+        targets: Vec<PathBuf>,
+        origin: OperationOrigin,
+    },
+    ResolveOverwrite {
+        job_id: JobId,
+    },
+    ResolveItemError {
+        job_id: JobId,
+    },
+    ConfirmTrashPurge {
+        items: Vec<PathBuf>,
+    },
     ConfirmQuit,
     Mkdir {
         base_dir: PathBuf,
@@ -1775,6 +1935,20 @@ enum PendingDialogAction {
     RenameEntry {
         source: PathBuf,
     },
+    Chmod {
+        targets: Vec<PathBuf>,
+    },
+    Chown {
+        targets: Vec<PathBuf>,
+    },
+    EditSymlink {
+        link: PathBuf,
+        current_target: PathBuf,
+    },
+    ConfirmDanglingSymlink {
+        link: PathBuf,
+        target: PathBuf,
+    },
     TransferDestination {
         kind: TransferKind,
         sources: Vec<PathBuf>,
@@ -1788,15 +1962,32 @@ enum PendingDialogAction {
         sources: Vec<PathBuf>,
         destination_dir: PathBuf,
         destination_names: Vec<String>,
+        preserve: PreserveLevel,
+        follow_links: FollowLinks,
+        hard_links: bool,
         origin: OperationOrigin,
     },
     TransferOverwrite {
         kind: TransferKind,
         sources: Vec<PathBuf>,
         destination_dir: PathBuf,
+        preserve: PreserveLevel,
+        follow_links: FollowLinks,
+        hard_links: bool,
         origin: OperationOrigin,
     },
+    TransferMask {
+        sources: Vec<PathBuf>,
+        destination_dir: PathBuf,
+    },
     SetDefaultOverwritePolicy,
+    ResumeInterruptedJobs,
+    ShowTransferPreview,
+    ShowSkippedItems,
+    SetJobBandwidthLimit {
+        job_id: JobId,
+    },
+    SetGlobalBandwidthLimit,
     SetSkin {
         original_skin: String,
     },
@@ -1903,6 +2094,7 @@ pub enum Route {
     FindResults(FindResultsState),
     Tree(Box<TreeState>),
     Hotlist,
+    Trash(TrashState),
     Dialog(DialogRoute),
 }
 
@@ -1940,6 +2132,7 @@ impl KeybindingHints {
             KeyContext::FindResults,
             KeyContext::Tree,
             KeyContext::Hotlist,
+            KeyContext::Trash,
             KeyContext::Dialog,
             KeyContext::Input,
             KeyContext::FindDialog,
@@ -2039,7 +2232,9 @@ pub struct AppState {
     paused_find_results: Option<FindResultsState>,
     pending_find_tree_picker: Option<FindDialogState>,
     pending_worker_commands: Vec<WorkerCommand>,
+    path_scheduler: job_scheduler::PathScheduler,
     pending_external_edit_requests: Vec<ExternalEditRequest>,
+    pending_bulk_rename: Option<bulk_rename::BulkRenameSession>,
     panelized_result_history: [Option<PanelizedResultSnapshot>; 2],
     previous_panel_directories: [Option<PathBuf>; 2],
     quick_cd_search: QuickCdSearchWorkflow,
@@ -2051,6 +2246,13 @@ pub struct AppState {
     deferred_persist_settings_request: Option<JobRequest>,
     panel_mkdirs: PanelMkdirTracker,
     tree_mutations: TreeMutationTracker,
+    undo_journal: UndoJournal,
+    pending_undo_journal_save: bool,
+    interrupted_jobs: JobQueue,
+    frozen_job_queue: Option<JobQueue>,
+    pending_job_queue_save: bool,
+    plan_request_id: u64,
+    pending_plan_job: Option<JobId>,
     keybinding_hints: KeybindingHints,
     keymap_unknown_actions: usize,
     keymap_invalid_bindings: usize,
@@ -91,10 +91,17 @@ impl AppState {
                     if targets.is_empty() {
                         self.set_status("Delete requires a selected or tagged entry");
                     } else {
-                        self.queue_delete_job(targets);
+                        self.queue_delete_job_with_mode(
+                            targets,
+                            OperationOrigin::Panel(self.active_panel),
+                            self.default_delete_mode(),
+                        );
                     }
                 }
             }
+            AppCommand::DeletePermanently => self.start_permanent_delete(),
+            AppCommand::UndoLastOperation => self.undo_last_operation(),
+            AppCommand::BulkRename => self.start_bulk_rename(),
             AppCommand::CancelJob => {
                 if matches!(self.top_route(), Route::FindResults(_)) {
                     self.cancel_active_find_search();
@@ -102,6 +109,14 @@ impl AppState {
                     self.cancel_latest_job();
                 }
             }
+            AppCommand::ToggleJobPause => self.toggle_selected_job_pause(),
+            AppCommand::TogglePauseAllJobs => self.toggle_pause_all_jobs(),
+            AppCommand::RetryJob => self.retry_selected_job(false),
+            AppCommand::RetryFailedItems => self.retry_selected_job(true),
+            AppCommand::ShowSkippedItems => self.show_skipped_items(),
+            AppCommand::MoveJobUp => self.move_selected_job(QueueMove::Up),
+            AppCommand::MoveJobDown => self.move_selected_job(QueueMove::Down),
+            AppCommand::RunJobNext => self.move_selected_job(QueueMove::Next),
             AppCommand::RestorePanelizedResults => self.restore_panelized_results(),
             AppCommand::OpenEntry => {
                 if self.open_selected_directory() {
@@ -190,6 +205,12 @@ impl AppState {
                     self.hotlist_cursor = index;
                 }
             }
+            AppCommand::Navigate(NavigationTarget::Trash, motion) => {
+                self.apply_trash_navigation(motion);
+            }
+            AppCommand::TrashRestoreSelected => self.restore_selected_trash_item(),
+            AppCommand::TrashPurgeSelected => self.purge_selected_trash_item(),
+            AppCommand::TrashSelectAt(index) => self.select_trash_item(index),
             _ => {
                 unreachable!("non-navigation command dispatched to navigation handler: {command:?}")
             }
@@ -936,7 +957,11 @@ impl AppState {
         } else if self.settings.confirmation.confirm_delete {
             self.start_delete_confirmation_for_targets(vec![target], OperationOrigin::Tree);
         } else {
-            self.queue_delete_job_from(vec![target], OperationOrigin::Tree);
+            self.queue_delete_job_with_mode(
+                vec![target],
+                OperationOrigin::Tree,
+                self.default_delete_mode(),
+            );
         }
     }
 
@@ -45,8 +45,22 @@ impl AppState {
     pub fn handle_job_event(&mut self, event: JobEvent) {
         if let JobEvent::Finished { id, .. } = &event {
             self.find_pause_flags.remove(id);
+            self.finish_undo_record(*id);
+            self.dismiss_job_prompts(*id);
+            self.path_scheduler.release(*id);
+            self.start_ready_jobs();
         }
         self.jobs.handle_event(&event);
+        if let JobEvent::SourceCompleted { id, .. }
+        | JobEvent::DestinationClaimed { id, .. }
+        | JobEvent::Finished { id, .. } = &event
+            && self
+                .jobs
+                .job(*id)
+                .is_some_and(|job| job.retry_request.is_some())
+        {
+            self.pending_job_queue_save = true;
+        }
         self.clamp_jobs_cursor();
         match event {
             JobEvent::Started { id } => {
@@ -62,6 +76,35 @@ impl AppState {
                     }
                 }
             }
+            JobEvent::Undoable { id, entry } => self.undo_journal.track(id, entry),
+            JobEvent::OverwriteConflict { id, conflict } => {
+                self.prompt_overwrite_conflict(id, conflict)
+            }
+            JobEvent::ItemFailed { id, failure } => self.prompt_item_failure(id, failure),
+            JobEvent::ItemSkipped { id, failure } => {
+                tracing::warn!(
+                    job_event = "item_skipped",
+                    job_id = %id,
+                    path = %failure.path.to_string_lossy(),
+                    error = %failure.message,
+                    "job skipped a failed item"
+                );
+                self.set_status(format!(
+                    "Job #{id} skipped {}: {}",
+                    failure.path.to_string_lossy(),
+                    failure.message
+                ));
+            }
+            JobEvent::Warning { id, message } => {
+                tracing::warn!(
+                    job_event = "warning",
+                    job_id = %id,
+                    warning = %message,
+                    "job step partially succeeded"
+                );
+                self.set_status(format!("Job #{id} warning: {message}"));
+            }
+            JobEvent::SourceCompleted { .. } | JobEvent::DestinationClaimed { .. } => {}
             JobEvent::Progress { id, progress } => {
                 let percent = progress.percent();
                 let job_kind = self
@@ -86,8 +129,23 @@ impl AppState {
                     .and_then(Path::file_name)
                     .map(|name| name.to_string_lossy().into_owned())
                     .unwrap_or_else(|| String::from("-"));
+                if progress.phase == JobPhase::Verifying {
+                    self.set_status(format!("Job #{id} verifying {path_label}"));
+                    return;
+                }
+                let mut skipped = String::new();
+                if progress.bytes_resumed > 0 {
+                    skipped.push_str(&format!(" (resumed {})", progress.bytes_resumed));
+                }
+                if progress.bytes_linked > 0 {
+                    skipped.push_str(&format!(" (linked {})", progress.bytes_linked));
+                }
+                let rate = progress
+                    .rate_label()
+                    .map(|label| format!(" | {label}"))
+                    .unwrap_or_default();
                 self.set_status(format!(
-                    "Job #{id} {percent}% | items {}/{} | bytes {}/{} | {path_label}",
+                    "Job #{id} {percent}% | items {}/{} | bytes {}/{}{skipped}{rate} | {path_label}",
                     progress.items_done,
                     progress.items_total,
                     progress.bytes_done,
@@ -129,8 +187,16 @@ impl AppState {
                             JobKind::Copy
                                 | JobKind::Move
                                 | JobKind::Delete
+                                | JobKind::Trash
+                                | JobKind::RestoreTrash
+                                | JobKind::PurgeTrash
                                 | JobKind::Mkdir
                                 | JobKind::Rename
+                                | JobKind::Chmod
+                                | JobKind::Chown
+                                | JobKind::Link
+                                | JobKind::Symlink
+                                | JobKind::EditSymlink
                         )
                     );
                     let panel_mkdir_status =
@@ -138,6 +204,9 @@ impl AppState {
                     if should_refresh && panel_mkdir_status.is_none() {
                         self.refresh_panels();
                     }
+                    if is_trash_job(kind) {
+                        self.reload_trash_screens();
+                    }
                     if is_find {
                         if let Some(results) = self.find_results_by_job_id(id) {
                             self.set_status(find_results_status_message(results));
@@ -145,8 +214,15 @@ impl AppState {
                     } else if let Some(status) = panel_mkdir_status {
                         self.set_status(status);
                     } else if !suppress_status {
-                        if let Some(summary) = self.jobs.job(id).map(|job| job.summary.clone()) {
-                            self.set_status(format!("Job #{id} finished: {summary}"));
+                        if let Some(job) = self.jobs.job(id) {
+                            let status = match job.warnings.len() {
+                                0 => format!("Job #{id} finished: {}", job.summary),
+                                count => format!(
+                                    "Job #{id} finished with {count} warning(s): {}",
+                                    job.summary
+                                ),
+                            };
+                            self.set_status(status);
                         } else {
                             self.set_status(format!("Job #{id} finished"));
                         }
@@ -185,6 +261,9 @@ impl AppState {
                     if is_selection_size {
                         self.handle_selection_size_job_failure(id, &error);
                     }
+                    if is_trash_job(kind) {
+                        self.reload_trash_screens();
+                    }
                     if is_find && let Some(results) = self.find_results_by_job_id_mut(id) {
                         results.status = if error.is_canceled() {
                             FindResultsStatus::Canceled
@@ -332,6 +411,9 @@ impl AppState {
                 request_id,
                 report,
             } => self.handle_selection_size_measured(panel, request_id, report),
+            BackgroundEvent::TransferPlanned { request_id, result } => {
+                self.handle_transfer_planned(request_id, result)
+            }
             BackgroundEvent::QuickCdSearchUpdated {
                 request_id,
                 snapshot,
@@ -383,24 +465,15 @@ impl AppState {
         sources: Vec<PathBuf>,
         destination_dir: PathBuf,
         overwrite: OverwritePolicy,
+        preserve: PreserveLevel,
         origin: OperationOrigin,
     ) {
-        let request = match kind {
-            TransferKind::Copy => JobRequest::Copy {
-                sources,
-                destination_dir,
-                overwrite,
-            },
-            TransferKind::Move => JobRequest::Move {
-                sources,
-                destination_dir,
-                destination_names: None,
-                overwrite,
-            },
-        };
+        let request =
+            self.transfer_request(kind, sources, destination_dir, None, overwrite, preserve);
         self.queue_filesystem_job(request, origin);
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn queue_copy_or_move_job_with_names(
         &mut self,
         kind: TransferKind,
@@ -408,22 +481,61 @@ impl AppState {
         destination_dir: PathBuf,
         destination_names: Vec<String>,
         overwrite: OverwritePolicy,
+        preserve: PreserveLevel,
         origin: OperationOrigin,
     ) {
-        let request = match kind {
+        let request = self.transfer_request(
+            kind,
+            sources,
+            destination_dir,
+            Some(destination_names),
+            overwrite,
+            preserve,
+        );
+        self.queue_filesystem_job(request, origin);
+    }
+
+    /// The job a transfer dialog queues. Links always keep the source names.
+    pub(crate) fn transfer_request(
+        &self,
+        kind: TransferKind,
+        sources: Vec<PathBuf>,
+        destination_dir: PathBuf,
+        destination_names: Option<Vec<String>>,
+        overwrite: OverwritePolicy,
+        preserve: PreserveLevel,
+    ) -> JobRequest {
+        match kind {
             TransferKind::Copy => JobRequest::Copy {
                 sources,
                 destination_dir,
+                destination_names,
                 overwrite,
+                verify: self.settings.configuration.verify_copies,
+                preserve,
+                follow_links: FollowLinks::Never,
+                hard_links: false,
             },
             TransferKind::Move => JobRequest::Move {
                 sources,
                 destination_dir,
-                destination_names: Some(destination_names),
+                destination_names,
                 overwrite,
+                verify: self.settings.configuration.verify_copies,
+                preserve,
             },
-        };
-        self.queue_filesystem_job(request, origin);
+            TransferKind::Link => JobRequest::Link {
+                sources,
+                destination_dir,
+                overwrite,
+            },
+            TransferKind::Symlink | TransferKind::RelativeSymlink => JobRequest::Symlink {
+                sources,
+                destination_dir,
+                relative: kind == TransferKind::RelativeSymlink,
+                overwrite,
+            },
+        }
     }
 
     pub(crate) fn queue_filesystem_job(
@@ -458,6 +570,13 @@ impl AppState {
         job_id
     }
 
+    fn copy_options(&self) -> CopyOptions {
+        CopyOptions {
+            resume: self.settings.configuration.resume_copies,
+            workers: self.settings.advanced.copy_workers,
+        }
+    }
+
     pub fn enqueue_worker_job_request(&mut self, request: JobRequest) -> JobId {
         self.queue_worker_job_request(request)
     }
@@ -487,7 +606,27 @@ impl AppState {
                 return active_id;
             }
         }
-        let worker_job = self.jobs.enqueue(request);
+        self.queue_worker_job_merging(request, None)
+    }
+
+    /// Queues `request` with the job settings in effect; `claimed` is
+    /// passed on to [`WorkerJob::set_claimed_destination`].
+    pub(crate) fn queue_worker_job_merging(
+        &mut self,
+        request: JobRequest,
+        claimed: Option<ClaimedDestination>,
+    ) -> JobId {
+        self.jobs
+            .set_global_bandwidth_limit(self.settings.configuration.bandwidth_limit);
+        if request.is_retryable() {
+            self.pending_job_queue_save = true;
+        }
+        let mut worker_job = self.jobs.enqueue(request);
+        worker_job.set_copy_options(self.copy_options());
+        worker_job.set_continue_on_error(self.settings.configuration.continue_on_error);
+        if let Some(claimed) = claimed {
+            worker_job.set_claimed_destination(claimed);
+        }
         self.queue_worker_job(worker_job)
     }
 
@@ -555,6 +694,33 @@ impl AppState {
         let job_id = worker_job.id;
         let job_kind = worker_job.request.kind().label();
         let summary = worker_job.request.summary();
+        let Some(worker_job) = self.path_scheduler.admit(worker_job) else {
+            self.note_held_job_waits();
+            let wait = self
+                .jobs
+                .job(job_id)
+                .and_then(|job| job.waiting_for.clone());
+            tracing::debug!(
+                job_event = "held",
+                job_kind,
+                job_id = %job_id,
+                blocking_job_id = ?wait.as_ref().map(|wait| wait.job),
+                summary = %summary,
+                "held worker job behind a job it would get in the way of"
+            );
+            if report_status && let Some(wait) = wait {
+                let device = if wait.shared_device {
+                    " on the same device"
+                } else {
+                    ""
+                };
+                self.set_status(format!(
+                    "Queued job #{job_id} after job #{}{device}: {summary}",
+                    wait.job
+                ));
+            }
+            return job_id;
+        };
         self.pending_worker_commands
             .push(WorkerCommand::Run(Box::new(worker_job)));
         tracing::debug!(
@@ -571,6 +737,71 @@ impl AppState {
         job_id
     }
 
+    /// Hands the worker the held jobs nothing is in the way of any more,
+    /// and notes what the others wait for now.
+    fn start_ready_jobs(&mut self) {
+        for worker_job in self.path_scheduler.take_ready() {
+            tracing::debug!(
+                job_event = "released",
+                job_kind = worker_job.request.kind().label(),
+                job_id = %worker_job.id,
+                "released held worker job"
+            );
+            self.jobs.set_waiting_for(worker_job.id, None);
+            self.pending_worker_commands
+                .push(WorkerCommand::Run(Box::new(worker_job)));
+        }
+        self.note_held_job_waits();
+    }
+
+    fn note_held_job_waits(&mut self) {
+        for (job_id, wait) in self.path_scheduler.waits() {
+            self.jobs.set_waiting_for(job_id, Some(wait));
+        }
+    }
+
+    /// Moves the selected held job up or down the queue, or to its front.
+    pub(crate) fn move_selected_job(&mut self, queue_move: QueueMove) {
+        let selected = if matches!(self.top_route(), Route::Jobs) {
+            self.selected_job_record()
+        } else {
+            None
+        };
+        let Some(job_id) = selected.map(|job| job.id) else {
+            self.set_status("No job selected");
+            return;
+        };
+        match self.path_scheduler.move_held(job_id, queue_move) {
+            None => self.set_status(format!("Job #{job_id} is not waiting in the queue")),
+            Some(Ok(false)) => self.set_status(match queue_move {
+                QueueMove::Up | QueueMove::Next => format!("Job #{job_id} is already first"),
+                QueueMove::Down => format!("Job #{job_id} is already last"),
+            }),
+            Some(Ok(true)) => {
+                self.start_ready_jobs();
+                let position = self
+                    .jobs
+                    .job(job_id)
+                    .and_then(|job| job.waiting_for.as_ref())
+                    .map(|wait| wait.position);
+                self.set_status(match position {
+                    Some(position) => format!("Job #{job_id} is now #{position} in the queue"),
+                    None => format!("Job #{job_id} starts now"),
+                });
+            }
+            Some(Err((other, path))) => {
+                let side = match queue_move {
+                    QueueMove::Up | QueueMove::Next => "behind",
+                    QueueMove::Down => "ahead of",
+                };
+                self.set_status(format!(
+                    "Job #{job_id} stays {side} job #{other}: both work on {}",
+                    path.to_string_lossy()
+                ));
+            }
+        }
+    }
+
     pub(crate) fn active_persist_settings_job_id(&self) -> Option<JobId> {
         self.jobs
             .jobs()
@@ -659,6 +890,129 @@ impl AppState {
         }
     }
 
+    pub(crate) fn toggle_selected_job_pause(&mut self) {
+        let selected = if matches!(self.top_route(), Route::Jobs) {
+            self.selected_job_record()
+        } else {
+            None
+        };
+        let Some(job) = selected else {
+            self.set_status("No job selected");
+            return;
+        };
+        let (job_id, kind) = (job.id, job.kind);
+        let pause = job.status != JobStatus::Paused;
+        if !kind.is_pausable() {
+            self.set_status(format!("Job #{job_id} ({}) cannot be paused", kind.label()));
+        } else if !self.jobs.set_paused(job_id, pause) {
+            self.set_status(format!("Job #{job_id} is not active"));
+        } else {
+            self.update_held_jobs_after_pause(pause);
+            if pause {
+                self.set_status(format!("Paused job #{job_id}"));
+            } else {
+                self.set_status(format!("Resumed job #{job_id}"));
+            }
+        }
+    }
+
+    pub(crate) fn toggle_pause_all_jobs(&mut self) {
+        let (ids, pause) = self.jobs.toggle_pause_all();
+        if !ids.is_empty() {
+            self.update_held_jobs_after_pause(pause);
+        }
+        match (ids, pause) {
+            (ids, _) if ids.is_empty() => self.set_status("No job to pause"),
+            (ids, true) => self.set_status(format!("Paused {} job(s)", ids.len())),
+            (ids, false) => self.set_status(format!("Resumed {} job(s)", ids.len())),
+        }
+    }
+
+    /// A paused job lets held jobs on its devices start; a resumed one has
+    /// them wait for it again.
+    fn update_held_jobs_after_pause(&mut self, paused: bool) {
+        if paused {
+            self.start_ready_jobs();
+        } else {
+            self.note_held_job_waits();
+        }
+    }
+
+    /// Queues the selected failed or canceled job again, either whole or
+    /// without the sources it already finished.
+    pub(crate) fn retry_selected_job(&mut self, failed_items_only: bool) {
+        let selected = if matches!(self.top_route(), Route::Jobs) {
+            self.selected_job_record()
+        } else {
+            None
+        };
+        let Some(job) = selected else {
+            self.set_status("No job selected");
+            return;
+        };
+        let job_id = job.id;
+        if !matches!(job.status, JobStatus::Failed | JobStatus::Canceled) {
+            self.set_status(format!("Job #{job_id} did not fail"));
+            return;
+        }
+        let Some(request) = job.retry_request.as_ref() else {
+            self.set_status(format!(
+                "Job #{job_id} ({}) cannot be retried",
+                job.kind.label()
+            ));
+            return;
+        };
+        if job.retry_hint == Some(JobRetryHint::None) {
+            let error = job.last_error.as_deref().unwrap_or("unknown error");
+            self.set_status(format!(
+                "Job #{job_id} would fail again: {error}; fix the cause and start it anew"
+            ));
+            return;
+        }
+        let request = if failed_items_only {
+            request.without_sources(&job.completed_sources)
+        } else {
+            Some(request.clone())
+        };
+        let Some(request) = request else {
+            self.set_status(format!("Job #{job_id} has no failed items left"));
+            return;
+        };
+        let items = request.item_count();
+        let retry_id = self.queue_worker_job_request(request);
+        self.set_status(format!(
+            "Retrying job #{job_id} as job #{retry_id} ({items} item(s))"
+        ));
+    }
+
+    /// Lists the items the selected job failed on and skipped.
+    pub(crate) fn show_skipped_items(&mut self) {
+        let selected = if matches!(self.top_route(), Route::Jobs) {
+            self.selected_job_record()
+        } else {
+            None
+        };
+        let Some(job) = selected else {
+            self.set_status("No job selected");
+            return;
+        };
+        let job_id = job.id;
+        if job.skipped_items.is_empty() {
+            self.set_status(format!("Job #{job_id} skipped no items"));
+            return;
+        }
+        let items: Vec<String> = job
+            .skipped_items
+            .iter()
+            .map(|failure| format!("{}: {}", failure.path.to_string_lossy(), failure.message))
+            .collect();
+        let footer = format!("{} item(s) | Enter/Esc back", items.len());
+        self.push_dialog(
+            DialogState::listbox_with_hint(format!("Skipped by job #{job_id}"), items, 0, footer),
+            PendingDialogAction::ShowSkippedItems,
+        );
+    }
+
     pub(crate) fn request_cancel_for_job(&mut self, job_id: JobId) -> bool {
         if !self.jobs.request_cancel(job_id) {
             return false;
@@ -671,6 +1025,20 @@ impl AppState {
             .job(job_id)
             .map(|job| job.kind.label())
             .unwrap_or("unknown");
+        if self.path_scheduler.take_held(job_id).is_some() {
+            // The worker never saw it, so it is finished here.
+            tracing::debug!(
+                job_event = "cancel_requested",
+                job_kind,
+                job_id = %job_id,
+                "canceled held job"
+            );
+            self.handle_job_event(JobEvent::Finished {
+                id: job_id,
+                result: Err(JobError::canceled()),
+            });
+            return true;
+        }
         tracing::debug!(
             job_event = "cancel_requested",
             job_kind,
@@ -689,8 +1057,10 @@ impl AppState {
             .jobs()
             .iter()
             .filter(|job| {
-                matches!(job.status, JobStatus::Queued | JobStatus::Running)
-                    && !matches!(job.kind, JobKind::PersistSettings)
+                matches!(
+                    job.status,
+                    JobStatus::Queued | JobStatus::Running | JobStatus::Paused
+                ) && !matches!(job.kind, JobKind::PersistSettings)
             })
             .map(|job| job.id)
             .collect();
@@ -699,13 +1069,38 @@ impl AppState {
         }
     }
 
-    pub(crate) fn queue_delete_job(&mut self, targets: Vec<PathBuf>) {
-        self.queue_delete_job_from(targets, OperationOrigin::Panel(self.active_panel));
-    }
-
     pub(crate) fn queue_delete_job_from(&mut self, targets: Vec<PathBuf>, origin: OperationOrigin) {
         self.queue_filesystem_job(JobRequest::Delete { targets }, origin);
     }
+
+    pub(crate) fn queue_delete_job_with_mode(
+        &mut self,
+        targets: Vec<PathBuf>,
+        origin: OperationOrigin,
+        mode: DeleteMode,
+    ) {
+        match mode {
+            DeleteMode::Trash => {
+                self.queue_filesystem_job(JobRequest::Trash { targets }, origin);
+            }
+            DeleteMode::Permanent => self.queue_delete_job_from(targets, origin),
+        }
+    }
+
+    pub(crate) fn default_delete_mode(&self) -> DeleteMode {
+        if self.settings.confirmation.delete_to_trash {
+            DeleteMode::Trash
+        } else {
+            DeleteMode::Permanent
+        }
+    }
+}
+
+fn is_trash_job(kind: Option<JobKind>) -> bool {
+    matches!(
+        kind,
+        Some(JobKind::Trash | JobKind::RestoreTrash | JobKind::PurgeTrash)
+    )
 }
 
 fn suppress_transient_job_status(kind: Option<JobKind>) -> bool {
@@ -718,6 +1113,7 @@ fn suppress_transient_job_status(kind: Option<JobKind>) -> bool {
                 | JobKind::QuickCdSearch
                 | JobKind::MeasureSelection
                 | JobKind::BuildTree
+                | JobKind::PlanTransfer
         )
     )
 }
@@ -740,7 +1136,7 @@ fn tree_mutation_impacts(request: &JobRequest) -> Vec<PathBuf> {
             );
             impacts.push(destination_dir.clone());
         }
-        JobRequest::Delete { targets } => impacts.extend(
+        JobRequest::Delete { targets } | JobRequest::Trash { targets } => impacts.extend(
             targets
                 .iter()
                 .filter_map(|target| target.parent().map(Path::to_path_buf)),
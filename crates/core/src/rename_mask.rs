@@ -0,0 +1,169 @@
+//! Source and destination masks that rename a batch of files while they are
+//! copied or moved, as in mc's copy and move dialogs.
+//!
+//! With shell patterns the source mask may use `*` and `?`, and every
+//! wildcard of the destination mask takes what the wildcard at the same
+//! position matched, so `*.jpeg` to `*.jpg` only changes the extension. As a
+//! regular expression the source mask has to match the whole name. In both
+//! modes `\1` to `\9` insert a group of the match and `\0` the whole name.
+
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::FindNameMode;
+
+#[derive(Clone, Debug)]
+pub struct RenameMask {
+    source: Regex,
+    destination: Vec<MaskToken>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum MaskToken {
+    Literal(char),
+    Group(usize),
+}
+
+impl RenameMask {
+    pub fn new(
+        source_mask: &str,
+        destination_mask: &str,
+        mode: FindNameMode,
+    ) -> Result<Self, String> {
+        if source_mask.is_empty() || destination_mask.is_empty() {
+            return Err(String::from("both masks are required"));
+        }
+        let expression = match mode {
+            FindNameMode::Glob => shell_mask_expression(source_mask),
+            FindNameMode::Regex => format!("^(?:{source_mask})$"),
+        };
+        let source =
+            Regex::new(&expression).map_err(|error| format!("invalid source mask: {error}"))?;
+        let destination = destination_tokens(destination_mask, mode);
+        let groups = source.captures_len() - 1;
+        if let Some(group) = destination.iter().find_map(|token| match token {
+            MaskToken::Group(group) if *group > groups => Some(*group),
+            _ => None,
+        }) {
+            return Err(format!(
+                "destination mask uses group {group} but the source mask has {groups}"
+            ));
+        }
+        Ok(Self {
+            source,
+            destination,
+        })
+    }
+
+    /// The new name for `name`, or `None` when the source mask does not
+    /// match it.
+    pub fn apply(&self, name: &str) -> Option<String> {
+        let captures = self.source.captures(name)?;
+        let mut renamed = String::new();
+        for token in &self.destination {
+            match token {
+                MaskToken::Literal(character) => renamed.push(*character),
+                MaskToken::Group(group) => {
+                    renamed.push_str(captures.get(*group).map_or("", |group| group.as_str()));
+                }
+            }
+        }
+        Some(renamed)
+    }
+
+    /// The destination name of every source: masked where the source mask
+    /// matches, unchanged elsewhere.
+    pub fn destination_names(&self, sources: &[PathBuf]) -> Vec<String> {
+        sources
+            .iter()
+            .map(|source| {
+                let name = source_name(source);
+                self.apply(&name).unwrap_or(name)
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn source_name(source: &Path) -> String {
+    source
+        .file_name()
+        .map_or_else(|| source.to_string_lossy(), |name| name.to_string_lossy())
+        .into_owned()
+}
+
+/// Splits a destination mask into literal characters and group references;
+/// with shell patterns the wildcards count up from the first group.
+fn destination_tokens(mask: &str, mode: FindNameMode) -> Vec<MaskToken> {
+    let mut tokens = Vec::new();
+    let mut wildcard = 0;
+    let mut characters = mask.chars().peekable();
+    while let Some(character) = characters.next() {
+        let group = match character {
+            '\\' => characters
+                .next_if(char::is_ascii_digit)
+                .and_then(|digit| digit.to_digit(10)),
+            '*' | '?' if mode == FindNameMode::Glob => {
+                wildcard += 1;
+                Some(wildcard)
+            }
+            _ => None,
+        };
+        tokens.push(match group {
+            Some(group) => MaskToken::Group(group as usize),
+            None => MaskToken::Literal(character),
+        });
+    }
+    tokens
+}
+
+/// Turns a shell mask into an anchored expression with one group per
+/// wildcard.
+fn shell_mask_expression(mask: &str) -> String {
+    let mut expression = String::from("^");
+    for character in mask.chars() {
+        match character {
+            '*' => expression.push_str("(.*)"),
+            '?' => expression.push_str("(.)"),
+            _ => expression.push_str(&regex::escape(&character.to_string())),
+        }
+    }
+    expression.push('$');
+    expression
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shell_and_regex_masks_rename_matching_names() {
+        let mask = RenameMask::new("*.jpeg", "*.jpg", FindNameMode::Glob)
+            .expect("shell masks should compile");
+        assert_eq!(
+            mask.apply("holiday.jpeg"),
+            Some(String::from("holiday.jpg"))
+        );
+        assert_eq!(mask.apply("notes.txt"), None);
+        assert_eq!(
+            mask.destination_names(&[PathBuf::from("/a/b.jpeg"), PathBuf::from("/a/c.txt")]),
+            vec![String::from("b.jpg"), String::from("c.txt")]
+        );
+
+        let mask = RenameMask::new("IMG_?(*)", "\\2-\\1", FindNameMode::Glob)
+            .expect("literal parentheses should be escaped");
+        assert_eq!(mask.apply("IMG_7(beach)"), Some(String::from("beach-7")));
+
+        let mask = RenameMask::new(r"(\d+)_(.*)\.txt", r"\2_\1.md", FindNameMode::Regex)
+            .expect("regex masks should compile");
+        assert_eq!(
+            mask.apply("01_intro.txt"),
+            Some(String::from("intro_01.md"))
+        );
+        assert_eq!(mask.apply("x01_intro.txt"), None);
+
+        assert!(RenameMask::new("*.txt", "*-*.txt", FindNameMode::Glob).is_err());
+        assert!(RenameMask::new("(", "x", FindNameMode::Regex).is_err());
+        assert!(RenameMask::new("*", "", FindNameMode::Glob).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::layout::{
-    ScreenRect, find_results_layout, hotlist_layout, listbox_dialog_layout, tree_layout,
-    visible_window,
+    ScreenRect, find_results_layout, hotlist_layout, listbox_dialog_layout, trash_layout,
+    tree_layout, visible_window,
 };
 use crate::*;
 
@@ -23,6 +23,7 @@ impl AppState {
                 if self.settings.confirmation.confirm_quit {
                     self.start_quit_confirmation();
                 } else {
+                    self.freeze_job_queue();
                     self.request_cancel_for_all_jobs();
                     return CommandOutcome::Quit;
                 }
@@ -35,6 +36,8 @@ impl AppState {
             AppCommand::CloseTree => self.close_tree_screen(),
             AppCommand::OpenHotlist => self.open_hotlist_screen(),
             AppCommand::CloseHotlist => self.close_hotlist_screen(),
+            AppCommand::OpenTrash => self.open_trash_screen(),
+            AppCommand::CloseTrash => self.close_trash_screen(),
             AppCommand::OpenPanelizeDialog => self.open_panelize_dialog(),
             AppCommand::PanelizePresetAdd => self.start_panelize_preset_add(),
             AppCommand::PanelizePresetEdit => self.start_panelize_preset_edit(),
@@ -296,6 +299,20 @@ impl AppState {
                     AppCommand::HotlistOpenEntry,
                 ))
             }
+            Route::Trash(trash) => {
+                let layout = trash_layout(viewport);
+                let index = visible_list_index_at(
+                    layout.list,
+                    column,
+                    row,
+                    trash.items.len(),
+                    trash.cursor,
+                )?;
+                Some(MouseClickCommands::list_selection(
+                    AppCommand::TrashSelectAt(index),
+                    AppCommand::TrashRestoreSelected,
+                ))
+            }
             Route::Dialog(dialog)
                 if matches!(
                     dialog.action(),
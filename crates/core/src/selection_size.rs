@@ -3,8 +3,11 @@ use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
 
 pub const SELECTION_SIZE_CANCELED_MESSAGE: &str = "selection size calculation canceled";
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct SelectionSizeReport {
@@ -21,7 +24,16 @@ pub fn measure_selection_size(
     paths: &[PathBuf],
     cancel_flag: &AtomicBool,
 ) -> io::Result<SelectionSizeReport> {
-    ensure_not_canceled(cancel_flag)?;
+    measure_selection_size_pausable(paths, cancel_flag, &AtomicBool::new(false))
+}
+
+/// Like [`measure_selection_size`], but waits between entries while `pause_flag` is set.
+pub fn measure_selection_size_pausable(
+    paths: &[PathBuf],
+    cancel_flag: &AtomicBool,
+    pause_flag: &AtomicBool,
+) -> io::Result<SelectionSizeReport> {
+    ensure_not_canceled(cancel_flag, pause_flag)?;
 
     let selected_paths = paths.iter().cloned().collect::<HashSet<_>>();
     let mut pending = selected_paths.iter().cloned().collect::<Vec<_>>();
@@ -32,7 +44,7 @@ pub fn measure_selection_size(
     let mut report = SelectionSizeReport::default();
 
     while let Some(path) = pending.pop() {
-        ensure_not_canceled(cancel_flag)?;
+        ensure_not_canceled(cancel_flag, pause_flag)?;
         let metadata = match fs::symlink_metadata(&path) {
             Ok(metadata) => metadata,
             Err(_) => {
@@ -55,7 +67,7 @@ pub fn measure_selection_size(
                 }
             };
             for entry in entries {
-                ensure_not_canceled(cancel_flag)?;
+                ensure_not_canceled(cancel_flag, pause_flag)?;
                 let path = match entry {
                     Ok(entry) => entry.path(),
                     Err(_) => {
@@ -94,7 +106,7 @@ pub fn measure_selection_size(
         );
     }
 
-    ensure_not_canceled(cancel_flag)?;
+    ensure_not_canceled(cancel_flag, pause_flag)?;
     Ok(report)
 }
 
@@ -113,25 +125,32 @@ fn measure_non_directory(
     report.apparent_bytes = report.apparent_bytes.saturating_add(metadata.len());
 }
 
-fn ensure_not_canceled(cancel_flag: &AtomicBool) -> io::Result<()> {
-    if cancel_flag.load(Ordering::Relaxed) {
-        return Err(io::Error::new(
-            io::ErrorKind::Interrupted,
-            SELECTION_SIZE_CANCELED_MESSAGE,
-        ));
+fn ensure_not_canceled(cancel_flag: &AtomicBool, pause_flag: &AtomicBool) -> io::Result<()> {
+    loop {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                SELECTION_SIZE_CANCELED_MESSAGE,
+            ));
+        }
+        if !pause_flag.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        thread::sleep(PAUSE_POLL_INTERVAL);
     }
-    Ok(())
 }
 
+/// What tells a directory apart from every other one, whatever path leads
+/// to it.
 #[cfg(unix)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct DirectoryIdentity {
+pub(crate) struct DirectoryIdentity {
     device: u64,
     inode: u64,
 }
 
 #[cfg(unix)]
-fn directory_identity(_path: &Path, metadata: &fs::Metadata) -> DirectoryIdentity {
+pub(crate) fn directory_identity(_path: &Path, metadata: &fs::Metadata) -> DirectoryIdentity {
     use std::os::unix::fs::MetadataExt as _;
 
     DirectoryIdentity {
@@ -141,10 +160,10 @@ fn directory_identity(_path: &Path, metadata: &fs::Metadata) -> DirectoryIdentit
 }
 
 #[cfg(not(unix))]
-type DirectoryIdentity = PathBuf;
+pub(crate) type DirectoryIdentity = PathBuf;
 
 #[cfg(not(unix))]
-fn directory_identity(path: &Path, _metadata: &fs::Metadata) -> DirectoryIdentity {
+pub(crate) fn directory_identity(path: &Path, _metadata: &fs::Metadata) -> DirectoryIdentity {
     path.to_path_buf()
 }
 
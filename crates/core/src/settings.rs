@@ -1,7 +1,7 @@
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
-use crate::{OverwritePolicy, PanelFilter, PanelListingFormat, SortMode};
+use crate::{OverwritePolicy, PanelFilter, PanelListingFormat, PreserveLevel, SortMode};
 
 pub const DEFAULT_PANELIZE_PRESETS: &[(&str, &str)] = &[
     ("All files", "find . -type f"),
@@ -67,6 +67,20 @@ impl Settings {
 pub struct ConfigurationSettings {
     pub default_overwrite_policy: OverwritePolicy,
     pub macos_option_symbols: bool,
+    /// Keep interrupted file copies in a `.name.rc-partial` staging file and
+    /// continue them on the next copy of the same source.
+    pub resume_copies: bool,
+    /// Hash copied files and re-read them before they replace anything.
+    pub verify_copies: bool,
+    /// Skip items a copy, move or delete fails on instead of asking; they
+    /// are listed with the job.
+    pub continue_on_error: bool,
+    /// Attributes copies keep by default; the overwrite dialog can change it
+    /// per job.
+    pub preserve_level: PreserveLevel,
+    /// Bandwidth cap shared by all jobs in bytes per second; zero is
+    /// unlimited.
+    pub bandwidth_limit: u64,
     pub editor_command: Option<String>,
     pub hotlist: Vec<HotlistEntry>,
     pub panelize_presets: Vec<PanelizePreset>,
@@ -130,6 +144,11 @@ impl Default for ConfigurationSettings {
         Self {
             default_overwrite_policy: OverwritePolicy::Skip,
             macos_option_symbols: cfg!(target_os = "macos"),
+            resume_copies: false,
+            verify_copies: false,
+            continue_on_error: false,
+            preserve_level: PreserveLevel::Basic,
+            bandwidth_limit: 0,
             editor_command: None,
             hotlist: Vec::new(),
             panelize_presets: DEFAULT_PANELIZE_PRESETS
@@ -195,6 +214,9 @@ pub struct ConfirmationSettings {
     pub confirm_overwrite: bool,
     pub confirm_quit: bool,
     pub confirm_hotlist_delete: bool,
+    /// Default choice of the delete dialog: move to the trash instead of
+    /// deleting permanently.
+    pub delete_to_trash: bool,
 }
 
 impl Default for ConfirmationSettings {
@@ -204,6 +226,7 @@ impl Default for ConfirmationSettings {
             confirm_overwrite: true,
             confirm_quit: false,
             confirm_hotlist_delete: true,
+            delete_to_trash: false,
         }
     }
 }
@@ -269,6 +292,8 @@ pub struct AdvancedSettings {
     pub max_find_results: usize,
     pub tree_max_depth: usize,
     pub tree_max_entries: usize,
+    /// Threads copying the small files of a directory tree; 1 copies serially.
+    pub copy_workers: usize,
 }
 
 impl Default for AdvancedSettings {
@@ -279,6 +304,7 @@ impl Default for AdvancedSettings {
             max_find_results: 2_000,
             tree_max_depth: 6,
             tree_max_entries: 2_000,
+            copy_workers: 4,
         }
     }
 }
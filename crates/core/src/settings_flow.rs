@@ -78,11 +78,39 @@ impl AppState {
                     self.overwrite_policy().label(),
                     SettingsEntryAction::CycleDefaultOverwritePolicy,
                 ),
+                SettingsEntry::new(
+                    "Preserve attributes",
+                    self.settings.configuration.preserve_level.label(),
+                    SettingsEntryAction::CyclePreserveLevel,
+                ),
+                SettingsEntry::new(
+                    "Bandwidth limit (all jobs)",
+                    match self.settings.configuration.bandwidth_limit {
+                        0 => String::from("unlimited"),
+                        limit => throttle::format_rate(limit),
+                    },
+                    SettingsEntryAction::EditBandwidthLimit,
+                ),
                 SettingsEntry::new(
                     "macOS Option-symbol compatibility",
                     bool_label(self.settings.configuration.macos_option_symbols),
                     SettingsEntryAction::ToggleMacosOptionSymbols,
                 ),
+                SettingsEntry::new(
+                    "Resumable copies",
+                    bool_label(self.settings.configuration.resume_copies),
+                    SettingsEntryAction::ToggleResumeCopies,
+                ),
+                SettingsEntry::new(
+                    "Verify copies",
+                    bool_label(self.settings.configuration.verify_copies),
+                    SettingsEntryAction::ToggleVerifyCopies,
+                ),
+                SettingsEntry::new(
+                    "Continue on error",
+                    bool_label(self.settings.configuration.continue_on_error),
+                    SettingsEntryAction::ToggleContinueOnError,
+                ),
                 SettingsEntry::new(
                     "Keymap override",
                     self.settings
@@ -199,6 +227,11 @@ impl AppState {
                     bool_label(self.settings.confirmation.confirm_hotlist_delete),
                     SettingsEntryAction::ToggleConfirmHotlistDelete,
                 ),
+                SettingsEntry::new(
+                    "Delete to trash by default",
+                    bool_label(self.settings.confirmation.delete_to_trash),
+                    SettingsEntryAction::ToggleDeleteToTrash,
+                ),
             ],
             SettingsCategory::Appearance => vec![
                 SettingsEntry::new(
@@ -329,6 +362,16 @@ impl AppState {
                     self.overwrite_policy().label()
                 ));
             }
+            SettingsEntryAction::CyclePreserveLevel => {
+                self.settings.configuration.preserve_level =
+                    self.settings.configuration.preserve_level.next();
+                self.settings.mark_dirty();
+                self.set_status(format!(
+                    "Preserve attributes: {}",
+                    self.settings.configuration.preserve_level.label()
+                ));
+            }
+            SettingsEntryAction::EditBandwidthLimit => self.start_global_bandwidth_dialog(),
             SettingsEntryAction::ToggleMacosOptionSymbols => {
                 self.settings.configuration.macos_option_symbols =
                     !self.settings.configuration.macos_option_symbols;
@@ -338,6 +381,33 @@ impl AppState {
                     bool_label(self.settings.configuration.macos_option_symbols)
                 ));
             }
+            SettingsEntryAction::ToggleResumeCopies => {
+                self.settings.configuration.resume_copies =
+                    !self.settings.configuration.resume_copies;
+                self.settings.mark_dirty();
+                self.set_status(format!(
+                    "Resumable copies: {}",
+                    bool_label(self.settings.configuration.resume_copies)
+                ));
+            }
+            SettingsEntryAction::ToggleVerifyCopies => {
+                self.settings.configuration.verify_copies =
+                    !self.settings.configuration.verify_copies;
+                self.settings.mark_dirty();
+                self.set_status(format!(
+                    "Verify copies: {}",
+                    bool_label(self.settings.configuration.verify_copies)
+                ));
+            }
+            SettingsEntryAction::ToggleContinueOnError => {
+                self.settings.configuration.continue_on_error =
+                    !self.settings.configuration.continue_on_error;
+                self.settings.mark_dirty();
+                self.set_status(format!(
+                    "Continue on error: {}",
+                    bool_label(self.settings.configuration.continue_on_error)
+                ));
+            }
             SettingsEntryAction::ToggleLayoutShowMenuBar => {
                 self.settings.layout.show_menu_bar = !self.settings.layout.show_menu_bar;
                 self.settings.mark_dirty();
@@ -454,6 +524,15 @@ impl AppState {
                     bool_label(self.settings.confirmation.confirm_hotlist_delete)
                 ));
             }
+            SettingsEntryAction::ToggleDeleteToTrash => {
+                self.settings.confirmation.delete_to_trash =
+                    !self.settings.confirmation.delete_to_trash;
+                self.settings.mark_dirty();
+                self.set_status(format!(
+                    "Delete to trash by default: {}",
+                    bool_label(self.settings.confirmation.delete_to_trash)
+                ));
+            }
             SettingsEntryAction::OpenSkinDialog => self.start_skin_dialog(),
             SettingsEntryAction::ToggleUtf8Output => {
                 self.settings.display_bits.utf8_output = !self.settings.display_bits.utf8_output;
@@ -522,7 +601,8 @@ fn next_overwrite_policy(policy: OverwritePolicy) -> OverwritePolicy {
     match policy {
         OverwritePolicy::Overwrite => OverwritePolicy::Skip,
         OverwritePolicy::Skip => OverwritePolicy::Rename,
-        OverwritePolicy::Rename => OverwritePolicy::Overwrite,
+        OverwritePolicy::Rename => OverwritePolicy::Ask,
+        OverwritePolicy::Ask => OverwritePolicy::Overwrite,
     }
 }
 
@@ -1,6 +1,9 @@
+use crate::job_queue::JOB_QUEUE_FILE_NAME;
+use crate::throttle::parse_rate;
+use crate::undo::UNDO_JOURNAL_FILE_NAME;
 use crate::{
-    FindNameMode, HotlistEntry, OverwritePolicy, PanelListingFormat, PanelizePreset, Settings,
-    SortField,
+    FindNameMode, HotlistEntry, OverwritePolicy, PanelListingFormat, PanelizePreset, PreserveLevel,
+    Settings, SortField,
 };
 use std::fs;
 use std::io::{self, Write};
@@ -8,6 +11,8 @@ use std::path::{Path, PathBuf};
 
 const MC_CONFIG_SECTION: &str = "Midnight-Commander";
 const MC_SKIN_KEY: &str = "skin";
+/// Upper bound for `copy_workers`, so a typo cannot spawn thousands of threads.
+const MAX_COPY_WORKERS: usize = 64;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SettingsPaths {
@@ -15,6 +20,24 @@ pub struct SettingsPaths {
     pub rc_ini_path: Option<PathBuf>,
 }
 
+impl SettingsPaths {
+    /// The undo journal lives next to `settings.ini`.
+    pub fn undo_journal_path(&self) -> Option<PathBuf> {
+        self.rc_ini_path
+            .as_deref()
+            .and_then(Path::parent)
+            .map(|dir| dir.join(UNDO_JOURNAL_FILE_NAME))
+    }
+
+    /// Unfinished mutation jobs are kept next to `settings.ini` as well.
+    pub fn job_queue_path(&self) -> Option<PathBuf> {
+        self.rc_ini_path
+            .as_deref()
+            .and_then(Path::parent)
+            .map(|dir| dir.join(JOB_QUEUE_FILE_NAME))
+    }
+}
+
 pub fn settings_paths() -> SettingsPaths {
     let home = std::env::var_os("HOME").map(PathBuf::from);
     SettingsPaths {
@@ -112,7 +135,7 @@ fn write_rc_settings_ini(path: &Path, settings: &Settings) -> io::Result<()> {
     write_atomic(path, &source)
 }
 
-fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+pub(crate) fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
@@ -326,6 +349,31 @@ fn apply_rc_settings_ini(settings: &mut Settings, source: &str) {
                     settings.configuration.macos_option_symbols = parsed;
                 }
             }
+            ("configuration", "resume_copies") => {
+                if let Some(parsed) = parse_bool(value) {
+                    settings.configuration.resume_copies = parsed;
+                }
+            }
+            ("configuration", "verify_copies") => {
+                if let Some(parsed) = parse_bool(value) {
+                    settings.configuration.verify_copies = parsed;
+                }
+            }
+            ("configuration", "continue_on_error") => {
+                if let Some(parsed) = parse_bool(value) {
+                    settings.configuration.continue_on_error = parsed;
+                }
+            }
+            ("configuration", "bandwidth_limit") => {
+                if let Some(limit) = parse_rate(value) {
+                    settings.configuration.bandwidth_limit = limit;
+                }
+            }
+            ("configuration", "preserve_level") => {
+                if let Some(level) = parse_preserve_level(value) {
+                    settings.configuration.preserve_level = level;
+                }
+            }
             ("configuration", "editor_command") => {
                 settings.configuration.editor_command =
                     (!value.is_empty()).then(|| value.to_string());
@@ -524,6 +572,11 @@ fn apply_rc_settings_ini(settings: &mut Settings, source: &str) {
                     settings.confirmation.confirm_hotlist_delete = parsed;
                 }
             }
+            ("confirmation", "delete_to_trash") => {
+                if let Some(parsed) = parse_bool(value) {
+                    settings.confirmation.delete_to_trash = parsed;
+                }
+            }
             ("appearance", "skin") if !value.is_empty() => {
                 settings.appearance.skin = value.to_string();
             }
@@ -596,6 +649,11 @@ fn apply_rc_settings_ini(settings: &mut Settings, source: &str) {
                     settings.advanced.tree_max_entries = parsed.max(1);
                 }
             }
+            ("advanced", "copy_workers") => {
+                if let Ok(parsed) = value.parse::<usize>() {
+                    settings.advanced.copy_workers = parsed.clamp(1, MAX_COPY_WORKERS);
+                }
+            }
             _ => {}
         }
     }
@@ -625,6 +683,26 @@ fn render_rc_settings_ini(settings: &Settings) -> String {
         "macos_option_symbols={}",
         settings.configuration.macos_option_symbols
     ));
+    lines.push(format!(
+        "resume_copies={}",
+        settings.configuration.resume_copies
+    ));
+    lines.push(format!(
+        "verify_copies={}",
+        settings.configuration.verify_copies
+    ));
+    lines.push(format!(
+        "continue_on_error={}",
+        settings.configuration.continue_on_error
+    ));
+    lines.push(format!(
+        "preserve_level={}",
+        preserve_level_label(settings.configuration.preserve_level)
+    ));
+    lines.push(format!(
+        "bandwidth_limit={}",
+        settings.configuration.bandwidth_limit
+    ));
     lines.push(format!(
         "editor_command={}",
         settings
@@ -748,6 +826,10 @@ fn render_rc_settings_ini(settings: &Settings) -> String {
         "confirm_hotlist_delete={}",
         settings.confirmation.confirm_hotlist_delete
     ));
+    lines.push(format!(
+        "delete_to_trash={}",
+        settings.confirmation.delete_to_trash
+    ));
 
     lines.push(String::new());
     lines.push(String::from("[appearance]"));
@@ -801,26 +883,48 @@ fn render_rc_settings_ini(settings: &Settings) -> String {
         "tree_max_entries={}",
         settings.advanced.tree_max_entries
     ));
+    lines.push(format!("copy_workers={}", settings.advanced.copy_workers));
 
     let mut rendered = lines.join("\n");
     rendered.push('\n');
     rendered
 }
 
-fn parse_overwrite_policy(value: &str) -> Option<OverwritePolicy> {
+pub(crate) fn parse_overwrite_policy(value: &str) -> Option<OverwritePolicy> {
     match value.trim().to_ascii_lowercase().as_str() {
         "overwrite" => Some(OverwritePolicy::Overwrite),
         "skip" => Some(OverwritePolicy::Skip),
         "rename" => Some(OverwritePolicy::Rename),
+        "ask" => Some(OverwritePolicy::Ask),
         _ => None,
     }
 }
 
-fn overwrite_policy_label(policy: OverwritePolicy) -> &'static str {
+pub(crate) fn overwrite_policy_label(policy: OverwritePolicy) -> &'static str {
     match policy {
         OverwritePolicy::Overwrite => "overwrite",
         OverwritePolicy::Skip => "skip",
         OverwritePolicy::Rename => "rename",
+        OverwritePolicy::Ask => "ask",
+    }
+}
+
+pub(crate) fn parse_preserve_level(value: &str) -> Option<PreserveLevel> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "basic" => Some(PreserveLevel::Basic),
+        "xattrs" => Some(PreserveLevel::Xattrs),
+        "acls" => Some(PreserveLevel::Acls),
+        "everything" | "all" => Some(PreserveLevel::Everything),
+        _ => None,
+    }
+}
+
+pub(crate) fn preserve_level_label(level: PreserveLevel) -> &'static str {
+    match level {
+        PreserveLevel::Basic => "basic",
+        PreserveLevel::Xattrs => "xattrs",
+        PreserveLevel::Acls => "acls",
+        PreserveLevel::Everything => "everything",
     }
 }
 
@@ -947,6 +1051,13 @@ skin=default
         ];
         settings.layout.status_message_timeout_seconds = 42;
         settings.confirmation.confirm_hotlist_delete = false;
+        settings.confirmation.delete_to_trash = true;
+        settings.configuration.resume_copies = true;
+        settings.configuration.verify_copies = true;
+        settings.configuration.continue_on_error = true;
+        settings.configuration.preserve_level = PreserveLevel::Acls;
+        settings.configuration.bandwidth_limit = 10 * 1024 * 1024;
+        settings.advanced.copy_workers = 8;
 
         let source = render_rc_settings_ini(&settings);
         let mut parsed = Settings::default();
@@ -972,6 +1083,13 @@ skin=default
         assert_eq!(parsed.panel_options.filters, settings.panel_options.filters);
         assert_eq!(parsed.layout.status_message_timeout_seconds, 42);
         assert!(!parsed.confirmation.confirm_hotlist_delete);
+        assert!(parsed.confirmation.delete_to_trash);
+        assert!(parsed.configuration.resume_copies);
+        assert!(parsed.configuration.verify_copies);
+        assert!(parsed.configuration.continue_on_error);
+        assert_eq!(parsed.configuration.preserve_level, PreserveLevel::Acls);
+        assert_eq!(parsed.configuration.bandwidth_limit, 10 * 1024 * 1024);
+        assert_eq!(parsed.advanced.copy_workers, 8);
     }
 
     #[test]
@@ -34,7 +34,9 @@ impl AppState {
             paused_find_results: None,
             pending_find_tree_picker: None,
             pending_worker_commands: Vec::new(),
+            path_scheduler: job_scheduler::PathScheduler::default(),
             pending_external_edit_requests: Vec::new(),
+            pending_bulk_rename: None,
             panelized_result_history: [None, None],
             previous_panel_directories: [None, None],
             quick_cd_search: QuickCdSearchWorkflow::default(),
@@ -46,6 +48,13 @@ impl AppState {
             deferred_persist_settings_request: None,
             panel_mkdirs: PanelMkdirTracker::default(),
             tree_mutations: TreeMutationTracker::default(),
+            undo_journal: UndoJournal::default(),
+            pending_undo_journal_save: false,
+            interrupted_jobs: JobQueue::default(),
+            frozen_job_queue: None,
+            pending_job_queue_save: false,
+            plan_request_id: 0,
+            pending_plan_job: None,
             keybinding_hints: KeybindingHints::default(),
             keymap_unknown_actions: 0,
             keymap_invalid_bindings: 0,
@@ -512,6 +521,7 @@ impl AppState {
             Route::FindResults(_) => KeyContext::FindResults,
             Route::Tree(_) => KeyContext::Tree,
             Route::Hotlist => KeyContext::Hotlist,
+            Route::Trash(_) => KeyContext::Trash,
             Route::Help(_) => KeyContext::Help,
             Route::Dialog(dialog) => dialog.key_context(),
         }
@@ -0,0 +1,18 @@
+//! Fixtures shared by the unit tests of several modules.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Creates an empty directory under the system temp directory whose name
+/// starts with `rc-{label}-`; the test removes it when done.
+pub(crate) fn make_temp_dir(label: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-{label}-{stamp}"));
+    fs::create_dir_all(&root).expect("temp dir should be creatable");
+    root
+}
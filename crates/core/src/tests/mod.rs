@@ -219,6 +219,23 @@ fn drain_background(app: &mut AppState) {
                             .map_err(JobError::from_io);
                             let _ = event_tx.send(JobEvent::Finished { id: job_id, result });
                         }
+                        JobRequest::PlanTransfer {
+                            request,
+                            request_id,
+                        } => {
+                            let _ = event_tx.send(JobEvent::Started { id: job_id });
+                            let cancel_flag = job.cancel_flag();
+                            let result = plan_request(request, cancel_flag.as_ref())
+                                .map_err(|error| error.to_string());
+                            app.handle_background_event(BackgroundEvent::TransferPlanned {
+                                request_id: *request_id,
+                                result,
+                            });
+                            let _ = event_tx.send(JobEvent::Finished {
+                                id: job_id,
+                                result: Ok(()),
+                            });
+                        }
                         _ => {
                             execute_worker_job(job, &event_tx);
                         }
@@ -930,6 +947,64 @@ fn rename_dialog_queues_rename_job() {
     fs::remove_dir_all(&root).expect("must remove temp root");
 }
 
+#[cfg(unix)]
+#[test]
+fn chmod_dialog_queues_chmod_job_for_toggled_bits() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-chmod-dialog-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let script = root.join("run.sh");
+    fs::write(&script, "#!/bin/sh\n").expect("must create script");
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o644)).expect("must set script mode");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let script_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == script)
+        .expect("script entry should be visible");
+    app.active_panel_mut().cursor = script_index;
+
+    app.apply(AppCommand::OpenChmodDialog)
+        .expect("chmod dialog should open");
+    app.apply(AppCommand::DialogFocusNext)
+        .expect("chmod dialog should move focus");
+    app.apply(AppCommand::DialogFocusNext)
+        .expect("chmod dialog should move focus");
+    app.apply(AppCommand::DialogInputChar(' '))
+        .expect("chmod dialog should toggle owner execute");
+    app.apply(AppCommand::DialogAccept)
+        .expect("chmod dialog should submit");
+
+    let pending = app.take_pending_worker_commands();
+    assert_eq!(pending.len(), 1, "chmod should enqueue one worker command");
+    match &pending[0] {
+        WorkerCommand::Run(job) => match &job.request {
+            JobRequest::Chmod {
+                targets,
+                file_mask,
+                dir_mask,
+                recursive,
+            } => {
+                assert_eq!(targets, &vec![script.clone()]);
+                assert_eq!(*file_mask, PermissionMask::new(0o100, 0));
+                assert_eq!(*dir_mask, PermissionMask::new(0o100, 0));
+                assert!(!recursive);
+            }
+            _ => panic!("expected chmod request"),
+        },
+        _ => panic!("expected worker run command"),
+    }
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
 #[test]
 fn skin_dialog_emits_selected_skin() {
     let stamp = SystemTime::now()
@@ -2154,6 +2229,213 @@ fn persist_settings_job_coalesces_pending_request() {
     fs::remove_dir_all(&root).expect("must remove temp root");
 }
 
+#[test]
+fn jobs_on_overlapping_paths_wait_for_each_other() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-path-scheduler-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let run_ids = |commands: Vec<WorkerCommand>| {
+        commands
+            .into_iter()
+            .filter_map(|command| match command {
+                // Panel refreshes follow finished jobs; they touch nothing.
+                WorkerCommand::Run(job) if job.request.path_footprint().is_some() => Some(job.id),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let move_id = app.enqueue_worker_job_request(JobRequest::Move {
+        sources: vec![root.join("a")],
+        destination_dir: root.join("b"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::default(),
+    });
+    let delete_id = app.enqueue_worker_job_request(JobRequest::Delete {
+        targets: vec![root.join("b")],
+    });
+    let unrelated_id = app.enqueue_worker_job_request(JobRequest::Mkdir {
+        path: root.join("c"),
+    });
+    let chmod_id = app.enqueue_worker_job_request(JobRequest::Chmod {
+        targets: vec![root.join("b").join("c")],
+        file_mask: PermissionMask::default(),
+        dir_mask: PermissionMask::default(),
+        recursive: true,
+    });
+    assert_eq!(
+        run_ids(app.take_pending_worker_commands()),
+        vec![move_id, unrelated_id],
+        "only jobs on unrelated paths should run side by side"
+    );
+    let wait = app
+        .jobs
+        .job(delete_id)
+        .and_then(|job| job.waiting_for.clone())
+        .expect("delete should wait for the move");
+    assert_eq!(wait.job, move_id);
+    assert_eq!(wait.path, root.join("b").join("a"));
+    assert_eq!(
+        app.jobs
+            .job(chmod_id)
+            .and_then(|job| job.waiting_for.as_ref())
+            .map(|wait| wait.position),
+        Some(2),
+        "a later job should queue behind the held one"
+    );
+
+    app.handle_job_event(JobEvent::Started { id: move_id });
+    app.handle_job_event(JobEvent::Finished {
+        id: move_id,
+        result: Ok(()),
+    });
+    assert_eq!(run_ids(app.take_pending_worker_commands()), vec![delete_id]);
+    assert!(
+        app.jobs
+            .job(delete_id)
+            .is_some_and(|job| job.waiting_for.is_none())
+    );
+
+    assert!(app.request_cancel_for_job(chmod_id));
+    assert!(
+        app.take_pending_worker_commands().is_empty(),
+        "a held job is canceled without the worker"
+    );
+    assert_eq!(
+        app.jobs.job(chmod_id).map(|job| job.status),
+        Some(JobStatus::Canceled)
+    );
+    app.handle_job_event(JobEvent::Finished {
+        id: delete_id,
+        result: Ok(()),
+    });
+    assert!(run_ids(app.take_pending_worker_commands()).is_empty());
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn jobs_screen_reorders_jobs_waiting_for_one_device() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-device-queue-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    // All on the temp directory's device, on trees of their own.
+    let [first, second, third] = ["a", "b", "c"].map(|name| {
+        app.enqueue_worker_job_request(JobRequest::Copy {
+            sources: vec![root.join(name)],
+            destination_dir: root.join(format!("{name}-copy")),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        })
+    });
+    let pending = app.take_pending_worker_commands();
+    assert!(
+        matches!(&pending[..], [WorkerCommand::Run(job)] if job.id == first),
+        "copies onto one device should take turns"
+    );
+    let position = |app: &AppState, id: JobId| {
+        app.jobs
+            .job(id)
+            .and_then(|job| job.waiting_for.as_ref())
+            .map(|wait| (wait.position, wait.shared_device))
+    };
+    assert_eq!(position(&app, second), Some((1, true)));
+    assert_eq!(position(&app, third), Some((2, true)));
+
+    app.apply(AppCommand::OpenJobsScreen)
+        .expect("jobs screen should open");
+    app.jobs_cursor = app
+        .jobs
+        .jobs()
+        .iter()
+        .position(|job| job.id == third)
+        .expect("third job should be listed");
+    app.apply(AppCommand::RunJobNext)
+        .expect("job should move to the front");
+    assert_eq!(position(&app, third), Some((1, true)));
+    assert_eq!(position(&app, second), Some((2, true)));
+    assert!(app.status_line.contains("is now #1 in the queue"));
+    app.apply(AppCommand::MoveJobUp)
+        .expect("first job should stay first");
+    assert!(app.status_line.contains("already first"));
+
+    app.handle_job_event(JobEvent::Finished {
+        id: first,
+        result: Err(JobError::canceled()),
+    });
+    let pending = app.take_pending_worker_commands();
+    assert!(
+        matches!(&pending[..], [WorkerCommand::Run(job)] if job.id == third),
+        "the job run next should start when the device frees up"
+    );
+    app.apply(AppCommand::MoveJobDown)
+        .expect("started job should not move");
+    assert!(app.status_line.contains("is not waiting in the queue"));
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn pausing_a_copy_lets_the_next_copy_on_its_device_start() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-device-pause-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let [first, second] = ["a", "b"].map(|name| {
+        app.enqueue_worker_job_request(JobRequest::Copy {
+            sources: vec![root.join(name)],
+            destination_dir: root.join(format!("{name}-copy")),
+            destination_names: None,
+            overwrite: OverwritePolicy::Skip,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+            follow_links: FollowLinks::Never,
+            hard_links: false,
+        })
+    });
+    let _ = app.take_pending_worker_commands();
+    app.handle_job_event(JobEvent::Started { id: first });
+
+    app.apply(AppCommand::OpenJobsScreen)
+        .expect("jobs screen should open");
+    app.jobs_cursor = app
+        .jobs
+        .jobs()
+        .iter()
+        .position(|job| job.id == first)
+        .expect("first job should be listed");
+    app.apply(AppCommand::ToggleJobPause)
+        .expect("running copy should pause");
+    let pending = app.take_pending_worker_commands();
+    assert!(
+        matches!(&pending[..], [WorkerCommand::Run(job)] if job.id == second),
+        "the waiting copy should start while the one on its device is paused"
+    );
+    assert_eq!(
+        app.jobs.job(second).and_then(|job| job.waiting_for.clone()),
+        None
+    );
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
 #[test]
 fn persist_settings_job_defers_latest_while_active() {
     let stamp = SystemTime::now()
@@ -2457,6 +2739,161 @@ fn delete_command_queues_job_only_after_confirmation() {
     fs::remove_dir_all(&root).expect("must remove temp root");
 }
 
+#[test]
+fn delete_dialog_defaults_to_trash_and_shift_delete_stays_permanent() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-trash-dialog-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let victim = root.join("victim.txt");
+    fs::write(&victim, "victim").expect("must create victim file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    app.settings_mut().confirmation.delete_to_trash = true;
+    let victim_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == victim)
+        .expect("victim entry should be visible");
+    app.active_panel_mut().cursor = victim_index;
+
+    app.apply(AppCommand::Delete)
+        .expect("delete should open mode dialog");
+    app.apply(AppCommand::DialogAccept)
+        .expect("mode dialog should submit");
+    let pending = app.take_pending_worker_commands();
+    assert!(matches!(
+        &pending[..],
+        [WorkerCommand::Run(job)]
+            if job.request == JobRequest::Trash { targets: vec![victim.clone()] }
+    ));
+    // The delete below works on the same file, so it would wait for this one.
+    let trash_id = app.jobs.last_job().map(|job| job.id).expect("trash job");
+    app.handle_job_event(JobEvent::Finished {
+        id: trash_id,
+        result: Err(JobError::canceled()),
+    });
+
+    app.apply(AppCommand::DeletePermanently)
+        .expect("permanent delete should open confirm dialog");
+    assert_eq!(app.route_depth(), 2);
+    app.apply(AppCommand::DialogAccept)
+        .expect("confirm dialog should submit");
+    let pending = app.take_pending_worker_commands();
+    assert!(matches!(
+        &pending[..],
+        [WorkerCommand::Run(job)]
+            if job.request == JobRequest::Delete { targets: vec![victim.clone()] }
+    ));
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn undo_last_operation_reverts_rename_and_refuses_after_divergence() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-undo-flow-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let before = root.join("before.txt");
+    let after = root.join("after.txt");
+    fs::write(&before, "payload").expect("must create source file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let rename = JobRequest::Rename {
+        source: before.clone(),
+        destination: after.clone(),
+    };
+    app.queue_filesystem_job(rename.clone(), OperationOrigin::Panel(ActivePanel::Left));
+    drain_background(&mut app);
+    assert_eq!(app.undo_journal().len(), 1);
+    assert!(app.take_pending_undo_journal_save());
+
+    app.apply(AppCommand::UndoLastOperation)
+        .expect("undo should queue inverse job");
+    assert!(app.undo_journal().is_empty());
+    drain_background(&mut app);
+    assert_eq!(
+        fs::read_to_string(&before).expect("source should be back"),
+        "payload"
+    );
+    assert!(!after.exists());
+    assert!(
+        app.undo_journal().is_empty(),
+        "undo jobs must not be journaled"
+    );
+
+    app.queue_filesystem_job(rename, OperationOrigin::Panel(ActivePanel::Left));
+    drain_background(&mut app);
+    fs::write(&before, "newer").expect("must recreate source path");
+    app.apply(AppCommand::UndoLastOperation)
+        .expect("undo should report divergence");
+    assert!(app.take_pending_worker_commands().is_empty());
+    assert!(
+        app.status_line.contains("exists again"),
+        "{}",
+        app.status_line
+    );
+    assert_eq!(fs::read_to_string(&before).expect("new file kept"), "newer");
+    assert!(after.exists());
+    assert_eq!(
+        app.undo_journal().len(),
+        1,
+        "a refused undo must stay in the journal"
+    );
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn moves_that_overwrite_their_destination_are_not_undoable() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-undo-overwriting-move-{stamp}"));
+    let destination = root.join("dest");
+    fs::create_dir_all(&destination).expect("must create destination");
+    let source = root.join("a.txt");
+    fs::write(&source, "new").expect("must create source file");
+    fs::write(destination.join("a.txt"), "old").expect("must create overwritten file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    app.queue_filesystem_job(
+        JobRequest::Move {
+            sources: vec![source.clone()],
+            destination_dir: destination.clone(),
+            destination_names: None,
+            overwrite: OverwritePolicy::Overwrite,
+            verify: false,
+            preserve: PreserveLevel::Basic,
+        },
+        OperationOrigin::Panel(ActivePanel::Left),
+    );
+    drain_background(&mut app);
+    assert_eq!(
+        fs::read_to_string(destination.join("a.txt")).expect("moved file should exist"),
+        "new"
+    );
+    assert!(
+        app.undo_journal().is_empty(),
+        "undoing the move would lose the file it replaced"
+    );
+
+    app.apply(AppCommand::UndoLastOperation)
+        .expect("undo should report the empty journal");
+    assert!(app.take_pending_worker_commands().is_empty());
+    assert_eq!(app.status_line, "Nothing to undo");
+    assert!(!source.exists());
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
 #[test]
 fn copy_command_uses_destination_and_policy_dialogs() {
     let stamp = SystemTime::now()
@@ -2498,7 +2935,12 @@ fn copy_command_uses_destination_and_policy_dialogs() {
             JobRequest::Copy {
                 sources,
                 destination_dir,
+                destination_names: None,
                 overwrite,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+                follow_links: FollowLinks::Never,
+                hard_links: false,
             } => {
                 assert_eq!(sources, &vec![source.clone()]);
                 assert_eq!(destination_dir, &root);
@@ -2513,12 +2955,12 @@ fn copy_command_uses_destination_and_policy_dialogs() {
 }
 
 #[test]
-fn copy_relative_destination_is_resolved_from_active_panel() {
+fn relative_symlink_command_uses_destination_and_policy_dialogs() {
     let stamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("time should be monotonic")
         .as_nanos();
-    let root = env::temp_dir().join(format!("rc-copy-relative-destination-{stamp}"));
+    let root = env::temp_dir().join(format!("rc-symlink-dialog-{stamp}"));
     fs::create_dir_all(&root).expect("must create temp root");
     let source = root.join("a.txt");
     fs::write(&source, "a").expect("must create source file");
@@ -2532,20 +2974,1071 @@ fn copy_relative_destination_is_resolved_from_active_panel() {
         .expect("source entry should be visible");
     app.active_panel_mut().cursor = source_index;
 
-    app.start_copy_dialog();
-    app.finish_dialog(DialogResult::InputSubmitted(String::from("dest")));
+    app.apply(AppCommand::OpenRelativeSymlinkDialog)
+        .expect("relative symlink should open destination dialog");
+    assert_eq!(app.status_line, "Relative symlink: choose destination");
+    app.apply(AppCommand::DialogAccept)
+        .expect("destination dialog should submit");
+    app.apply(AppCommand::DialogAccept)
+        .expect("policy dialog should submit");
 
-    match app.top_route() {
-        Route::Dialog(dialog) => match dialog.action() {
-            Some(PendingDialogAction::TransferOverwrite {
-                destination_dir, ..
-            }) => {
-                assert_eq!(destination_dir, &root.join("dest"));
+    let pending = app.take_pending_worker_commands();
+    assert_eq!(
+        pending.len(),
+        1,
+        "symlink should enqueue one worker command"
+    );
+    match &pending[0] {
+        WorkerCommand::Run(job) => match &job.request {
+            JobRequest::Symlink {
+                sources,
+                destination_dir,
+                relative,
+                overwrite,
+            } => {
+                assert_eq!(sources, &vec![source.clone()]);
+                assert_eq!(destination_dir, &root);
+                assert!(*relative);
+                assert_eq!(*overwrite, app.overwrite_policy());
             }
-            other => panic!("expected transfer overwrite action, got {other:?}"),
+            _ => panic!("expected symlink job request"),
         },
-        other => panic!("expected transfer overwrite dialog, got {other:?}"),
+        _ => panic!("expected queued worker run command"),
     }
 
     fs::remove_dir_all(&root).expect("must remove temp root");
 }
+
+#[cfg(unix)]
+#[test]
+fn edit_symlink_dialog_warns_before_queueing_dangling_target() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-edit-symlink-dialog-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    fs::write(root.join("a.txt"), "a").expect("must create link target");
+    let link = root.join("link");
+    std::os::unix::fs::symlink("a.txt", &link).expect("must create symlink");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let link_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == link)
+        .expect("link entry should be visible");
+    app.active_panel_mut().cursor = link_index;
+
+    app.apply(AppCommand::OpenEditSymlinkDialog)
+        .expect("edit symlink dialog should open");
+    let Some(Route::Dialog(dialog)) = app.routes.last() else {
+        panic!("expected edit symlink dialog");
+    };
+    let DialogKind::Input(input) = &dialog.kind else {
+        panic!("expected input dialog");
+    };
+    assert_eq!(input.value, "a.txt");
+
+    for _ in 0.."a.txt".len() {
+        app.apply(AppCommand::DialogBackspace)
+            .expect("input should accept backspace");
+    }
+    for ch in "missing.txt".chars() {
+        app.apply(AppCommand::DialogInputChar(ch))
+            .expect("input should accept typing");
+    }
+    app.apply(AppCommand::DialogAccept)
+        .expect("edit symlink dialog should submit");
+    assert_eq!(app.status_line, "Edit symlink: new target does not resolve");
+    assert!(app.take_pending_worker_commands().is_empty());
+
+    app.apply(AppCommand::DialogAccept)
+        .expect("dangling target confirmation should submit");
+    let pending = app.take_pending_worker_commands();
+    assert_eq!(pending.len(), 1, "edit symlink should enqueue one job");
+    match &pending[0] {
+        WorkerCommand::Run(job) => assert_eq!(
+            job.request,
+            JobRequest::EditSymlink {
+                link: link.clone(),
+                target: PathBuf::from("missing.txt"),
+            }
+        ),
+        _ => panic!("expected queued worker run command"),
+    }
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn copy_relative_destination_is_resolved_from_active_panel() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-copy-relative-destination-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let source = root.join("a.txt");
+    fs::write(&source, "a").expect("must create source file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let source_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == source)
+        .expect("source entry should be visible");
+    app.active_panel_mut().cursor = source_index;
+
+    app.start_copy_dialog();
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("dest")));
+
+    match app.top_route() {
+        Route::Dialog(dialog) => match dialog.action() {
+            Some(PendingDialogAction::TransferOverwrite {
+                destination_dir, ..
+            }) => {
+                assert_eq!(destination_dir, &root.join("dest"));
+            }
+            other => panic!("expected transfer overwrite action, got {other:?}"),
+        },
+        other => panic!("expected transfer overwrite dialog, got {other:?}"),
+    }
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn bandwidth_limit_dialogs_cap_the_selected_job_and_all_jobs() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-bandwidth-limit-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let job_id = app.enqueue_worker_job_request(JobRequest::Copy {
+        sources: vec![root.join("a.txt")],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    });
+    app.apply(AppCommand::OpenJobsScreen)
+        .expect("jobs screen should open");
+    app.apply(AppCommand::OpenJobBandwidthLimit)
+        .expect("job limit dialog should open");
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("512K")));
+    assert_eq!(
+        app.jobs.job(job_id).map(|job| job.bandwidth_limit),
+        Some(512 * 1024)
+    );
+
+    app.apply(AppCommand::OpenGlobalBandwidthLimit)
+        .expect("global limit dialog should open");
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("fast")));
+    assert!(app.status_line.contains("Invalid bandwidth limit 'fast'"));
+    assert_eq!(app.settings.configuration.bandwidth_limit, 0);
+
+    app.apply(AppCommand::OpenGlobalBandwidthLimit)
+        .expect("global limit dialog should open");
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("2M")));
+    assert_eq!(app.settings.configuration.bandwidth_limit, 2 * 1024 * 1024);
+    assert_eq!(app.jobs.global_bandwidth_limit(), 2 * 1024 * 1024);
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn jobs_screen_pauses_selected_and_all_jobs() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-pause-jobs-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let copy_id = app.enqueue_worker_job_request(JobRequest::Copy {
+        sources: vec![root.join("a.txt")],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    });
+    let delete_id = app.enqueue_worker_job_request(JobRequest::Delete {
+        targets: vec![root.join("b.txt")],
+    });
+    let status = |app: &AppState, id| app.jobs.job(id).map(|job| job.status);
+
+    app.apply(AppCommand::OpenJobsScreen)
+        .expect("jobs screen should open");
+    app.apply(AppCommand::ToggleJobPause)
+        .expect("selected job should pause");
+    assert_eq!(status(&app, copy_id), Some(JobStatus::Paused));
+    assert_eq!(status(&app, delete_id), Some(JobStatus::Queued));
+
+    app.apply(AppCommand::TogglePauseAllJobs)
+        .expect("remaining jobs should pause");
+    assert_eq!(status(&app, delete_id), Some(JobStatus::Paused));
+    assert!(app.status_line.contains("Paused 1 job(s)"));
+
+    app.apply(AppCommand::TogglePauseAllJobs)
+        .expect("all jobs should resume");
+    assert_eq!(status(&app, copy_id), Some(JobStatus::Queued));
+    assert_eq!(status(&app, delete_id), Some(JobStatus::Queued));
+    assert!(app.status_line.contains("Resumed 2 job(s)"));
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn jobs_screen_retries_failed_job_whole_or_failed_items_only() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-retry-jobs-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let (first, second) = (root.join("a.txt"), root.join("b.txt"));
+    let job_id = app.enqueue_worker_job_request(JobRequest::Copy {
+        sources: vec![first.clone(), second.clone()],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    });
+    let _ = app.take_pending_worker_commands();
+    app.handle_job_event(JobEvent::Started { id: job_id });
+    app.handle_job_event(JobEvent::SourceCompleted {
+        id: job_id,
+        source: first.clone(),
+    });
+    app.handle_job_event(JobEvent::Finished {
+        id: job_id,
+        result: Err(JobError::from_io(io::Error::other(
+            "no space left on device",
+        ))),
+    });
+    app.apply(AppCommand::OpenJobsScreen)
+        .expect("jobs screen should open");
+
+    let retried_sources = |app: &mut AppState| match app.take_pending_worker_commands().as_slice() {
+        [WorkerCommand::Run(job)] => match &job.request {
+            JobRequest::Copy { sources, .. } => sources.clone(),
+            other => panic!("expected copy retry, got {other:?}"),
+        },
+        other => panic!("expected one queued retry, got {other:?}"),
+    };
+    app.apply(AppCommand::RetryFailedItems)
+        .expect("failed items should be retried");
+    assert_eq!(retried_sources(&mut app), vec![second.clone()]);
+    // The next retry writes where this one does, so it would wait for it.
+    let retry_id = app.jobs.last_job().map(|job| job.id).expect("retry job");
+    app.handle_job_event(JobEvent::Finished {
+        id: retry_id,
+        result: Err(JobError::canceled()),
+    });
+    app.apply(AppCommand::RetryJob)
+        .expect("whole job should be retried");
+    assert_eq!(retried_sources(&mut app), vec![first, second]);
+    assert!(app.status_line.contains(&format!("Retrying job #{job_id}")));
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn unfinished_jobs_survive_quit_and_resume_without_finished_sources() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-job-queue-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    app.settings_mut().confirmation.confirm_quit = true;
+    let (first, second) = (root.join("a.txt"), root.join("b.txt"));
+    let job_id = app.enqueue_worker_job_request(JobRequest::Copy {
+        sources: vec![first.clone(), second.clone()],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    });
+    let _ = app.take_pending_worker_commands();
+    app.handle_job_event(JobEvent::Started { id: job_id });
+    app.handle_job_event(JobEvent::SourceCompleted {
+        id: job_id,
+        source: first.clone(),
+    });
+    assert!(app.take_pending_job_queue_save());
+    assert_eq!(app.job_queue().jobs()[0].completed, vec![first.clone()]);
+
+    app.apply(AppCommand::Quit).expect("quit should ask first");
+    app.finish_dialog(DialogResult::ConfirmAccepted);
+    app.handle_job_event(JobEvent::Finished {
+        id: job_id,
+        result: Err(JobError::canceled()),
+    });
+    assert!(app.take_pending_job_queue_save());
+    let queue = JobQueue::parse(&app.job_queue().render());
+    assert_eq!(queue.len(), 1, "quitting must not drop the canceled job");
+
+    let mut next = AppState::new(root.clone()).expect("app should initialize");
+    next.offer_interrupted_jobs(queue);
+    assert_eq!(next.job_queue().len(), 1, "unanswered jobs stay queued");
+    next.finish_dialog(DialogResult::ListboxSubmitted {
+        index: Some(0),
+        value: None,
+    });
+    match next.take_pending_worker_commands().as_slice() {
+        [WorkerCommand::Run(job)] => match &job.request {
+            JobRequest::Copy { sources, .. } => assert_eq!(sources, &vec![second]),
+            other => panic!("expected resumed copy, got {other:?}"),
+        },
+        other => panic!("expected one resumed job, got {other:?}"),
+    }
+    assert!(next.status_line.contains("Resumed 1 unfinished job(s)"));
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn resumed_directory_copy_completes_its_partial_destination() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-job-queue-partial-{stamp}"));
+    let tree = root.join("tree");
+    let partial = root.join("dest").join("tree");
+    fs::create_dir_all(tree.join("sub")).expect("must create source tree");
+    fs::create_dir_all(&partial).expect("must create partial copy");
+    fs::write(tree.join("a.txt"), "alpha").expect("must write a");
+    fs::write(tree.join("b.txt"), "bravo").expect("must write b");
+    fs::write(tree.join("sub").join("c.txt"), "charlie").expect("must write c");
+    // `a.txt` was copied before the interruption; the same size and time
+    // keep it, so the differing bytes show it was not copied again.
+    fs::write(partial.join("a.txt"), "ALPHA").expect("must write copied a");
+    let modified = filetime::FileTime::from_last_modification_time(
+        &fs::metadata(tree.join("a.txt")).expect("a should exist"),
+    );
+    filetime::set_file_mtime(partial.join("a.txt"), modified).expect("must set a's time");
+    fs::write(partial.join("b.txt"), "br").expect("must write cut-off b");
+
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let job_id = app.enqueue_worker_job_request(JobRequest::Copy {
+        sources: vec![tree.clone()],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    });
+    let _ = app.take_pending_worker_commands();
+    app.handle_job_event(JobEvent::Started { id: job_id });
+    let claimed = ClaimedDestination {
+        source: tree.clone(),
+        destination: partial.clone(),
+    };
+    app.handle_job_event(JobEvent::DestinationClaimed {
+        id: job_id,
+        claimed: claimed.clone(),
+    });
+    assert!(app.take_pending_job_queue_save());
+    let queue = JobQueue::parse(&app.job_queue().render());
+    assert_eq!(queue.jobs()[0].claimed, Some(claimed));
+
+    let mut next = AppState::new(root.clone()).expect("app should initialize");
+    next.offer_interrupted_jobs(queue);
+    next.finish_dialog(DialogResult::ListboxSubmitted {
+        index: Some(0),
+        value: None,
+    });
+    drain_background(&mut next);
+
+    let resumed = next.jobs.last_job().expect("resumed job should exist");
+    assert_eq!(resumed.status, JobStatus::Succeeded);
+    assert_eq!(
+        fs::read_to_string(partial.join("a.txt")).expect("a should be kept"),
+        "ALPHA"
+    );
+    assert_eq!(
+        fs::read_to_string(partial.join("b.txt")).expect("b should be copied again"),
+        "bravo"
+    );
+    assert_eq!(
+        fs::read_to_string(partial.join("sub").join("c.txt")).expect("c should be copied"),
+        "charlie"
+    );
+    assert!(!root.join("dest").join("tree (1)").exists());
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn resumed_move_resolves_a_destination_it_did_not_claim() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-job-queue-unclaimed-{stamp}"));
+    let tree = root.join("tree");
+    let existing = root.join("dest").join("tree");
+    fs::create_dir_all(&tree).expect("must create source tree");
+    fs::create_dir_all(&existing).expect("must create existing destination");
+    fs::write(tree.join("a.txt"), "alpha").expect("must write a");
+    fs::write(existing.join("a.txt"), "mine").expect("must write the user's a");
+
+    // The job stopped while it still asked about the existing destination,
+    // so it never claimed it.
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let job_id = app.enqueue_worker_job_request(JobRequest::Move {
+        sources: vec![tree.clone()],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+    });
+    let _ = app.take_pending_worker_commands();
+    app.handle_job_event(JobEvent::Started { id: job_id });
+    let queue = JobQueue::parse(&app.job_queue().render());
+    assert_eq!(queue.jobs()[0].claimed, None);
+
+    let mut next = AppState::new(root.clone()).expect("app should initialize");
+    next.offer_interrupted_jobs(queue);
+    next.finish_dialog(DialogResult::ListboxSubmitted {
+        index: Some(0),
+        value: None,
+    });
+    drain_background(&mut next);
+
+    let resumed = next.jobs.last_job().expect("resumed job should exist");
+    assert_eq!(resumed.status, JobStatus::Succeeded);
+    assert_eq!(
+        fs::read_to_string(existing.join("a.txt")).expect("the user's a should remain"),
+        "mine"
+    );
+    assert_eq!(
+        fs::read_to_string(tree.join("a.txt")).expect("the skipped source should remain"),
+        "alpha"
+    );
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn copy_preview_lists_planned_actions_and_returns_to_the_confirmation() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-copy-preview-{stamp}"));
+    let destination = root.join("dest");
+    fs::create_dir_all(&destination).expect("must create destination");
+    let source = root.join("a.txt");
+    fs::write(&source, "new").expect("must create source file");
+    fs::write(destination.join("a.txt"), "old").expect("must create conflict");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let source_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == source)
+        .expect("source entry should be visible");
+    app.active_panel_mut().cursor = source_index;
+
+    app.start_copy_dialog();
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("dest")));
+    app.apply(AppCommand::DialogInputChar('p'))
+        .expect("p should start a preview");
+    drain_background(&mut app);
+    match app.top_route() {
+        Route::Dialog(dialog) => match &dialog.kind {
+            DialogKind::Listbox(listbox) => {
+                assert_eq!(dialog.title, "Preview");
+                assert!(
+                    listbox.items[0].contains("1 destination(s) already exist"),
+                    "{:?}",
+                    listbox.items
+                );
+                assert!(
+                    listbox
+                        .items
+                        .iter()
+                        .any(|item| item.contains("a.txt -> ") && !item.starts_with("create")),
+                    "{:?}",
+                    listbox.items
+                );
+            }
+            other => panic!("expected preview listbox, got {other:?}"),
+        },
+        other => panic!("expected preview dialog, got {other:?}"),
+    }
+
+    app.apply(AppCommand::DialogCancel)
+        .expect("preview should close");
+    assert!(matches!(
+        app.top_route(),
+        Route::Dialog(dialog) if matches!(dialog.kind, DialogKind::Listbox(_)) && dialog.title != "Preview"
+    ));
+    assert_eq!(
+        fs::read_to_string(destination.join("a.txt")).expect("conflict should remain"),
+        "old"
+    );
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn copy_masks_preview_names_reject_collisions_and_rename_copies() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-copy-masks-{stamp}"));
+    let destination = root.join("dest");
+    fs::create_dir_all(&destination).expect("must create destination");
+    for name in ["a.jpeg", "b.jpeg"] {
+        fs::write(root.join(name), name).expect("must create source file");
+    }
+
+    let mut app = app_with_loaded_panels(root.clone());
+    for name in ["a.jpeg", "b.jpeg"] {
+        let index = app
+            .active_panel()
+            .entries
+            .iter()
+            .position(|entry| entry.path == root.join(name))
+            .expect("source entry should be visible");
+        app.active_panel_mut().cursor = index;
+        app.apply(AppCommand::ToggleTag)
+            .expect("tagging should succeed");
+    }
+    app.start_copy_dialog();
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("dest")));
+    app.apply(AppCommand::DialogInputChar('m'))
+        .expect("m should open the masks");
+    let type_destination_mask = |app: &mut AppState, mask: &str| {
+        for _ in 0..16 {
+            app.apply(AppCommand::DialogBackspace)
+                .expect("backspace should edit the mask");
+        }
+        for ch in mask.chars() {
+            app.apply(AppCommand::DialogInputChar(ch))
+                .expect("typing should edit the mask");
+        }
+    };
+    let mask_preview = |app: &AppState| match app.top_route() {
+        Route::Dialog(dialog) => match &dialog.kind {
+            DialogKind::Mask(mask) => mask.preview.clone(),
+            other => panic!("expected mask dialog, got {other:?}"),
+        },
+        other => panic!("expected mask dialog, got {other:?}"),
+    };
+    assert_eq!(
+        mask_preview(&app),
+        vec!["a.jpeg (unchanged)", "b.jpeg (unchanged)"]
+    );
+
+    type_destination_mask(&mut app, "same.jpg");
+    assert!(
+        mask_preview(&app)[0].contains("multiple sources have the same destination"),
+        "{:?}",
+        mask_preview(&app)
+    );
+    app.apply(AppCommand::DialogAccept)
+        .expect("colliding masks should be rejected");
+    assert!(
+        app.status_line.starts_with("Masks rejected"),
+        "{}",
+        app.status_line
+    );
+
+    type_destination_mask(&mut app, "*.jpg");
+    assert_eq!(
+        mask_preview(&app),
+        vec!["a.jpeg -> a.jpg", "b.jpeg -> b.jpg"]
+    );
+    app.apply(AppCommand::DialogAccept)
+        .expect("masks should apply");
+    app.apply(AppCommand::DialogAccept)
+        .expect("overwrite policy should submit");
+    drain_background(&mut app);
+
+    assert_eq!(
+        fs::read_to_string(destination.join("a.jpg")).expect("a.jpg should be copied"),
+        "a.jpeg"
+    );
+    assert!(destination.join("b.jpg").exists());
+    assert!(!destination.join("a.jpeg").exists());
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn bulk_rename_refuses_deleted_lines_and_swaps_names_in_one_move() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-bulk-rename-test-{stamp}"));
+    fs::create_dir_all(&root).expect("must create root");
+    for name in ["a.txt", "b.txt", "c.txt"] {
+        fs::write(root.join(name), name).expect("must create source file");
+    }
+
+    let mut app = app_with_loaded_panels(root.clone());
+    for name in ["a.txt", "b.txt", "c.txt"] {
+        let index = app
+            .active_panel()
+            .entries
+            .iter()
+            .position(|entry| entry.path == root.join(name))
+            .expect("source entry should be visible");
+        app.active_panel_mut().cursor = index;
+        app.apply(AppCommand::ToggleTag)
+            .expect("tagging should succeed");
+    }
+    let edit_names = |app: &mut AppState, edited: &str| {
+        app.start_bulk_rename_with_resolver(|| Some(String::from("vi")));
+        let requests = app.take_pending_external_edit_requests();
+        assert_eq!(requests.len(), 1, "bulk rename should open the editor");
+        let request = &requests[0];
+        assert_eq!(request.cwd, root);
+        assert_eq!(
+            fs::read_to_string(&request.path).expect("name list should be written"),
+            "a.txt\nb.txt\nc.txt\n"
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&request.path)
+                .expect("name list should exist")
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600, "name list should be private");
+        }
+        fs::write(&request.path, edited).expect("name list should be editable");
+        app.finish_external_edit(request, Ok(()));
+        assert!(!request.path.exists(), "name list should be removed");
+    };
+
+    let jobs_before = app.jobs.jobs().len();
+    edit_names(&mut app, "a.txt\nc.txt\n");
+    assert!(
+        app.status_line.contains("1 line(s) deleted"),
+        "{}",
+        app.status_line
+    );
+    assert_eq!(app.jobs.jobs().len(), jobs_before);
+
+    edit_names(&mut app, "b.txt\na.txt\nnotes.txt\n");
+    assert_eq!(app.status_line, "Bulk rename: renaming 3 item(s)");
+    assert_eq!(app.jobs.jobs().len(), jobs_before + 1);
+    drain_background(&mut app);
+
+    for (name, payload) in [
+        ("a.txt", "b.txt"),
+        ("b.txt", "a.txt"),
+        ("notes.txt", "c.txt"),
+    ] {
+        assert_eq!(
+            fs::read_to_string(root.join(name)).expect("renamed file should exist"),
+            payload
+        );
+    }
+    assert!(!root.join("c.txt").exists());
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn copy_overwrite_dialog_cycles_which_links_the_copy_follows() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-copy-follow-links-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let source = root.join("a.txt");
+    fs::write(&source, "a").expect("must create source file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let source_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == source)
+        .expect("source entry should be visible");
+    app.active_panel_mut().cursor = source_index;
+    let footer = |app: &AppState| match app.top_route() {
+        Route::Dialog(dialog) => match &dialog.kind {
+            DialogKind::Listbox(listbox) => listbox.footer_hint.clone().unwrap_or_default(),
+            other => panic!("expected overwrite listbox, got {other:?}"),
+        },
+        other => panic!("expected overwrite dialog, got {other:?}"),
+    };
+
+    app.start_move_dialog();
+    app.apply(AppCommand::DialogAccept)
+        .expect("move destination should submit");
+    assert!(!footer(&app).contains("links"), "{}", footer(&app));
+    app.apply(AppCommand::DialogCancel)
+        .expect("move should cancel");
+
+    app.start_copy_dialog();
+    app.apply(AppCommand::DialogAccept)
+        .expect("copy destination should submit");
+    assert!(footer(&app).contains("l links: never"), "{}", footer(&app));
+    app.apply(AppCommand::DialogInputChar('l'))
+        .expect("l should cycle follow links");
+    assert!(
+        footer(&app).contains("l links: command line"),
+        "{}",
+        footer(&app)
+    );
+    assert_eq!(app.status_line, "Copy: follow links command line");
+
+    app.apply(AppCommand::DialogAccept)
+        .expect("policy dialog should submit");
+    let pending = app.take_pending_worker_commands();
+    match &pending[..] {
+        [WorkerCommand::Run(job)] => match &job.request {
+            JobRequest::Copy { follow_links, .. } => {
+                assert_eq!(*follow_links, FollowLinks::CommandLine);
+            }
+            other => panic!("expected copy job request, got {other:?}"),
+        },
+        other => panic!("expected one queued copy, got {other:?}"),
+    }
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn copy_overwrite_dialog_toggles_keeping_hard_links() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-copy-hard-links-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let source = root.join("a.txt");
+    fs::write(&source, "a").expect("must create source file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let source_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == source)
+        .expect("source entry should be visible");
+    app.active_panel_mut().cursor = source_index;
+    let footer = |app: &AppState| match app.top_route() {
+        Route::Dialog(dialog) => match &dialog.kind {
+            DialogKind::Listbox(listbox) => listbox.footer_hint.clone().unwrap_or_default(),
+            other => panic!("expected overwrite listbox, got {other:?}"),
+        },
+        other => panic!("expected overwrite dialog, got {other:?}"),
+    };
+
+    app.start_move_dialog();
+    app.apply(AppCommand::DialogAccept)
+        .expect("move destination should submit");
+    app.apply(AppCommand::DialogInputChar('h'))
+        .expect("h should be ignored by moves");
+    assert!(!footer(&app).contains("hard links"), "{}", footer(&app));
+    app.apply(AppCommand::DialogCancel)
+        .expect("move should cancel");
+
+    app.start_copy_dialog();
+    app.apply(AppCommand::DialogAccept)
+        .expect("copy destination should submit");
+    assert!(
+        footer(&app).contains("h hard links: copy"),
+        "{}",
+        footer(&app)
+    );
+    app.apply(AppCommand::DialogInputChar('h'))
+        .expect("h should toggle hard links");
+    assert!(
+        footer(&app).contains("h hard links: keep"),
+        "{}",
+        footer(&app)
+    );
+    assert_eq!(app.status_line, "Copy: hard links keep");
+
+    app.apply(AppCommand::DialogAccept)
+        .expect("policy dialog should submit");
+    let pending = app.take_pending_worker_commands();
+    match &pending[..] {
+        [WorkerCommand::Run(job)] => match &job.request {
+            JobRequest::Copy { hard_links, .. } => assert!(*hard_links),
+            other => panic!("expected copy job request, got {other:?}"),
+        },
+        other => panic!("expected one queued copy, got {other:?}"),
+    }
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn item_failure_prompt_answers_job_and_jobs_screen_lists_skipped_items() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-item-failures-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+
+    let mut app = AppState::new(root.clone()).expect("app should initialize");
+    let failure = |name: &str| ItemFailure {
+        path: root.join(name),
+        message: String::from("Permission denied (os error 13)"),
+    };
+    let job_id = app.enqueue_worker_job_request(JobRequest::Copy {
+        sources: vec![root.join("a.txt"), root.join("b.txt")],
+        destination_dir: root.join("dest"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    });
+    let _ = app.take_pending_worker_commands();
+    app.handle_job_event(JobEvent::Started { id: job_id });
+
+    app.handle_job_event(JobEvent::ItemFailed {
+        id: job_id,
+        failure: failure("a.txt"),
+    });
+    match app.top_route() {
+        Route::Dialog(dialog) => {
+            assert_eq!(dialog.title, format!("Job #{job_id} failed on 'a.txt'"));
+            let DialogKind::Listbox(listbox) = &dialog.kind else {
+                panic!("item failures should be asked in a listbox");
+            };
+            assert_eq!(listbox.items, ["Retry", "Skip", "Skip all", "Abort"]);
+            assert_eq!(listbox.selected, 1, "Skip should be preselected");
+        }
+        other => panic!("expected the item failure prompt, got {other:?}"),
+    }
+    app.apply(AppCommand::DialogAccept)
+        .expect("answer should submit");
+    assert!(!matches!(app.top_route(), Route::Dialog(_)));
+    assert_eq!(app.status_line, format!("Job #{job_id}: Skip"));
+    app.handle_job_event(JobEvent::ItemSkipped {
+        id: job_id,
+        failure: failure("a.txt"),
+    });
+
+    app.handle_job_event(JobEvent::ItemFailed {
+        id: job_id,
+        failure: failure("b.txt"),
+    });
+    app.apply(AppCommand::DialogCancel)
+        .expect("dismissing should abort");
+    assert_eq!(app.status_line, format!("Job #{job_id}: Abort"));
+    assert!(
+        app.take_pending_worker_commands().is_empty(),
+        "an abort fails the job instead of canceling it"
+    );
+    app.handle_job_event(JobEvent::Finished {
+        id: job_id,
+        result: Err(JobError::from_io(io::Error::from(
+            io::ErrorKind::PermissionDenied,
+        ))),
+    });
+    assert_eq!(
+        app.jobs.job(job_id).map(|job| job.skipped_items.clone()),
+        Some(vec![failure("a.txt")])
+    );
+
+    app.apply(AppCommand::OpenJobsScreen)
+        .expect("jobs screen should open");
+    app.apply(AppCommand::ShowSkippedItems)
+        .expect("skipped items should be listed");
+    match app.top_route() {
+        Route::Dialog(dialog) => {
+            assert_eq!(dialog.title, format!("Skipped by job #{job_id}"));
+            let DialogKind::Listbox(listbox) = &dialog.kind else {
+                panic!("skipped items should be listed in a listbox");
+            };
+            assert_eq!(
+                listbox.items,
+                [format!(
+                    "{}: Permission denied (os error 13)",
+                    root.join("a.txt").to_string_lossy()
+                )]
+            );
+        }
+        other => panic!("expected the skipped items, got {other:?}"),
+    }
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn copy_overwrite_dialog_cycles_preserve_level_and_reports_warnings() {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should be monotonic")
+        .as_nanos();
+    let root = env::temp_dir().join(format!("rc-copy-preserve-level-{stamp}"));
+    fs::create_dir_all(&root).expect("must create temp root");
+    let source = root.join("a.txt");
+    fs::write(&source, "a").expect("must create source file");
+
+    let mut app = app_with_loaded_panels(root.clone());
+    let source_index = app
+        .active_panel()
+        .entries
+        .iter()
+        .position(|entry| entry.path == source)
+        .expect("source entry should be visible");
+    app.active_panel_mut().cursor = source_index;
+
+    app.start_copy_dialog();
+    app.finish_dialog(DialogResult::InputSubmitted(String::from("dest")));
+    app.apply(AppCommand::DialogFocusNext)
+        .expect("tab should cycle the preserve level");
+    app.apply(AppCommand::DialogInputChar(' '))
+        .expect("space should cycle the preserve level");
+    match app.top_route() {
+        Route::Dialog(dialog) => match &dialog.kind {
+            DialogKind::Listbox(listbox) => assert!(
+                listbox
+                    .footer_hint
+                    .as_deref()
+                    .is_some_and(|hint| hint.starts_with("Preserve: +ACLs")),
+                "{:?}",
+                listbox.footer_hint
+            ),
+            other => panic!("expected overwrite policy listbox, got {other:?}"),
+        },
+        other => panic!("expected overwrite policy dialog, got {other:?}"),
+    }
+
+    app.apply(AppCommand::DialogAccept)
+        .expect("policy should submit");
+    let commands = app.take_pending_worker_commands();
+    let job_id = match commands.as_slice() {
+        [WorkerCommand::Run(job)] => {
+            assert!(matches!(
+                job.request,
+                JobRequest::Copy {
+                    preserve: PreserveLevel::Acls,
+                    ..
+                }
+            ));
+            job.id
+        }
+        other => panic!("expected one copy job, got {other:?}"),
+    };
+
+    app.handle_job_event(JobEvent::Started { id: job_id });
+    app.handle_job_event(JobEvent::Warning {
+        id: job_id,
+        message: String::from("a.txt: attributes not preserved: security.selinux"),
+    });
+    assert!(app.status_line.contains("warning"));
+    app.handle_job_event(JobEvent::Finished {
+        id: job_id,
+        result: Ok(()),
+    });
+    assert!(
+        app.status_line.contains("finished with 1 warning(s)"),
+        "{}",
+        app.status_line
+    );
+    assert_eq!(app.jobs.job(job_id).map(|job| job.warnings.len()), Some(1));
+
+    fs::remove_dir_all(&root).expect("must remove temp root");
+}
+
+#[test]
+fn overwrite_conflict_prompt_answers_job_and_aborts_on_cancel() {
+    let root = env::temp_dir();
+    let mut app = app_with_loaded_panels(root.clone());
+    let conflict = |name: &str| OverwriteConflict {
+        source: root.join("src").join(name),
+        destination: root.join("dst").join(name),
+        source_entry: ConflictEntry::default(),
+        destination_entry: ConflictEntry::default(),
+    };
+    let request = JobRequest::Copy {
+        sources: vec![root.join("src").join("a.txt")],
+        destination_dir: root.join("dst"),
+        destination_names: None,
+        overwrite: OverwritePolicy::Ask,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
+    };
+    let job_id =
+        app.queue_filesystem_job(request.clone(), OperationOrigin::Panel(ActivePanel::Left));
+    let _ = app.take_pending_worker_commands();
+
+    app.handle_job_event(JobEvent::OverwriteConflict {
+        id: job_id,
+        conflict: conflict("a.txt"),
+    });
+    assert!(matches!(
+        app.top_route(),
+        Route::Dialog(dialog) if matches!(dialog.kind, DialogKind::Overwrite(_))
+    ));
+    app.apply(AppCommand::DialogAccept)
+        .expect("answer should submit");
+    assert!(!matches!(app.top_route(), Route::Dialog(_)));
+    assert!(app.take_pending_worker_commands().is_empty());
+
+    app.handle_job_event(JobEvent::OverwriteConflict {
+        id: job_id,
+        conflict: conflict("b.txt"),
+    });
+    app.apply(AppCommand::DialogCancel)
+        .expect("cancel should abort the job");
+    assert!(matches!(
+        app.take_pending_worker_commands().as_slice(),
+        [WorkerCommand::Cancel(id)] if *id == job_id
+    ));
+
+    let job_id = app.queue_filesystem_job(request, OperationOrigin::Panel(ActivePanel::Left));
+    app.handle_job_event(JobEvent::OverwriteConflict {
+        id: job_id,
+        conflict: conflict("c.txt"),
+    });
+    app.handle_job_event(JobEvent::Finished {
+        id: job_id,
+        result: Err(JobError::canceled()),
+    });
+    assert!(
+        !matches!(app.top_route(), Route::Dialog(_)),
+        "finished jobs must drop their pending prompts"
+    );
+}
@@ -0,0 +1,225 @@
+//! Transfer rates: throughput measured over a sliding window, and bandwidth
+//! caps the copy loop paces itself against.
+//!
+//! A job copies under two caps at once, its own and the global one shared by
+//! every job; whichever asks for the longer pause wins. Both can be changed
+//! while the job runs, since the worker only reads the current limit.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// How far back the throughput of a job is averaged.
+const RATE_WINDOW: Duration = Duration::from_secs(5);
+/// Updates closer than this to the newest sample are not stored, so a fast
+/// copy keeps a short sample list.
+const RATE_SAMPLE_SPACING: Duration = Duration::from_millis(100);
+/// Rates measured over less time than this are too noisy to show.
+const RATE_MIN_SPAN: Duration = Duration::from_millis(250);
+/// How far a sender may fall behind schedule before the schedule restarts,
+/// so an idle stretch between files does not allow a burst afterwards.
+const PACE_RESET_AFTER: Duration = Duration::from_secs(1);
+
+/// A bandwidth cap in bytes per second; zero means unlimited.
+#[derive(Debug, Default)]
+pub struct Throttle {
+    limit: AtomicU64,
+    pace: Mutex<Pace>,
+}
+
+#[derive(Debug, Default)]
+struct Pace {
+    started: Option<Instant>,
+    bytes: u64,
+    limit: u64,
+}
+
+impl Throttle {
+    pub fn limit(&self) -> u64 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    pub fn set_limit(&self, bytes_per_second: u64) {
+        self.limit.store(bytes_per_second, Ordering::Relaxed);
+    }
+
+    /// Accounts `bytes` just sent and returns how long the sender has to
+    /// wait to stay under the limit.
+    pub(crate) fn pause_for(&self, bytes: u64) -> Duration {
+        let limit = self.limit();
+        let mut pace = self
+            .pace
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if limit == 0 {
+            *pace = Pace::default();
+            return Duration::ZERO;
+        }
+        let now = Instant::now();
+        let started = match pace.started {
+            Some(started) if pace.limit == limit => started,
+            // A new or changed limit starts a fresh schedule.
+            _ => {
+                *pace = Pace {
+                    started: Some(now),
+                    bytes: 0,
+                    limit,
+                };
+                now
+            }
+        };
+        pace.bytes = pace.bytes.saturating_add(bytes);
+        let due = Duration::from_secs_f64(pace.bytes as f64 / limit as f64);
+        let elapsed = now.duration_since(started);
+        if elapsed > due + PACE_RESET_AFTER {
+            pace.started = Some(now);
+            pace.bytes = 0;
+            return Duration::ZERO;
+        }
+        due.saturating_sub(elapsed)
+    }
+}
+
+/// Throughput over the last few seconds of a job.
+#[derive(Debug, Default)]
+pub(crate) struct RateWindow {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl RateWindow {
+    /// Records that `bytes` were transferred in total by `now` and returns
+    /// the rate in bytes per second, or zero while it is not known yet.
+    pub(crate) fn record(&mut self, now: Instant, bytes: u64) -> u64 {
+        if self
+            .samples
+            .back()
+            .is_none_or(|(at, _)| now.duration_since(*at) >= RATE_SAMPLE_SPACING)
+        {
+            self.samples.push_back((now, bytes));
+        }
+        while self.samples.len() > 2
+            && self
+                .samples
+                .get(1)
+                .is_some_and(|(at, _)| now.duration_since(*at) >= RATE_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+
+        let Some(&(first_at, first_bytes)) = self.samples.front() else {
+            return 0;
+        };
+        let span = now.duration_since(first_at);
+        if span < RATE_MIN_SPAN {
+            return 0;
+        }
+        (bytes.saturating_sub(first_bytes) as f64 / span.as_secs_f64()) as u64
+    }
+}
+
+/// Parses a rate such as `512K`, `10M`, `1.5G/s` or `0`/`off` (unlimited).
+/// Suffixes are binary multiples; a bare number is bytes per second.
+pub fn parse_rate(value: &str) -> Option<u64> {
+    let value = value.trim().to_ascii_lowercase();
+    if value.is_empty() || value == "off" || value == "none" {
+        return Some(0);
+    }
+    let value = value.strip_suffix("/s").unwrap_or(&value);
+    let value = value
+        .strip_suffix("ib")
+        .or_else(|| value.strip_suffix('b'))
+        .unwrap_or(value);
+    let (number, multiplier) = match value.chars().last()? {
+        'k' => (&value[..value.len() - 1], 1024_f64),
+        'm' => (&value[..value.len() - 1], 1024_f64.powi(2)),
+        'g' => (&value[..value.len() - 1], 1024_f64.powi(3)),
+        _ => (value, 1_f64),
+    };
+    let number: f64 = number.trim().parse().ok()?;
+    if !number.is_finite() || number < 0.0 {
+        return None;
+    }
+    Some((number * multiplier).round() as u64)
+}
+
+/// Formats bytes per second compactly, e.g. `12.5M/s`.
+pub fn format_rate(bytes_per_second: u64) -> String {
+    format!("{}/s", format_size(bytes_per_second))
+}
+
+/// Formats a byte count with the units of [`format_rate`], e.g. `12.5M`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 || value >= 100.0 {
+        format!("{}{}", value.round() as u64, UNITS[unit])
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+/// Formats a remaining time as `m:ss`, or `h:mm:ss` from an hour on.
+pub fn format_eta(seconds: u64) -> String {
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates_parse_and_format_with_binary_units() {
+        assert_eq!(parse_rate("off"), Some(0));
+        assert_eq!(parse_rate("4096"), Some(4096));
+        assert_eq!(parse_rate("512K"), Some(512 * 1024));
+        assert_eq!(parse_rate("1.5MiB/s"), Some(1024 * 1024 * 3 / 2));
+        assert_eq!(parse_rate("10m"), Some(10 * 1024 * 1024));
+        assert_eq!(parse_rate("fast"), None);
+        assert_eq!(parse_rate("-1M"), None);
+
+        assert_eq!(format_rate(500), "500/s");
+        assert_eq!(format_rate(12 * 1024 * 1024 + 512 * 1024), "12.5M/s");
+        assert_eq!(format_eta(65), "1:05");
+        assert_eq!(format_eta(3723), "1:02:03");
+    }
+
+    #[test]
+    fn throttle_paces_bytes_against_the_current_limit() {
+        let throttle = Throttle::default();
+        assert_eq!(throttle.pause_for(1 << 20), Duration::ZERO);
+
+        throttle.set_limit(1024 * 1024);
+        let pause = throttle.pause_for(512 * 1024);
+        assert!(
+            pause > Duration::from_millis(400) && pause <= Duration::from_millis(500),
+            "{pause:?}"
+        );
+
+        throttle.set_limit(0);
+        assert_eq!(throttle.pause_for(1 << 30), Duration::ZERO);
+    }
+
+    #[test]
+    fn rate_window_averages_recent_samples() {
+        let start = Instant::now();
+        let mut window = RateWindow::default();
+        assert_eq!(window.record(start, 0), 0);
+        assert_eq!(window.record(start + Duration::from_millis(50), 100), 0);
+        assert_eq!(window.record(start + Duration::from_secs(1), 1000), 1000);
+        assert_eq!(window.record(start + Duration::from_secs(2), 3000), 1500);
+        // Older samples fall out of the window.
+        let rate = window.record(start + Duration::from_secs(8), 15_000);
+        assert_eq!(rate, 2000);
+    }
+}
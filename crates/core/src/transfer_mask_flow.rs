@@ -0,0 +1,202 @@
+use std::path::{Path, PathBuf};
+
+use crate::dialog_flow::most_common_file_extension_pattern;
+use crate::jobs::validate_destination_names;
+use crate::rename_mask::source_name;
+use crate::*;
+
+impl AppState {
+    /// Opens the rename masks of the copy or move whose overwrite dialog is
+    /// on top. Returns false when there is none, so the key falls through.
+    pub(crate) fn start_transfer_mask(&mut self) -> bool {
+        let Some(Route::Dialog(dialog)) = self.routes.last() else {
+            return false;
+        };
+        let (sources, destination_dir) = match dialog.action() {
+            Some(
+                PendingDialogAction::TransferOverwrite {
+                    kind,
+                    sources,
+                    destination_dir,
+                    ..
+                }
+                | PendingDialogAction::TransferRenameOverwrite {
+                    kind,
+                    sources,
+                    destination_dir,
+                    ..
+                },
+            ) if matches!(kind, TransferKind::Copy | TransferKind::Move) => {
+                (sources.clone(), destination_dir.clone())
+            }
+            _ => return false,
+        };
+        let mask = most_common_file_extension_pattern(&sources);
+        self.push_transfer_mask_dialog(sources, destination_dir, &mask, &mask, FindNameMode::Glob);
+        self.set_status("Rename masks: edit the masks, Enter to use the names");
+        true
+    }
+
+    fn push_transfer_mask_dialog(
+        &mut self,
+        sources: Vec<PathBuf>,
+        destination_dir: PathBuf,
+        source_mask: &str,
+        destination_mask: &str,
+        name_mode: FindNameMode,
+    ) {
+        self.push_dialog(
+            DialogState::mask("Rename masks", source_mask, destination_mask, name_mode),
+            PendingDialogAction::TransferMask {
+                sources,
+                destination_dir,
+            },
+        );
+        self.sync_transfer_mask_preview();
+    }
+
+    /// Recomputes the names the open mask dialog would give.
+    pub(crate) fn sync_transfer_mask_preview(&mut self) {
+        let Some(Route::Dialog(dialog)) = self.routes.last_mut() else {
+            return;
+        };
+        let preview = match (dialog.action(), &dialog.kind) {
+            (
+                Some(PendingDialogAction::TransferMask {
+                    sources,
+                    destination_dir,
+                }),
+                DialogKind::Mask(mask),
+            ) => mask_preview(
+                sources,
+                destination_dir,
+                &mask.source_mask,
+                &mask.destination_mask,
+                mask.name_mode,
+            ),
+            _ => return,
+        };
+        if let DialogKind::Mask(mask) = &mut dialog.kind {
+            mask.preview = preview;
+        }
+    }
+
+    /// Gives the copy or move below the mask dialog the masked names, or
+    /// reopens the masks when they fail or make names collide.
+    pub(crate) fn apply_transfer_mask(
+        &mut self,
+        sources: Vec<PathBuf>,
+        destination_dir: PathBuf,
+        source_mask: &str,
+        destination_mask: &str,
+        name_mode: FindNameMode,
+    ) {
+        let names = masked_names(
+            &sources,
+            &destination_dir,
+            source_mask,
+            destination_mask,
+            name_mode,
+        );
+        let names = match names {
+            Ok(names) => names,
+            Err(error) => {
+                self.push_transfer_mask_dialog(
+                    sources,
+                    destination_dir,
+                    source_mask,
+                    destination_mask,
+                    name_mode,
+                );
+                self.set_status(format!("Masks rejected: {error}"));
+                return;
+            }
+        };
+        let renamed = sources
+            .iter()
+            .zip(&names)
+            .filter(|(source, name)| source_name(source) != **name)
+            .count();
+
+        let Some(Route::Dialog(dialog)) = self.routes.last_mut() else {
+            return;
+        };
+        let Some(action) = dialog.action_mut() else {
+            return;
+        };
+        let (kind, preserve, follow_links, hard_links, origin) = match action {
+            PendingDialogAction::TransferOverwrite {
+                kind,
+                preserve,
+                follow_links,
+                hard_links,
+                origin,
+                ..
+            }
+            | PendingDialogAction::TransferRenameOverwrite {
+                kind,
+                preserve,
+                follow_links,
+                hard_links,
+                origin,
+                ..
+            } => (*kind, *preserve, *follow_links, *hard_links, *origin),
+            _ => return,
+        };
+        *action = PendingDialogAction::TransferRenameOverwrite {
+            kind,
+            sources,
+            destination_dir,
+            destination_names: names,
+            preserve,
+            follow_links,
+            hard_links,
+            origin,
+        };
+        self.set_status(format!(
+            "Masks rename {renamed} item(s); choose overwrite policy"
+        ));
+    }
+}
+
+/// The destination names the masks give, checked for collisions the same
+/// way a move checks them.
+fn masked_names(
+    sources: &[PathBuf],
+    destination_dir: &Path,
+    source_mask: &str,
+    destination_mask: &str,
+    name_mode: FindNameMode,
+) -> Result<Vec<String>, String> {
+    let names =
+        RenameMask::new(source_mask, destination_mask, name_mode)?.destination_names(sources);
+    validate_destination_names(sources, destination_dir, &names, false)
+        .map_err(|error| error.to_string())?;
+    Ok(names)
+}
+
+fn mask_preview(
+    sources: &[PathBuf],
+    destination_dir: &Path,
+    source_mask: &str,
+    destination_mask: &str,
+    name_mode: FindNameMode,
+) -> Vec<String> {
+    let names = match RenameMask::new(source_mask, destination_mask, name_mode) {
+        Ok(mask) => mask.destination_names(sources),
+        Err(error) => return vec![format!("! {error}")],
+    };
+    let mut rows = Vec::new();
+    if let Err(error) = validate_destination_names(sources, destination_dir, &names, false) {
+        rows.push(format!("! {error}"));
+    }
+    rows.extend(sources.iter().zip(&names).map(|(source, name)| {
+        let old = source_name(source);
+        if old == *name {
+            format!("{old} (unchanged)")
+        } else {
+            format!("{old} -> {name}")
+        }
+    }));
+    rows
+}
@@ -0,0 +1,156 @@
+use crate::dialog_flow::overwrite_policy_from_index;
+use crate::throttle::format_size;
+use crate::*;
+
+impl AppState {
+    /// Starts a dry run of what the confirm dialog on top would queue with
+    /// the choice it highlights. Returns false when there is nothing to
+    /// preview, so the key falls through to the dialog.
+    pub(crate) fn start_transfer_preview(&mut self) -> bool {
+        let Some(request) = self.previewed_request() else {
+            return false;
+        };
+        if let Some(job_id) = self.pending_plan_job.take() {
+            let _ = self.request_cancel_for_job(job_id);
+        }
+        self.plan_request_id = self.plan_request_id.wrapping_add(1);
+        let job_id = self.queue_transient_worker_job_request(JobRequest::PlanTransfer {
+            request: Box::new(request),
+            request_id: self.plan_request_id,
+        });
+        self.pending_plan_job = Some(job_id);
+        self.set_status("Planning preview...");
+        true
+    }
+
+    fn previewed_request(&self) -> Option<JobRequest> {
+        let Some(Route::Dialog(dialog)) = self.routes.last() else {
+            return None;
+        };
+        let selected = match &dialog.kind {
+            DialogKind::Listbox(listbox) => listbox.selected,
+            _ => 0,
+        };
+        match dialog.action()? {
+            PendingDialogAction::TransferOverwrite {
+                kind,
+                sources,
+                destination_dir,
+                preserve,
+                follow_links,
+                hard_links,
+                ..
+            } if matches!(kind, TransferKind::Copy | TransferKind::Move) => Some(
+                self.transfer_request(
+                    *kind,
+                    sources.clone(),
+                    destination_dir.clone(),
+                    None,
+                    overwrite_policy_from_index(selected),
+                    *preserve,
+                )
+                .with_follow_links(*follow_links)
+                .with_hard_links(*hard_links),
+            ),
+            PendingDialogAction::TransferRenameOverwrite {
+                kind,
+                sources,
+                destination_dir,
+                destination_names,
+                preserve,
+                follow_links,
+                hard_links,
+                ..
+            } if matches!(kind, TransferKind::Copy | TransferKind::Move) => Some(
+                self.transfer_request(
+                    *kind,
+                    sources.clone(),
+                    destination_dir.clone(),
+                    Some(destination_names.clone()),
+                    overwrite_policy_from_index(selected),
+                    *preserve,
+                )
+                .with_follow_links(*follow_links)
+                .with_hard_links(*hard_links),
+            ),
+            PendingDialogAction::ConfirmDelete { targets, .. } => {
+                Some(match DeleteMode::ALL.get(selected)? {
+                    DeleteMode::Trash => JobRequest::Trash {
+                        targets: targets.clone(),
+                    },
+                    DeleteMode::Permanent => JobRequest::Delete {
+                        targets: targets.clone(),
+                    },
+                })
+            }
+            PendingDialogAction::ConfirmPermanentDelete { targets, .. } => {
+                Some(JobRequest::Delete {
+                    targets: targets.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn handle_transfer_planned(
+        &mut self,
+        request_id: u64,
+        result: Result<TransferPlan, String>,
+    ) {
+        if request_id != self.plan_request_id || self.pending_plan_job.take().is_none() {
+            return;
+        }
+        let plan = match result {
+            Ok(plan) => plan,
+            Err(error) => {
+                self.set_status(format!("Preview failed: {error}"));
+                return;
+            }
+        };
+        // The dialog was answered while the plan was built.
+        if self.previewed_request().is_none() {
+            return;
+        }
+
+        let mut items: Vec<String> = plan
+            .warnings
+            .iter()
+            .map(|warning| format!("! {warning}"))
+            .collect();
+        items.extend(plan.steps.iter().map(preview_step_line));
+        let footer = format!(
+            "{} item(s), {} | Enter/Esc back",
+            plan.items,
+            format_size(plan.bytes)
+        );
+        self.push_dialog(
+            DialogState::listbox_with_hint("Preview", items, 0, footer),
+            PendingDialogAction::ShowTransferPreview,
+        );
+        self.set_status(format!(
+            "Preview: {} step(s), {} warning(s)",
+            plan.steps.len(),
+            plan.warnings.len()
+        ));
+    }
+}
+
+fn preview_step_line(step: &PlannedStep) -> String {
+    match &step.action {
+        PlannedAction::Delete | PlannedAction::Trash => {
+            format!("{} {}", step.action.label(), step.source.to_string_lossy())
+        }
+        PlannedAction::RenameTo(target) => format!(
+            "{} {} -> {}",
+            step.action.label(),
+            step.source.to_string_lossy(),
+            target.to_string_lossy()
+        ),
+        _ => format!(
+            "{} {} -> {}",
+            step.action.label(),
+            step.source.to_string_lossy(),
+            step.destination.to_string_lossy()
+        ),
+    }
+}
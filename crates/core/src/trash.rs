@@ -0,0 +1,595 @@
+//! Freedesktop.org trash support.
+//!
+//! Files on the same filesystem as the home trash go to
+//! `$XDG_DATA_HOME/Trash`; files on other mounts go to `$topdir/.Trash/$uid`
+//! when the administrator provided a sticky `.Trash` directory, and to
+//! `$topdir/.Trash-$uid` otherwise. Each trashed entry gets an
+//! `info/NAME.trashinfo` file recording its original path and deletion date.
+
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+pub const MOUNTS_PATH: &str = "/proc/self/mounts";
+
+const TRASH_INFO_HEADER: &str = "[Trash Info]";
+const TRASH_INFO_EXTENSION: &str = ".trashinfo";
+const DELETION_DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// An entry currently sitting in one of the trash directories.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrashedItem {
+    pub name: String,
+    pub original_path: PathBuf,
+    pub deleted_at: String,
+    pub info_path: PathBuf,
+    pub files_path: PathBuf,
+}
+
+impl TrashedItem {
+    /// Reads the `.trashinfo` file at `info_path` and locates its payload.
+    pub fn load(info_path: &Path) -> io::Result<Self> {
+        let invalid = |reason: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{reason}: {}", info_path.to_string_lossy()),
+            )
+        };
+        let file_name = info_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| invalid("invalid trash info name"))?;
+        let name = file_name
+            .strip_suffix(TRASH_INFO_EXTENSION)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| invalid("invalid trash info name"))?;
+        let root = info_path
+            .parent()
+            .and_then(Path::parent)
+            .ok_or_else(|| invalid("trash info outside a trash directory"))?;
+
+        let (path, deleted_at) = parse_trash_info(&fs::read_to_string(info_path)?)
+            .ok_or_else(|| invalid("malformed trash info"))?;
+        let original_path = if path.is_absolute() {
+            path
+        } else {
+            topdir_for_trash_root(root)
+                .ok_or_else(|| invalid("relative trash path outside a mount trash"))?
+                .join(path)
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            original_path,
+            deleted_at,
+            info_path: info_path.to_path_buf(),
+            files_path: root.join("files").join(name),
+        })
+    }
+}
+
+/// The set of trash directories visible to the current user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trash {
+    home: PathBuf,
+    mount_points: Vec<PathBuf>,
+}
+
+impl Trash {
+    /// Locates the home trash from `XDG_DATA_HOME`/`HOME` and reads the mount
+    /// table so per-mount trash directories can be listed.
+    pub fn from_env() -> io::Result<Self> {
+        let data_home = env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "cannot locate the trash: neither XDG_DATA_HOME nor HOME is set",
+                )
+            })?;
+        let mount_points = fs::read_to_string(MOUNTS_PATH)
+            .map(|content| parse_mount_points(&content))
+            .unwrap_or_default();
+        Ok(Self {
+            home: data_home.join("Trash"),
+            mount_points,
+        })
+    }
+
+    /// A trash rooted at `home` that ignores other mounts.
+    pub fn with_home(home: impl Into<PathBuf>) -> Self {
+        Self {
+            home: home.into(),
+            mount_points: Vec::new(),
+        }
+    }
+
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    /// Moves `path` into the trash of its filesystem and returns the location
+    /// of the trashed payload.
+    pub fn trash(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = absolute_path(path)?;
+        let metadata = fs::symlink_metadata(&path)?;
+        let name = path.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cannot trash {}", path.to_string_lossy()),
+            )
+        })?;
+        let device = device_id(&metadata);
+
+        let (root, recorded_path) = if nearest_existing_device(&self.home)? == device {
+            (self.home.clone(), path.clone())
+        } else {
+            let parent = path.parent().unwrap_or(Path::new("/"));
+            let topdir = filesystem_topdir(&fs::canonicalize(parent)?, device)?;
+            let recorded = path
+                .strip_prefix(&topdir)
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|_| path.clone());
+            (mount_trash_root(&topdir)?, recorded)
+        };
+        if root.starts_with(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cannot move {} into its own trash", path.to_string_lossy()),
+            ));
+        }
+        fs::create_dir_all(root.join("files"))?;
+        fs::create_dir_all(root.join("info"))?;
+
+        let info = format!(
+            "{TRASH_INFO_HEADER}\nPath={}\nDeletionDate={}\n",
+            encode_path(&recorded_path),
+            chrono::Local::now().format(DELETION_DATE_FORMAT)
+        );
+        let name = name.to_string_lossy();
+        let (info_path, files_path) = reserve_trash_name(&root, &name, &info)?;
+        if let Err(error) = fs::rename(&path, &files_path) {
+            let _ = fs::remove_file(&info_path);
+            return Err(error);
+        }
+        Ok(files_path)
+    }
+
+    /// Lists every entry of the home trash and the per-mount trashes, most
+    /// recently deleted first. Unreadable info files are skipped.
+    pub fn list(&self) -> io::Result<Vec<TrashedItem>> {
+        let mut roots = vec![self.home.clone()];
+        let uid = current_uid();
+        for mount_point in &self.mount_points {
+            for root in [
+                mount_point.join(".Trash").join(uid.to_string()),
+                mount_point.join(format!(".Trash-{uid}")),
+            ] {
+                if root.is_dir() && !roots.contains(&root) {
+                    roots.push(root);
+                }
+            }
+        }
+
+        let mut items = Vec::new();
+        for root in roots {
+            let entries = match fs::read_dir(root.join("info")) {
+                Ok(entries) => entries,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error),
+            };
+            for entry in entries {
+                let info_path = entry?.path();
+                if let Ok(item) = TrashedItem::load(&info_path)
+                    && fs::symlink_metadata(&item.files_path).is_ok()
+                {
+                    items.push(item);
+                }
+            }
+        }
+        items.sort_by(|left, right| {
+            right
+                .deleted_at
+                .cmp(&left.deleted_at)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        Ok(items)
+    }
+}
+
+/// Moves a trashed entry back to its original path. Existing entries are never
+/// replaced.
+pub fn restore(info_path: &Path) -> io::Result<PathBuf> {
+    let item = TrashedItem::load(info_path)?;
+    if fs::symlink_metadata(&item.original_path).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "restore target already exists: {}",
+                item.original_path.to_string_lossy()
+            ),
+        ));
+    }
+    if let Some(parent) = item.original_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&item.files_path, &item.original_path)?;
+    fs::remove_file(&item.info_path)?;
+    Ok(item.original_path)
+}
+
+fn reserve_trash_name(root: &Path, name: &str, info: &str) -> io::Result<(PathBuf, PathBuf)> {
+    for attempt in 1..=u32::MAX {
+        let candidate = if attempt == 1 {
+            name.to_string()
+        } else {
+            format!("{name}.{attempt}")
+        };
+        let files_path = root.join("files").join(&candidate);
+        if fs::symlink_metadata(&files_path).is_ok() {
+            continue;
+        }
+        let info_path = root
+            .join("info")
+            .join(format!("{candidate}{TRASH_INFO_EXTENSION}"));
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&info_path)
+        {
+            Ok(mut file) => {
+                if let Err(error) = file.write_all(info.as_bytes()) {
+                    let _ = fs::remove_file(&info_path);
+                    return Err(error);
+                }
+                return Ok((info_path, files_path));
+            }
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("no free trash name for {name}"),
+    ))
+}
+
+fn parse_trash_info(content: &str) -> Option<(PathBuf, String)> {
+    let mut lines = content.lines().map(str::trim);
+    if lines.next()? != TRASH_INFO_HEADER {
+        return None;
+    }
+    let mut path = None;
+    let mut deleted_at = String::new();
+    for line in lines {
+        if line.starts_with('[') {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Path=") {
+            path = Some(decode_path(value));
+        } else if let Some(value) = line.strip_prefix("DeletionDate=") {
+            deleted_at = value.to_string();
+        }
+    }
+    path.filter(|path| !path.as_os_str().is_empty())
+        .map(|path| (path, deleted_at))
+}
+
+fn topdir_for_trash_root(root: &Path) -> Option<PathBuf> {
+    let name = root.file_name()?.to_str()?;
+    if name.starts_with(".Trash-") {
+        return root.parent().map(Path::to_path_buf);
+    }
+    let parent = root.parent()?;
+    if parent.file_name()? == ".Trash" {
+        return parent.parent().map(Path::to_path_buf);
+    }
+    None
+}
+
+/// Returns the `.trashinfo` file that describes the payload at `files_path`.
+pub fn info_path_for(files_path: &Path) -> Option<PathBuf> {
+    let name = files_path.file_name()?.to_str()?;
+    let files_dir = files_path.parent()?;
+    if files_dir.file_name()? != "files" {
+        return None;
+    }
+    Some(
+        files_dir
+            .parent()?
+            .join("info")
+            .join(format!("{name}{TRASH_INFO_EXTENSION}")),
+    )
+}
+
+/// Picks the trash directory for the filesystem mounted at `topdir`, creating
+/// the per-user directory if needed.
+fn mount_trash_root(topdir: &Path) -> io::Result<PathBuf> {
+    let uid = current_uid();
+    let shared = topdir.join(".Trash");
+    if let Ok(metadata) = fs::symlink_metadata(&shared)
+        && metadata.is_dir()
+        && is_sticky(&metadata)
+    {
+        let root = shared.join(uid.to_string());
+        if create_private_dir(&root).is_ok() {
+            return Ok(root);
+        }
+    }
+
+    let root = topdir.join(format!(".Trash-{uid}"));
+    create_private_dir(&root)?;
+    Ok(root)
+}
+
+fn create_private_dir(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() && owned_by_current_user(&metadata) => return Ok(()),
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("unsafe trash directory: {}", path.to_string_lossy()),
+            ));
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+    let mut builder = fs::DirBuilder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(path)
+}
+
+/// Walks up from `dir` to the highest ancestor still on `device`.
+fn filesystem_topdir(dir: &Path, device: u64) -> io::Result<PathBuf> {
+    let mut topdir = dir.to_path_buf();
+    while let Some(parent) = topdir.parent() {
+        if device_id(&fs::metadata(parent)?) != device {
+            break;
+        }
+        topdir = parent.to_path_buf();
+    }
+    Ok(topdir)
+}
+
+fn nearest_existing_device(path: &Path) -> io::Result<u64> {
+    let mut current = path;
+    loop {
+        match fs::metadata(current) {
+            Ok(metadata) => return Ok(device_id(&metadata)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => match current.parent() {
+                Some(parent) => current = parent,
+                None => return Err(error),
+            },
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+fn absolute_path(path: &Path) -> io::Result<PathBuf> {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir()?.join(path)
+    };
+    Ok(path
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect())
+}
+
+fn parse_mount_points(content: &str) -> Vec<PathBuf> {
+    content
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(unescape_mount_field)
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Undoes the octal escapes (`\040` for a space and so on) used by the kernel
+/// in the mount table.
+fn unescape_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'\\'
+            && let Some(digits) = field.get(index + 1..index + 4)
+            && let Ok(value) = u8::from_str_radix(digits, 8)
+        {
+            output.push(value);
+            index += 4;
+            continue;
+        }
+        output.push(bytes[index]);
+        index += 1;
+    }
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+pub(crate) fn encode_path(path: &Path) -> String {
+    let mut encoded = String::new();
+    for byte in path_bytes(path) {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+pub(crate) fn decode_path(value: &str) -> PathBuf {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%'
+            && let Some(hex) = value.get(index + 1..index + 3)
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            decoded.push(byte);
+            index += 3;
+            continue;
+        }
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+    path_from_bytes(decoded)
+}
+
+#[cfg(unix)]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(not(unix))]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(unix)]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    use std::os::unix::ffi::OsStringExt;
+    PathBuf::from(std::ffi::OsString::from_vec(bytes))
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[cfg(unix)]
+fn device_id(metadata: &fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.dev()
+}
+
+#[cfg(not(unix))]
+fn device_id(_metadata: &fs::Metadata) -> u64 {
+    0
+}
+
+#[cfg(unix)]
+fn current_uid() -> u32 {
+    nix::unistd::getuid().as_raw()
+}
+
+#[cfg(not(unix))]
+fn current_uid() -> u32 {
+    0
+}
+
+#[cfg(unix)]
+fn is_sticky(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o1000 != 0
+}
+
+#[cfg(not(unix))]
+fn is_sticky(_metadata: &fs::Metadata) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn owned_by_current_user(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    metadata.uid() == current_uid()
+}
+
+#[cfg(not(unix))]
+fn owned_by_current_user(_metadata: &fs::Metadata) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_temp_dir;
+
+    #[test]
+    fn trash_records_info_and_restore_puts_entry_back() {
+        let root = make_temp_dir("trash-roundtrip");
+        let trash = Trash::with_home(root.join("Trash"));
+        let source = root.join("a file%.txt");
+        fs::write(&source, "payload").expect("must write source");
+
+        let trashed = trash.trash(&source).expect("trash should succeed");
+        assert!(!source.exists());
+        assert_eq!(trashed, root.join("Trash/files/a file%.txt"));
+        let info = fs::read_to_string(root.join("Trash/info/a file%.txt.trashinfo"))
+            .expect("info file should exist");
+        assert!(info.starts_with("[Trash Info]\nPath="));
+        assert!(info.contains("a%20file%25.txt\nDeletionDate="));
+
+        let items = trash.list().expect("list should succeed");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].original_path, source);
+        assert_eq!(items[0].files_path, trashed);
+        assert_eq!(info_path_for(&trashed).as_ref(), Some(&items[0].info_path));
+
+        assert_eq!(restore(&items[0].info_path).expect("restore"), source);
+        assert_eq!(fs::read_to_string(&source).expect("restored"), "payload");
+        assert!(trash.list().expect("list should succeed").is_empty());
+
+        fs::remove_dir_all(&root).expect("must remove temp dir");
+    }
+
+    #[test]
+    fn trash_keeps_same_named_entries_apart_and_restore_never_overwrites() {
+        let root = make_temp_dir("trash-collide");
+        let trash = Trash::with_home(root.join("Trash"));
+        let source = root.join("note");
+        fs::write(&source, "first").expect("must write source");
+        trash.trash(&source).expect("first trash");
+        fs::write(&source, "second").expect("must write source");
+        let second = trash.trash(&source).expect("second trash");
+        assert_eq!(second, root.join("Trash/files/note.2"));
+
+        fs::write(&source, "third").expect("must write source");
+        let items = trash.list().expect("list should succeed");
+        assert_eq!(items.len(), 2);
+        let error = restore(&items[0].info_path).expect_err("existing target must win");
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&source).expect("kept"), "third");
+
+        fs::remove_dir_all(&root).expect("must remove temp dir");
+    }
+
+    #[test]
+    fn relative_paths_resolve_against_mount_topdir() {
+        let root = make_temp_dir("trash-topdir");
+        let trash_root = root.join(".Trash-1000");
+        fs::create_dir_all(trash_root.join("info")).expect("must create info dir");
+        let info_path = trash_root.join("info/doc.trashinfo");
+        fs::write(
+            &info_path,
+            "[Trash Info]\nPath=work/doc\nDeletionDate=2024-03-01T10:00:00\n",
+        )
+        .expect("must write info");
+
+        let item = TrashedItem::load(&info_path).expect("info should parse");
+        assert_eq!(item.original_path, root.join("work/doc"));
+        assert_eq!(item.files_path, trash_root.join("files/doc"));
+        assert_eq!(item.deleted_at, "2024-03-01T10:00:00");
+
+        fs::remove_dir_all(&root).expect("must remove temp dir");
+    }
+
+    #[test]
+    fn mount_table_escapes_are_decoded() {
+        let mounts = "/dev/sda1 / ext4 rw 0 0\n/dev/sdb1 /media/usb\\040stick vfat rw 0 0\n";
+        assert_eq!(
+            parse_mount_points(mounts),
+            vec![PathBuf::from("/"), PathBuf::from("/media/usb stick")]
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::path::PathBuf;
+
+use crate::*;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TrashState {
+    pub items: Vec<TrashedItem>,
+    pub cursor: usize,
+    pub error: Option<String>,
+}
+
+impl TrashState {
+    fn load() -> Self {
+        let mut state = Self::default();
+        state.reload();
+        state
+    }
+
+    fn reload(&mut self) {
+        match Trash::from_env().and_then(|trash| trash.list()) {
+            Ok(items) => {
+                self.items = items;
+                self.error = None;
+            }
+            Err(error) => {
+                self.items.clear();
+                self.error = Some(format!("Trash is unavailable: {error}"));
+            }
+        }
+        self.clamp_cursor();
+    }
+
+    fn clamp_cursor(&mut self) {
+        self.cursor = self.cursor.min(self.items.len().saturating_sub(1));
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            self.cursor = 0;
+            return;
+        }
+        let last = self.items.len() - 1;
+        self.cursor = if delta.is_negative() {
+            self.cursor.saturating_sub(delta.unsigned_abs())
+        } else {
+            self.cursor.saturating_add(delta as usize).min(last)
+        };
+    }
+
+    pub fn selected_item(&self) -> Option<&TrashedItem> {
+        self.items.get(self.cursor)
+    }
+}
+
+impl AppState {
+    pub(crate) fn open_trash_screen(&mut self) {
+        if !matches!(self.top_route(), Route::Trash(_)) {
+            self.routes.push(Route::Trash(TrashState::load()));
+        }
+        let status = match self.top_route() {
+            Route::Trash(TrashState {
+                error: Some(error), ..
+            }) => error.clone(),
+            Route::Trash(trash) => format!("Opened trash ({} item(s))", trash.items.len()),
+            _ => return,
+        };
+        self.set_status(status);
+    }
+
+    pub(crate) fn close_trash_screen(&mut self) {
+        if matches!(self.top_route(), Route::Trash(_)) {
+            self.routes.pop();
+            self.set_status("Closed trash");
+        }
+    }
+
+    /// Re-reads the trash after a trash, restore or purge job finished.
+    pub(crate) fn reload_trash_screens(&mut self) {
+        for route in &mut self.routes {
+            if let Route::Trash(trash) = route {
+                trash.reload();
+            }
+        }
+    }
+
+    fn trash_state_mut(&mut self) -> Option<&mut TrashState> {
+        match self.routes.last_mut() {
+            Some(Route::Trash(trash)) => Some(trash),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn apply_trash_navigation(&mut self, motion: NavigationMotion) {
+        let page_step = self.settings.advanced.page_step as isize;
+        let Some(trash) = self.trash_state_mut() else {
+            return;
+        };
+        match motion {
+            NavigationMotion::Up => trash.move_cursor(-1),
+            NavigationMotion::Down => trash.move_cursor(1),
+            NavigationMotion::PageUp => trash.move_cursor(-page_step),
+            NavigationMotion::PageDown => trash.move_cursor(page_step),
+            NavigationMotion::Home => trash.cursor = 0,
+            NavigationMotion::End => trash.cursor = trash.items.len().saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn select_trash_item(&mut self, index: usize) {
+        if let Some(trash) = self.trash_state_mut()
+            && index < trash.items.len()
+        {
+            trash.cursor = index;
+        }
+    }
+
+    fn selected_trash_item(&self) -> Option<TrashedItem> {
+        match self.top_route() {
+            Route::Trash(trash) => trash.selected_item().cloned(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn restore_selected_trash_item(&mut self) {
+        let Some(item) = self.selected_trash_item() else {
+            self.set_status("No trashed item selected");
+            return;
+        };
+        self.queue_filesystem_job(
+            JobRequest::RestoreTrash {
+                items: vec![item.info_path],
+            },
+            OperationOrigin::Panel(self.active_panel),
+        );
+    }
+
+    pub(crate) fn purge_selected_trash_item(&mut self) {
+        let Some(item) = self.selected_trash_item() else {
+            self.set_status("No trashed item selected");
+            return;
+        };
+        if self.settings.confirmation.confirm_delete {
+            self.push_dialog(
+                DialogState::confirm(
+                    "Purge",
+                    format!(
+                        "Permanently delete '{}' from the trash?",
+                        item.original_path.to_string_lossy()
+                    ),
+                ),
+                PendingDialogAction::ConfirmTrashPurge {
+                    items: vec![item.info_path],
+                },
+            );
+            self.set_status("Confirm purge");
+        } else {
+            self.queue_trash_purge_job(vec![item.info_path]);
+        }
+    }
+
+    pub(crate) fn queue_trash_purge_job(&mut self, items: Vec<PathBuf>) {
+        self.queue_filesystem_job(
+            JobRequest::PurgeTrash { items },
+            OperationOrigin::Panel(self.active_panel),
+        );
+    }
+}
@@ -0,0 +1,616 @@
+//! Bounded journal of completed file operations that can be reverted.
+//!
+//! Workers report each finished Move, Rename, Mkdir, trash-delete and Copy
+//! step together with the identity of the entry it produced. Undoing a
+//! record first checks that the filesystem still matches what was recorded
+//! and only then turns the record into inverse jobs.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::copy_xattrs::PreserveLevel;
+use crate::jobs::{JobId, JobRequest, OverwritePolicy, path_identity_token};
+use crate::settings_io::write_atomic;
+use crate::trash::{self, decode_path, encode_path};
+
+pub const UNDO_JOURNAL_FILE_NAME: &str = "undo.journal";
+pub const UNDO_JOURNAL_LIMIT: usize = 32;
+
+const UNDO_JOURNAL_HEADER: &str = "# rc undo journal";
+const UNDO_RECORD_KEY: &str = "operation=";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UndoOperation {
+    Copy,
+    Move,
+    Rename,
+    Mkdir,
+    Trash,
+}
+
+impl UndoOperation {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Copy => "copy",
+            Self::Move => "move",
+            Self::Rename => "rename",
+            Self::Mkdir => "mkdir",
+            Self::Trash => "trash",
+        }
+    }
+
+    fn parse(label: &str) -> Option<Self> {
+        match label {
+            "copy" => Some(Self::Copy),
+            "move" => Some(Self::Move),
+            "rename" => Some(Self::Rename),
+            "mkdir" => Some(Self::Mkdir),
+            "trash" => Some(Self::Trash),
+            _ => None,
+        }
+    }
+}
+
+/// One completed step: `source` became `destination`.
+///
+/// Mkdir entries use the created directory for both paths, and trash entries
+/// point `destination` at the trashed payload. `identity` is the serialized
+/// `existing_path_identity` of `destination` right after the step, and
+/// `contents` is the [`tree_digest`] of what a copy created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UndoEntry {
+    pub operation: UndoOperation,
+    pub source: PathBuf,
+    pub destination: PathBuf,
+    pub identity: String,
+    pub contents: Option<String>,
+}
+
+impl UndoEntry {
+    /// Records `destination` as it exists now. Returns `None` when the entry
+    /// is already gone, since there is nothing left to undo.
+    pub(crate) fn capture(
+        operation: UndoOperation,
+        source: &Path,
+        destination: &Path,
+    ) -> io::Result<Option<Self>> {
+        let Some(identity) = path_identity_token(destination)? else {
+            return Ok(None);
+        };
+        let contents = match operation {
+            UndoOperation::Copy => Some(tree_digest(destination)?),
+            _ => None,
+        };
+        Ok(Some(Self {
+            operation,
+            source: source.to_path_buf(),
+            destination: destination.to_path_buf(),
+            identity,
+            contents,
+        }))
+    }
+
+    fn trash_info_path(&self) -> Result<PathBuf, String> {
+        trash::info_path_for(&self.destination).ok_or_else(|| {
+            format!(
+                "{} is not a trash entry",
+                self.destination.to_string_lossy()
+            )
+        })
+    }
+
+    /// Refuses when `destination` was removed or replaced, when a copied tree
+    /// changed, or when something new occupies the path the inverse job would
+    /// write to.
+    fn ensure_unchanged(&self) -> Result<(), String> {
+        let destination = self.destination.to_string_lossy();
+        match path_identity_token(&self.destination) {
+            Ok(Some(identity)) if identity == self.identity => {}
+            Ok(Some(_)) => return Err(format!("{destination} was replaced since")),
+            Ok(None) => return Err(format!("{destination} no longer exists")),
+            Err(error) => return Err(format!("cannot inspect {destination}: {error}")),
+        }
+
+        match self.operation {
+            UndoOperation::Move | UndoOperation::Rename | UndoOperation::Trash => {
+                // Case-only renames leave the source spelling resolving to the
+                // same entry on case-insensitive filesystems.
+                match path_identity_token(&self.source) {
+                    Ok(None) => {}
+                    Ok(Some(identity)) if identity == self.identity => {}
+                    Ok(Some(_)) => {
+                        return Err(format!("{} exists again", self.source.to_string_lossy()));
+                    }
+                    Err(error) => {
+                        return Err(format!(
+                            "cannot inspect {}: {error}",
+                            self.source.to_string_lossy()
+                        ));
+                    }
+                }
+            }
+            UndoOperation::Mkdir => {
+                let is_empty = fs::read_dir(&self.destination)
+                    .map(|mut entries| entries.next().is_none())
+                    .map_err(|error| format!("cannot inspect {destination}: {error}"))?;
+                if !is_empty {
+                    return Err(format!("{destination} is no longer empty"));
+                }
+            }
+            UndoOperation::Copy => {
+                // Journals written before copies kept a digest have none.
+                if let Some(expected) = &self.contents {
+                    let contents = tree_digest(&self.destination)
+                        .map_err(|error| format!("cannot inspect {destination}: {error}"))?;
+                    if &contents != expected {
+                        return Err(format!("{destination} changed since it was copied"));
+                    }
+                }
+            }
+        }
+
+        if self.operation == UndoOperation::Trash {
+            let info_path = self.trash_info_path()?;
+            if fs::symlink_metadata(&info_path).is_err() {
+                return Err(format!(
+                    "trash record {} is missing",
+                    info_path.to_string_lossy()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Digests the relative path, identity, size and modification time of every
+/// entry under `root`, in name order and without following symlinks, so any
+/// entry added, removed or rewritten since changes the result.
+fn tree_digest(root: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        let path = if relative.as_os_str().is_empty() {
+            root.to_path_buf()
+        } else {
+            root.join(&relative)
+        };
+        let metadata = fs::symlink_metadata(&path)?;
+        hasher.update(encode_path(&relative).as_bytes());
+        hasher.update(b"\t");
+        hasher.update(entry_stamp(&metadata).as_bytes());
+        hasher.update(b"\n");
+        if metadata.is_dir() {
+            let mut names = fs::read_dir(&path)?
+                .map(|entry| entry.map(|entry| entry.file_name()))
+                .collect::<io::Result<Vec<_>>>()?;
+            names.sort();
+            pending.extend(names.into_iter().rev().map(|name| relative.join(name)));
+        }
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+fn entry_stamp(metadata: &fs::Metadata) -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        format!(
+            "{}:{}:{}:{}.{}",
+            metadata.dev(),
+            metadata.ino(),
+            metadata.size(),
+            metadata.mtime(),
+            metadata.mtime_nsec()
+        )
+    }
+
+    #[cfg(not(unix))]
+    {
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+            .unwrap_or_default();
+        format!("{}:{}", metadata.len(), modified.as_nanos())
+    }
+}
+
+/// The undoable steps of one job.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UndoRecord {
+    pub summary: String,
+    pub entries: Vec<UndoEntry>,
+}
+
+impl UndoRecord {
+    /// Builds the jobs that revert this record, newest step first, or explains
+    /// why the filesystem no longer matches it.
+    pub fn inverse_requests(&self) -> Result<Vec<JobRequest>, String> {
+        for entry in &self.entries {
+            entry.ensure_unchanged()?;
+        }
+
+        let mut requests = Vec::new();
+        let mut removals = Vec::new();
+        let mut restores = Vec::new();
+        for entry in self.entries.iter().rev() {
+            match entry.operation {
+                UndoOperation::Copy | UndoOperation::Mkdir => {
+                    removals.push(entry.destination.clone());
+                }
+                UndoOperation::Trash => restores.push(entry.trash_info_path()?),
+                UndoOperation::Rename => requests.push(JobRequest::Rename {
+                    source: entry.destination.clone(),
+                    destination: entry.source.clone(),
+                }),
+                UndoOperation::Move => {
+                    let (Some(parent), Some(name)) =
+                        (entry.source.parent(), entry.source.file_name())
+                    else {
+                        return Err(format!(
+                            "{} has no parent directory",
+                            entry.source.to_string_lossy()
+                        ));
+                    };
+                    let name = name.to_string_lossy().into_owned();
+                    if let Some(JobRequest::Move {
+                        sources,
+                        destination_dir,
+                        destination_names: Some(destination_names),
+                        ..
+                    }) = requests.last_mut()
+                        && destination_dir == parent
+                    {
+                        sources.push(entry.destination.clone());
+                        destination_names.push(name);
+                        continue;
+                    }
+                    requests.push(JobRequest::Move {
+                        sources: vec![entry.destination.clone()],
+                        destination_dir: parent.to_path_buf(),
+                        destination_names: Some(vec![name]),
+                        overwrite: OverwritePolicy::Skip,
+                        verify: false,
+                        preserve: PreserveLevel::Basic,
+                    });
+                }
+            }
+        }
+        if !restores.is_empty() {
+            requests.push(JobRequest::RestoreTrash { items: restores });
+        }
+        if !removals.is_empty() {
+            requests.push(JobRequest::Delete { targets: removals });
+        }
+        Ok(requests)
+    }
+}
+
+/// Completed records, oldest first, plus the steps of jobs still running.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UndoJournal {
+    records: VecDeque<UndoRecord>,
+    in_flight: HashMap<JobId, Vec<UndoEntry>>,
+    ignored_jobs: HashSet<JobId>,
+}
+
+impl UndoJournal {
+    pub fn records(&self) -> impl Iterator<Item = &UndoRecord> {
+        self.records.iter()
+    }
+
+    pub fn last(&self) -> Option<&UndoRecord> {
+        self.records.back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub(crate) fn pop_last(&mut self) -> Option<UndoRecord> {
+        self.records.pop_back()
+    }
+
+    /// Keeps the steps of `job_id` out of the journal; used for undo jobs.
+    pub(crate) fn ignore_job(&mut self, job_id: JobId) {
+        self.ignored_jobs.insert(job_id);
+    }
+
+    pub(crate) fn track(&mut self, job_id: JobId, entry: UndoEntry) {
+        if !self.ignored_jobs.contains(&job_id) {
+            self.in_flight.entry(job_id).or_default().push(entry);
+        }
+    }
+
+    /// Closes the record of a finished job. Failed and canceled jobs keep the
+    /// steps they completed. Returns whether a record was added.
+    pub(crate) fn finish(&mut self, job_id: JobId, summary: String) -> bool {
+        self.ignored_jobs.remove(&job_id);
+        let Some(entries) = self.in_flight.remove(&job_id) else {
+            return false;
+        };
+        self.push(UndoRecord { summary, entries });
+        true
+    }
+
+    fn push(&mut self, record: UndoRecord) {
+        self.records.push_back(record);
+        while self.records.len() > UNDO_JOURNAL_LIMIT {
+            self.records.pop_front();
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(source) => Ok(Self::parse(&source)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write_atomic(path, &self.render())
+    }
+
+    /// Reads the journal format written by [`UndoJournal::render`], skipping
+    /// lines it does not understand.
+    pub fn parse(source: &str) -> Self {
+        let mut journal = Self::default();
+        let mut current: Option<UndoRecord> = None;
+        for line in source.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(summary) = line.strip_prefix(UNDO_RECORD_KEY) {
+                if let Some(record) = current.take().filter(|record| !record.entries.is_empty()) {
+                    journal.push(record);
+                }
+                current = Some(UndoRecord {
+                    summary: summary.to_string(),
+                    entries: Vec::new(),
+                });
+                continue;
+            }
+            let mut fields = line.split('\t');
+            let (Some(operation), Some(source), Some(destination), Some(identity), contents, None) = (
+                fields.next().and_then(UndoOperation::parse),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                continue;
+            };
+            if let Some(record) = current.as_mut() {
+                record.entries.push(UndoEntry {
+                    operation,
+                    source: decode_path(source),
+                    destination: decode_path(destination),
+                    identity: identity.to_string(),
+                    contents: contents.map(str::to_string),
+                });
+            }
+        }
+        if let Some(record) = current.filter(|record| !record.entries.is_empty()) {
+            journal.push(record);
+        }
+        journal
+    }
+
+    pub fn render(&self) -> String {
+        let mut output = format!("{UNDO_JOURNAL_HEADER}\n");
+        for record in &self.records {
+            let summary: String = record
+                .summary
+                .chars()
+                .map(|ch| if ch.is_control() { ' ' } else { ch })
+                .collect();
+            output.push_str(&format!("{UNDO_RECORD_KEY}{summary}\n"));
+            for entry in &record.entries {
+                output.push_str(&format!(
+                    "{}\t{}\t{}\t{}",
+                    entry.operation.label(),
+                    encode_path(&entry.source),
+                    encode_path(&entry.destination),
+                    entry.identity
+                ));
+                if let Some(contents) = &entry.contents {
+                    output.push_str(&format!("\t{contents}"));
+                }
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_temp_dir;
+
+    fn entry(operation: UndoOperation, source: &Path, destination: &Path) -> UndoEntry {
+        UndoEntry::capture(operation, source, destination)
+            .expect("identity should be readable")
+            .expect("destination should exist")
+    }
+
+    #[test]
+    fn journal_round_trips_and_stays_bounded() {
+        let mut journal = UndoJournal::default();
+        for index in 0..UNDO_JOURNAL_LIMIT + 3 {
+            let job_id = JobId(index as u64);
+            journal.track(
+                job_id,
+                UndoEntry {
+                    operation: UndoOperation::Move,
+                    source: PathBuf::from(format!("/tmp/a b\t{index}")),
+                    destination: PathBuf::from(format!("/srv/{index}")),
+                    identity: format!("1:{index}"),
+                    contents: None,
+                },
+            );
+            assert!(journal.finish(job_id, format!("move #{index}\nsecond line")));
+        }
+        assert_eq!(journal.len(), UNDO_JOURNAL_LIMIT);
+        assert!(!journal.finish(JobId(999), String::from("nothing tracked")));
+
+        let parsed = UndoJournal::parse(&journal.render());
+        assert_eq!(parsed.len(), UNDO_JOURNAL_LIMIT);
+        let last = parsed.last().expect("journal should not be empty");
+        assert_eq!(
+            last.summary,
+            format!("move #{} second line", UNDO_JOURNAL_LIMIT + 2)
+        );
+        assert_eq!(
+            last.entries[0].source,
+            PathBuf::from(format!("/tmp/a b\t{}", UNDO_JOURNAL_LIMIT + 2))
+        );
+    }
+
+    #[test]
+    fn ignored_jobs_are_not_journaled() {
+        let mut journal = UndoJournal::default();
+        journal.ignore_job(JobId(7));
+        journal.track(
+            JobId(7),
+            UndoEntry {
+                operation: UndoOperation::Rename,
+                source: PathBuf::from("/tmp/a"),
+                destination: PathBuf::from("/tmp/b"),
+                identity: String::from("1:2"),
+                contents: None,
+            },
+        );
+        assert!(!journal.finish(JobId(7), String::from("undo")));
+        assert!(journal.is_empty());
+    }
+
+    #[test]
+    fn inverse_requests_move_entries_back_and_remove_copies() {
+        let root = make_temp_dir("undo-inverse");
+        let target = root.join("target");
+        fs::create_dir_all(&target).expect("must create target");
+        fs::write(target.join("moved"), "moved").expect("must write moved");
+        fs::write(target.join("copied"), "copied").expect("must write copied");
+
+        let moves = UndoRecord {
+            summary: String::from("move"),
+            entries: vec![entry(
+                UndoOperation::Move,
+                &root.join("original"),
+                &target.join("moved"),
+            )],
+        };
+        assert_eq!(
+            moves.inverse_requests(),
+            Ok(vec![JobRequest::Move {
+                sources: vec![target.join("moved")],
+                destination_dir: root.clone(),
+                destination_names: Some(vec![String::from("original")]),
+                overwrite: OverwritePolicy::Skip,
+                verify: false,
+                preserve: PreserveLevel::Basic,
+            }])
+        );
+
+        let copies = UndoRecord {
+            summary: String::from("copy"),
+            entries: vec![entry(
+                UndoOperation::Copy,
+                &root.join("source"),
+                &target.join("copied"),
+            )],
+        };
+        assert_eq!(
+            copies.inverse_requests(),
+            Ok(vec![JobRequest::Delete {
+                targets: vec![target.join("copied")],
+            }])
+        );
+
+        fs::remove_dir_all(&root).expect("must remove temp dir");
+    }
+
+    #[test]
+    fn inverse_requests_refuse_when_filesystem_diverged() {
+        let root = make_temp_dir("undo-diverged");
+        let created = root.join("created");
+        fs::create_dir(&created).expect("must create dir");
+        let mkdir = UndoRecord {
+            summary: String::from("mkdir"),
+            entries: vec![entry(UndoOperation::Mkdir, &created, &created)],
+        };
+        fs::write(created.join("new"), "new").expect("must write child");
+        let error = mkdir
+            .inverse_requests()
+            .expect_err("non-empty dir must refuse");
+        assert!(error.ends_with("is no longer empty"), "{error}");
+
+        fs::write(root.join("renamed"), "data").expect("must write renamed");
+        let rename = UndoRecord {
+            summary: String::from("rename"),
+            entries: vec![entry(
+                UndoOperation::Rename,
+                &root.join("before"),
+                &root.join("renamed"),
+            )],
+        };
+        fs::write(root.join("before"), "other").expect("must write new source");
+        let error = rename
+            .inverse_requests()
+            .expect_err("reused source must refuse");
+        assert!(error.ends_with("exists again"), "{error}");
+
+        fs::write(root.join("replacement"), "replacement").expect("must write replacement");
+        fs::rename(root.join("replacement"), root.join("renamed")).expect("must replace");
+        fs::remove_file(root.join("before")).expect("must remove new source");
+        let error = rename
+            .inverse_requests()
+            .expect_err("replaced entry must refuse");
+        assert!(error.ends_with("was replaced since"), "{error}");
+
+        fs::remove_dir_all(&root).expect("must remove temp dir");
+    }
+
+    #[test]
+    fn copy_undo_refuses_when_copied_tree_changed() {
+        let root = make_temp_dir("undo-copy-tree");
+        let copied = root.join("copied");
+        fs::create_dir_all(copied.join("nested")).expect("must create copied tree");
+        fs::write(copied.join("nested").join("file"), "file").expect("must write file");
+        let copy = UndoRecord {
+            summary: String::from("copy"),
+            entries: vec![entry(UndoOperation::Copy, &root.join("source"), &copied)],
+        };
+
+        let parsed = UndoJournal::parse(&{
+            let mut journal = UndoJournal::default();
+            journal.track(JobId(1), copy.entries[0].clone());
+            journal.finish(JobId(1), copy.summary.clone());
+            journal.render()
+        });
+        assert_eq!(parsed.last(), Some(&copy));
+        assert!(copy.inverse_requests().is_ok());
+
+        fs::write(copied.join("nested").join("added"), "added").expect("must add file");
+        let error = copy.inverse_requests().expect_err("added file must refuse");
+        assert!(error.ends_with("changed since it was copied"), "{error}");
+
+        fs::remove_dir_all(&root).expect("must remove temp dir");
+    }
+}
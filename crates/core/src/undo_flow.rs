@@ -0,0 +1,54 @@
+use crate::*;
+
+impl AppState {
+    pub fn undo_journal(&self) -> &UndoJournal {
+        &self.undo_journal
+    }
+
+    pub fn replace_undo_journal(&mut self, journal: UndoJournal) {
+        self.undo_journal = journal;
+        self.pending_undo_journal_save = false;
+    }
+
+    pub fn take_pending_undo_journal_save(&mut self) -> bool {
+        std::mem::take(&mut self.pending_undo_journal_save)
+    }
+
+    pub(crate) fn finish_undo_record(&mut self, job_id: JobId) {
+        let summary = self
+            .jobs
+            .job(job_id)
+            .map(|job| job.summary.clone())
+            .unwrap_or_else(|| format!("job #{job_id}"));
+        if self.undo_journal.finish(job_id, summary) {
+            self.pending_undo_journal_save = true;
+        }
+    }
+
+    /// Queues the inverse of the newest journal record. A record that no
+    /// longer matches the filesystem stays in the journal, so it can be undone
+    /// once the files are put back.
+    pub(crate) fn undo_last_operation(&mut self) {
+        let Some(record) = self.undo_journal.last() else {
+            self.set_status("Nothing to undo");
+            return;
+        };
+        let requests = match record.inverse_requests() {
+            Ok(requests) => requests,
+            Err(reason) => {
+                self.set_status(format!("Cannot undo '{}': {reason}", record.summary));
+                return;
+            }
+        };
+        let Some(record) = self.undo_journal.pop_last() else {
+            return;
+        };
+        self.pending_undo_journal_save = true;
+        for request in requests {
+            let job_id =
+                self.queue_filesystem_job(request, OperationOrigin::Panel(self.active_panel));
+            self.undo_journal.ignore_job(job_id);
+        }
+        self.set_status(format!("Undoing '{}'", record.summary));
+    }
+}
@@ -8,7 +8,8 @@ use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use rc_core::{
-    JobEvent, JobManager, JobRequest, JobStatus, OverwritePolicy, WorkerCommand, run_worker,
+    FollowLinks, JobEvent, JobManager, JobRequest, JobStatus, OverwritePolicy, PreserveLevel,
+    WorkerCommand, run_worker,
 };
 
 fn make_temp_dir(label: &str) -> PathBuf {
@@ -72,7 +73,12 @@ fn recursive_copy_move_delete_round_trip() {
     let copy_job = manager.enqueue(JobRequest::Copy {
         sources: vec![source_root.clone()],
         destination_dir: copy_dest.clone(),
+        destination_names: None,
         overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
     });
     command_tx
         .send(WorkerCommand::Run(Box::new(copy_job)))
@@ -95,6 +101,8 @@ fn recursive_copy_move_delete_round_trip() {
         destination_dir: move_dest.clone(),
         destination_names: None,
         overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
     });
     command_tx
         .send(WorkerCommand::Run(Box::new(move_job)))
@@ -147,7 +155,12 @@ fn overwrite_policies_apply_end_to_end() {
     let skip_job = manager.enqueue(JobRequest::Copy {
         sources: vec![source_file.clone()],
         destination_dir: destination.clone(),
+        destination_names: None,
         overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
     });
     command_tx
         .send(WorkerCommand::Run(Box::new(skip_job)))
@@ -166,7 +179,12 @@ fn overwrite_policies_apply_end_to_end() {
     let rename_job = manager.enqueue(JobRequest::Copy {
         sources: vec![source_file.clone()],
         destination_dir: destination.clone(),
+        destination_names: None,
         overwrite: OverwritePolicy::Rename,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
     });
     command_tx
         .send(WorkerCommand::Run(Box::new(rename_job)))
@@ -186,7 +204,12 @@ fn overwrite_policies_apply_end_to_end() {
     let overwrite_job = manager.enqueue(JobRequest::Copy {
         sources: vec![source_file.clone()],
         destination_dir: destination.clone(),
+        destination_names: None,
         overwrite: OverwritePolicy::Overwrite,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
     });
     command_tx
         .send(WorkerCommand::Run(Box::new(overwrite_job)))
@@ -222,7 +245,12 @@ fn canceled_copy_job_reports_canceled_status() {
     let copy_job = manager.enqueue(JobRequest::Copy {
         sources: vec![source_file],
         destination_dir: destination,
+        destination_names: None,
         overwrite: OverwritePolicy::Skip,
+        verify: false,
+        preserve: PreserveLevel::Basic,
+        follow_links: FollowLinks::Never,
+        hard_links: false,
     });
     assert!(
         manager.request_cancel(copy_job.id),
@@ -15,15 +15,17 @@ use rc_core::keymap::KeyContext;
 use rc_core::layout::{
     FIND_DIALOG_HEIGHT, FIND_DIALOG_WIDTH, STANDARD_DIALOG_HEIGHT, STANDARD_DIALOG_WIDTH,
     ScreenRect, centered_overlay_rect, find_results_layout, hotlist_layout, listbox_dialog_layout,
-    tree_layout, visible_window,
+    trash_layout, tree_layout, visible_window,
 };
+use rc_core::throttle::format_rate;
 use rc_core::{
-    ActivePanel, AppCommand, AppState, DialogButtonFocus, DialogKind, DialogState, FileEntry,
-    FilterDialogField, FindDialogField, FindNameMode, FindResultsState, FindResultsStatus,
-    HelpSpan, HelpState, JobRecord, JobStatus, MenuState, NavigationMotion, NavigationTarget,
-    PairInputField, PanelCommand, PanelListingFormat, PanelState, PanelViewMode,
+    AccountEntry, ActivePanel, AppCommand, AppState, ChmodDialogField, ChownDialogField,
+    ConflictEntry, DialogButtonFocus, DialogKind, DialogState, FileEntry, FilterDialogField,
+    FindDialogField, FindNameMode, FindResultsState, FindResultsStatus, HelpSpan, HelpState,
+    JobRecord, JobStatus, MaskDialogField, MenuState, NavigationMotion, NavigationTarget,
+    OverwriteDecision, PairInputField, PanelCommand, PanelListingFormat, PanelState, PanelViewMode,
     QuickCdSearchStatus, QuickViewState, Route, SelectionSizeState, SettingsScreenState,
-    TreeLoadState, TreeState, ViewerState, top_menus,
+    TrashState, TreeLoadState, TreeState, ViewerState, top_menus,
 };
 use std::path::Path;
 use std::sync::{Arc, Mutex, OnceLock};
@@ -134,12 +136,13 @@ pub fn render(frame: &mut Frame, state: &AppState) {
 
     let status = if state.show_debug_status() {
         format!(
-            "context: {:?} | routes:{} | skin:{} | jobs q:{} r:{} ok:{} cx:{} err:{} | {}",
+            "context: {:?} | routes:{} | skin:{} | jobs q:{} r:{} p:{} ok:{} cx:{} err:{} | {}",
             state.key_context(),
             state.route_depth(),
             skin.name(),
             job_counts.queued,
             job_counts.running,
+            job_counts.paused,
             job_counts.succeeded,
             job_counts.canceled,
             job_counts.failed,
@@ -163,6 +166,7 @@ pub fn render(frame: &mut Frame, state: &AppState) {
         }
         Route::Tree(tree) => render_tree_screen(frame, state, tree, skin.as_ref()),
         Route::Hotlist => render_hotlist_screen(frame, state, skin.as_ref()),
+        Route::Trash(trash) => render_trash_screen(frame, state, trash, skin.as_ref()),
         Route::Help(help) => render_help_screen(frame, state, help, skin.as_ref()),
         Route::Menu(menu) => render_menu_overlay(frame, state, menu, skin.as_ref()),
         Route::Settings(settings) => render_settings_screen(frame, settings, skin.as_ref()),
@@ -1338,10 +1342,22 @@ fn indexed_color_rgb(index: u8) -> (u8, u8, u8) {
 }
 
 fn render_dialog(frame: &mut Frame, dialog: &DialogState, skin: &UiSkin) {
-    let (width, height) = if matches!(&dialog.kind, DialogKind::Find(_) | DialogKind::QuickCd(_)) {
-        (FIND_DIALOG_WIDTH, FIND_DIALOG_HEIGHT)
-    } else {
-        (STANDARD_DIALOG_WIDTH, STANDARD_DIALOG_HEIGHT)
+    let (width, height) = match &dialog.kind {
+        DialogKind::Find(_)
+        | DialogKind::QuickCd(_)
+        | DialogKind::Mask(_)
+        | DialogKind::Chmod(_)
+        | DialogKind::Overwrite(_) => (FIND_DIALOG_WIDTH, FIND_DIALOG_HEIGHT),
+        // Advanced chown lists every permission bit below the owner and group.
+        DialogKind::Chown(chown) => (
+            FIND_DIALOG_WIDTH,
+            if chown.permissions.is_some() {
+                FIND_DIALOG_HEIGHT + 3
+            } else {
+                STANDARD_DIALOG_HEIGHT
+            },
+        ),
+        _ => (STANDARD_DIALOG_WIDTH, STANDARD_DIALOG_HEIGHT),
     };
     let area = centered_rect(frame.area(), width, height);
     frame.render_widget(Clear, area);
@@ -1848,6 +1864,278 @@ fn render_dialog(frame: &mut Frame, dialog: &DialogState, skin: &UiSkin) {
                 layout[1],
             );
         }
+        DialogKind::Mask(mask) => {
+            let block = Block::default()
+                .title(dialog.title.as_str())
+                .borders(Borders::ALL)
+                .border_set(skin.dialog_border_set())
+                .border_style(skin.style("dialog", "_default_"))
+                .style(skin.style("dialog", "_default_"));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(4),
+                    Constraint::Min(3),
+                    Constraint::Length(2),
+                ])
+                .split(inner);
+            let normal = skin.style("dialog", "_default_");
+            let focused = skin.style("dialog", "dfocus");
+            let input = skin.style("core", "input");
+            let row = |field: MaskDialogField, label: &str, value: String| {
+                let is_focused = mask.focus == field;
+                Line::from(vec![
+                    Span::styled(if is_focused { "> " } else { "  " }, focused),
+                    Span::styled(
+                        format!("{label:<17}"),
+                        if is_focused { focused } else { normal },
+                    ),
+                    Span::styled(value, if is_focused { focused } else { input }),
+                ])
+            };
+            let mode = match mask.name_mode {
+                FindNameMode::Glob => "shell pattern",
+                FindNameMode::Regex => "regular expression",
+            };
+            let rows = vec![
+                row(
+                    MaskDialogField::SourceMask,
+                    "Source mask",
+                    mask.source_mask.clone(),
+                ),
+                row(
+                    MaskDialogField::DestinationMask,
+                    "To mask",
+                    mask.destination_mask.clone(),
+                ),
+                row(MaskDialogField::NameMode, "Mask mode", mode.to_string()),
+            ];
+            frame.render_widget(Paragraph::new(rows).style(normal), layout[0]);
+
+            let preview_width = layout[1].width as usize;
+            let preview: Vec<Line<'_>> = mask
+                .preview
+                .iter()
+                .take(layout[1].height as usize)
+                .map(|line| Line::from(fit_single_line(line, preview_width)))
+                .collect();
+            frame.render_widget(Paragraph::new(preview).style(normal), layout[1]);
+            frame.render_widget(
+                Paragraph::new(
+                    "Tab/Up/Down field | Space toggle mode | * ? or \\1 take matched text\nEnter use names | Esc back",
+                )
+                .style(skin.style("core", "disabled")),
+                layout[2],
+            );
+        }
+        DialogKind::Chmod(chmod) => {
+            let block = Block::default()
+                .title(dialog.title.as_str())
+                .borders(Borders::ALL)
+                .border_set(skin.dialog_border_set())
+                .border_style(skin.style("dialog", "_default_"))
+                .style(skin.style("dialog", "_default_"));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(2),
+                    Constraint::Min(4),
+                    Constraint::Length(2),
+                ])
+                .split(inner);
+            let normal = skin.style("dialog", "_default_");
+            let focused = skin.style("dialog", "dfocus");
+            let input = skin.style("core", "input");
+            frame.render_widget(
+                Paragraph::new(format!(
+                    "Target: {}\nMode {:04o} (was {:04o})",
+                    chmod.target_label, chmod.mode, chmod.original_mode
+                ))
+                .style(normal),
+                layout[0],
+            );
+            let rows: Vec<Line> = ChmodDialogField::ALL
+                .into_iter()
+                .map(|field| {
+                    let is_focused = chmod.focus == field;
+                    Line::from(vec![
+                        Span::styled(if is_focused { "> " } else { "  " }, focused),
+                        Span::styled(
+                            format!("{:<30}", field.label()),
+                            if is_focused { focused } else { normal },
+                        ),
+                        Span::styled(
+                            checkbox_label(chmod.is_checked(field)),
+                            if is_focused { focused } else { input },
+                        ),
+                    ])
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(rows).style(normal), layout[1]);
+            frame.render_widget(
+                Paragraph::new("Tab/Up/Down field | Space toggle\nEnter apply | Esc cancel")
+                    .style(skin.style("core", "disabled")),
+                layout[2],
+            );
+        }
+        DialogKind::Chown(chown) => {
+            let block = Block::default()
+                .title(dialog.title.as_str())
+                .borders(Borders::ALL)
+                .border_set(skin.dialog_border_set())
+                .border_style(skin.style("dialog", "_default_"))
+                .style(skin.style("dialog", "_default_"));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(2),
+                    Constraint::Min(3),
+                    Constraint::Length(2),
+                ])
+                .split(inner);
+            let normal = skin.style("dialog", "_default_");
+            let focused = skin.style("dialog", "dfocus");
+            let input = skin.style("core", "input");
+            let header = match chown.permissions.as_ref() {
+                Some(permissions) => format!(
+                    "Target: {}\nMode {:04o} (was {:04o})",
+                    chown.target_label, permissions.mode, permissions.original_mode
+                ),
+                None => format!("Target: {}", chown.target_label),
+            };
+            frame.render_widget(Paragraph::new(header).style(normal), layout[0]);
+
+            let account_label = |entry: Option<&AccountEntry>| {
+                entry.map_or_else(
+                    || String::from("<none>"),
+                    |entry| format!("{} ({})", entry.name, entry.id),
+                )
+            };
+            let rows: Vec<Line> = chown
+                .fields()
+                .into_iter()
+                .map(|field| {
+                    let is_focused = chown.focus == field;
+                    let (label, value) = match field {
+                        ChownDialogField::Owner => ("Owner", account_label(chown.selected_user())),
+                        ChownDialogField::Group => ("Group", account_label(chown.selected_group())),
+                        ChownDialogField::Recursive => {
+                            ("Apply recursively", checkbox_label(chown.recursive))
+                        }
+                        ChownDialogField::Permission(permission) => {
+                            (permission.label(), checkbox_label(chown.is_checked(field)))
+                        }
+                    };
+                    Line::from(vec![
+                        Span::styled(if is_focused { "> " } else { "  " }, focused),
+                        Span::styled(
+                            format!("{label:<30}"),
+                            if is_focused { focused } else { normal },
+                        ),
+                        Span::styled(value, if is_focused { focused } else { input }),
+                    ])
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(rows).style(normal), layout[1]);
+            frame.render_widget(
+                Paragraph::new(
+                    "Tab/Up/Down field | Space next/toggle | Letter jump | Backspace previous\nEnter apply | Esc cancel",
+                )
+                .style(skin.style("core", "disabled")),
+                layout[2],
+            );
+        }
+        DialogKind::Overwrite(overwrite) => {
+            let block = Block::default()
+                .title(dialog.title.as_str())
+                .borders(Borders::ALL)
+                .border_set(skin.dialog_border_set())
+                .border_style(skin.style("dialog", "_default_"))
+                .style(skin.style("dialog", "_default_"));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(6),
+                    Constraint::Min(7),
+                    Constraint::Length(2),
+                ])
+                .split(inner);
+            let normal = skin.style("dialog", "_default_");
+            let focused = skin.style("dialog", "dfocus");
+            let input = skin.style("core", "input");
+            let conflict = &overwrite.conflict;
+            let (source, destination) = (&conflict.source_entry, &conflict.destination_entry);
+            let size_label = |entry: &ConflictEntry| {
+                if entry.is_dir {
+                    String::from("<DIR>")
+                } else {
+                    format!("{} bytes", entry.size)
+                }
+            };
+            let modified_label = |entry: &ConflictEntry| {
+                entry.modified.map_or_else(
+                    || String::from("-"),
+                    |time| {
+                        DateTime::<Local>::from(time)
+                            .format("%Y-%m-%d %H:%M:%S")
+                            .to_string()
+                    },
+                )
+            };
+            frame.render_widget(
+                Paragraph::new(format!(
+                    "Source:      {}\nDestination: {}\n\n{:<10}{:>24}  {:>24}\n{:<10}{:>24}  {:>24}\n{:<10}{:>24}  {:>24}",
+                    conflict.source.to_string_lossy(),
+                    conflict.destination.to_string_lossy(),
+                    "",
+                    "Source",
+                    "Destination",
+                    "Size",
+                    size_label(source),
+                    size_label(destination),
+                    "Modified",
+                    modified_label(source),
+                    modified_label(destination),
+                ))
+                .style(normal),
+                layout[0],
+            );
+            let mut rows: Vec<Line> = OverwriteDecision::ALL
+                .into_iter()
+                .map(|decision| {
+                    let is_selected = overwrite.decision() == decision;
+                    Line::from(vec![
+                        Span::styled(if is_selected { "> " } else { "  " }, focused),
+                        Span::styled(decision.label(), if is_selected { focused } else { normal }),
+                    ])
+                })
+                .collect();
+            rows.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("{:<30}", "Apply to all conflicts"), normal),
+                Span::styled(checkbox_label(overwrite.apply_to_all), input),
+            ]));
+            frame.render_widget(Paragraph::new(rows).style(normal), layout[1]);
+            frame.render_widget(
+                Paragraph::new(
+                    "Up/Down choose | Space/Tab apply to all\nEnter confirm | Esc abort job",
+                )
+                .style(skin.style("core", "disabled")),
+                layout[2],
+            );
+        }
     }
 }
 
@@ -1899,6 +2187,32 @@ fn render_jobs_screen(frame: &mut Frame, state: &AppState, skin: &UiSkin) {
     let cancel = state
         .keybinding_joined_label(KeyContext::Jobs, AppCommand::CancelJob, " / ", 1)
         .unwrap_or_else(|| String::from("Alt-J"));
+    let limit = state
+        .keybinding_joined_label(
+            KeyContext::Jobs,
+            AppCommand::OpenJobBandwidthLimit,
+            " / ",
+            1,
+        )
+        .unwrap_or_else(|| String::from("l"));
+    let pause = state
+        .keybinding_joined_label(KeyContext::Jobs, AppCommand::ToggleJobPause, " / ", 1)
+        .unwrap_or_else(|| String::from("p"));
+    let retry = state
+        .keybinding_joined_label(KeyContext::Jobs, AppCommand::RetryJob, " / ", 1)
+        .unwrap_or_else(|| String::from("r"));
+    let skipped = state
+        .keybinding_joined_label(KeyContext::Jobs, AppCommand::ShowSkippedItems, " / ", 1)
+        .unwrap_or_else(|| String::from("s"));
+    let move_up = state
+        .keybinding_primary_label(KeyContext::Jobs, AppCommand::MoveJobUp)
+        .unwrap_or("Shift-Up");
+    let move_down = state
+        .keybinding_primary_label(KeyContext::Jobs, AppCommand::MoveJobDown)
+        .unwrap_or("Shift-Down");
+    let run_next = state
+        .keybinding_joined_label(KeyContext::Jobs, AppCommand::RunJobNext, " / ", 1)
+        .unwrap_or_else(|| String::from("n"));
     let close = state
         .keybinding_joined_label(KeyContext::Jobs, AppCommand::CloseJobsScreen, " / ", 2)
         .unwrap_or_else(|| String::from("Esc/q"));
@@ -1908,7 +2222,7 @@ fn render_jobs_screen(frame: &mut Frame, state: &AppState, skin: &UiSkin) {
             Constraint::Length(5),
             Constraint::Length(8),
             Constraint::Length(10),
-            Constraint::Length(20),
+            Constraint::Length(36),
             Constraint::Length(20),
             Constraint::Min(10),
         ],
@@ -1917,11 +2231,9 @@ fn render_jobs_screen(frame: &mut Frame, state: &AppState, skin: &UiSkin) {
     .style(skin.style("dialog", "_default_"))
     .highlight_style(skin.style("core", "selected"))
     .highlight_symbol(">> ")
-    .block(
-        Block::default()
-            .borders(Borders::NONE)
-            .title(format!("Up/Down select | {cancel} cancel | {close} close")),
-    );
+    .block(Block::default().borders(Borders::NONE).title(format!(
+        "Up/Down select | {cancel} cancel | {pause} pause | {limit} limit | {retry} retry | {skipped} skipped | {move_up}/{move_down} reorder | {run_next} run next | {close} close"
+    )));
 
     let mut table_state = TableState::default();
     if !state.jobs.jobs().is_empty() {
@@ -2250,6 +2562,93 @@ fn render_tree_screen(frame: &mut Frame, app: &AppState, tree: &TreeState, skin:
     );
 }
 
+fn render_trash_screen(frame: &mut Frame, app: &AppState, trash: &TrashState, skin: &UiSkin) {
+    let screen_layout = trash_layout(frame_area(frame));
+    let area = terminal_rect(screen_layout.outer);
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!("Trash ({})", trash.items.len()))
+        .borders(Borders::ALL)
+        .border_set(skin.dialog_border_set())
+        .border_style(skin.style("dialog", "_default_"))
+        .style(skin.style("dialog", "_default_"));
+    frame.render_widget(block, area);
+
+    let header_area = terminal_rect(screen_layout.header);
+    let list_area = terminal_rect(screen_layout.list);
+    let footer_area = terminal_rect(screen_layout.footer);
+
+    frame.render_widget(
+        Paragraph::new(fit_single_line(
+            format!("{:<19}  Original path", "Deleted"),
+            header_area.width as usize,
+        ))
+        .style(skin.style("core", "header")),
+        header_area,
+    );
+
+    let viewport_rows = list_area.height.max(1) as usize;
+    let (window_start, window_end) = visible_window(trash.items.len(), trash.cursor, viewport_rows);
+    let items: Vec<ListItem<'_>> = if let Some(error) = trash.error.as_ref() {
+        vec![ListItem::new(error.clone())]
+    } else if trash.items.is_empty() {
+        vec![ListItem::new("<trash is empty>")]
+    } else {
+        let viewport_width = list_area.width.saturating_sub(3) as usize;
+        trash
+            .items
+            .iter()
+            .skip(window_start)
+            .take(window_end.saturating_sub(window_start))
+            .map(|item| {
+                ListItem::new(fit_single_line(
+                    format!(
+                        "{:<19}  {}",
+                        item.deleted_at.replace('T', " "),
+                        item.original_path.to_string_lossy()
+                    ),
+                    viewport_width,
+                ))
+            })
+            .collect()
+    };
+    let list = List::new(items)
+        .style(skin.style("dialog", "_default_"))
+        .highlight_style(skin.style("core", "selected"))
+        .highlight_symbol(">> ");
+
+    let mut state = ListState::default();
+    if !trash.items.is_empty() {
+        let selected_row = trash
+            .cursor
+            .saturating_sub(window_start)
+            .min(window_end.saturating_sub(window_start).saturating_sub(1));
+        state.select(Some(selected_row));
+    }
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let restore = app
+        .keybinding_joined_label(
+            KeyContext::Trash,
+            AppCommand::TrashRestoreSelected,
+            " / ",
+            2,
+        )
+        .unwrap_or_else(|| String::from("r / Enter"));
+    let purge = app
+        .keybinding_joined_label(KeyContext::Trash, AppCommand::TrashPurgeSelected, " / ", 2)
+        .unwrap_or_else(|| String::from("d / Delete"));
+    let close = app
+        .keybinding_joined_label(KeyContext::Trash, AppCommand::CloseTrash, " / ", 2)
+        .unwrap_or_else(|| String::from("Esc / q"));
+    frame.render_widget(
+        Paragraph::new(format!("{restore} restore | {purge} purge | {close} close"))
+            .style(skin.style("core", "disabled")),
+        footer_area,
+    );
+}
+
 fn render_hotlist_screen(frame: &mut Frame, app: &AppState, skin: &UiSkin) {
     let screen_layout = hotlist_layout(frame_area(frame));
     let area = terminal_rect(screen_layout.outer);
@@ -2678,34 +3077,72 @@ fn path_leaf_label(path: &Path) -> String {
 fn job_row(job: &JobRecord) -> Row<'_> {
     let status = match job.status {
         JobStatus::Queued => "queued",
-        JobStatus::Running => "running",
+        JobStatus::Running => job
+            .progress
+            .as_ref()
+            .map_or("running", |progress| progress.phase.label()),
+        JobStatus::Paused => "paused",
+        JobStatus::Succeeded if !job.warnings.is_empty() || !job.skipped_items.is_empty() => "ok*",
         JobStatus::Succeeded => "ok",
         JobStatus::Canceled => "canceled",
         JobStatus::Failed => "failed",
     };
+    // Held jobs show their place in the queue, 1 starting first.
+    let status = match &job.waiting_for {
+        Some(wait) if job.status == JobStatus::Queued => format!("waiting {}", wait.position),
+        _ => status.to_string(),
+    };
     let progress = job
         .progress
         .as_ref()
         .map(|progress| {
-            format!(
+            let mut label = format!(
                 "{}% {}/{}",
                 progress.percent(),
                 progress.items_done,
                 progress.items_total
-            )
+            );
+            if job.status == JobStatus::Running
+                && let Some(rate) = progress.rate_label()
+            {
+                label = format!("{label} {rate}");
+            }
+            label
         })
         .unwrap_or_else(|| String::from("-"));
+    let progress = if job.bandwidth_limit > 0 {
+        format!("{progress} cap {}", format_rate(job.bandwidth_limit))
+    } else {
+        progress
+    };
     let current = job
         .progress
         .as_ref()
         .and_then(|progress| progress.current_path.as_deref())
         .map(|path| path.to_string_lossy().into_owned())
+        .or_else(|| {
+            job.waiting_for.as_ref().map(|wait| {
+                let after = if wait.shared_device {
+                    "same device as"
+                } else {
+                    "after"
+                };
+                format!("{after} #{}: {}", wait.job, wait.path.to_string_lossy())
+            })
+        })
         .unwrap_or_else(|| String::from("-"));
-    let error = job
-        .last_error
-        .as_ref()
-        .map(ToOwned::to_owned)
-        .unwrap_or_else(|| String::from("-"));
+    let error = match (&job.last_error, job.warnings.as_slice()) {
+        (Some(error), _) => error.clone(),
+        (None, _) if let Some(last) = job.skipped_items.last() => format!(
+            "{} skipped, last: {}: {}",
+            job.skipped_items.len(),
+            path_leaf_label(&last.path),
+            last.message
+        ),
+        (None, []) => String::from("-"),
+        (None, [warning]) => warning.clone(),
+        (None, [.., last]) => format!("{} warnings, last: {last}", job.warnings.len()),
+    };
 
     Row::new(vec![
         Cell::from(job.id.to_string()),